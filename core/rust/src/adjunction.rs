@@ -0,0 +1,148 @@
+use crate::category::{Category, CategoryError, LawReport, LawViolation, Morphism, Object};
+use crate::functor::{find_morphism, ComposedFunctor, Functor, IdentityFunctor, NaturalTransformation};
+use crate::metric::MetricMorphism;
+
+/// An adjunction F ⊣ G between functors F: C → D and G: D → C.
+///
+/// The unit η: 1_C ⟹ G ∘ F and counit ε: F ∘ G ⟹ 1_D must satisfy the
+/// triangle identities `ε_F(X) ∘ F(η_X) = id_F(X)` and `G(ε_Y) ∘ η_G(Y) = id_G(Y)`.
+#[derive(Clone, Debug)]
+pub struct Adjunction<F, G, U, E>
+where
+    F: Functor,
+    G: Functor<Source = F::Target, Target = F::Source>,
+    U: NaturalTransformation<
+        Source = F::Source,
+        Target = F::Source,
+        DomainFunctor = IdentityFunctor<F::Source>,
+        CodomainFunctor = ComposedFunctor<F, G>,
+    >,
+    E: NaturalTransformation<
+        Source = F::Target,
+        Target = F::Target,
+        DomainFunctor = ComposedFunctor<G, F>,
+        CodomainFunctor = IdentityFunctor<F::Target>,
+    >,
+{
+    unit: U,
+    counit: E,
+}
+
+impl<F, G, U, E> Adjunction<F, G, U, E>
+where
+    F: Functor,
+    G: Functor<Source = F::Target, Target = F::Source>,
+    U: NaturalTransformation<
+        Source = F::Source,
+        Target = F::Source,
+        DomainFunctor = IdentityFunctor<F::Source>,
+        CodomainFunctor = ComposedFunctor<F, G>,
+    >,
+    E: NaturalTransformation<
+        Source = F::Target,
+        Target = F::Target,
+        DomainFunctor = ComposedFunctor<G, F>,
+        CodomainFunctor = IdentityFunctor<F::Target>,
+    >,
+{
+    /// Create an adjunction from its unit and counit; the functors are read off the unit
+    pub fn new(unit: U, counit: E) -> Self {
+        Self { unit, counit }
+    }
+    
+    /// The left adjoint F
+    pub fn left(&self) -> &F {
+        self.unit.codomain_functor().first()
+    }
+    
+    /// The right adjoint G
+    pub fn right(&self) -> &G {
+        self.unit.codomain_functor().second()
+    }
+    
+    /// The unit η: 1_C ⟹ G ∘ F
+    pub fn unit(&self) -> &U {
+        &self.unit
+    }
+    
+    /// The counit ε: F ∘ G ⟹ 1_D
+    pub fn counit(&self) -> &E {
+        &self.counit
+    }
+    
+    /// Whether `ε_F(X) ∘ F(η_X) = id_F(X)` in `d`, or `None` if a side is undefined
+    fn left_triangle(&self, obj: &<F::Source as Category>::Ob, d: &F::Target) -> Option<bool> {
+        let image = self.left().map_object(obj);
+        let eta = self.unit.component_at(obj).ok()?;
+        let left_eta = find_morphism(d, &self.left().map_morphism(&eta).ok()?)?;
+        let epsilon = find_morphism(d, &self.counit.component_at(&image).ok()?)?;
+        let composite = d.compose(left_eta, epsilon).ok()?;
+        Some(composite.id() == d.identity(&image).ok()?.id())
+    }
+    
+    /// Whether `G(ε_Y) ∘ η_G(Y) = id_G(Y)` in `c`, or `None` if a side is undefined
+    fn right_triangle(&self, obj: &<F::Target as Category>::Ob, c: &F::Source) -> Option<bool> {
+        let image = self.right().map_object(obj);
+        let epsilon = self.counit.component_at(obj).ok()?;
+        let right_epsilon = find_morphism(c, &self.right().map_morphism(&epsilon).ok()?)?;
+        let eta = find_morphism(c, &self.unit.component_at(&image).ok()?)?;
+        let composite = c.compose(eta, right_epsilon).ok()?;
+        Some(composite.id() == c.identity(&image).ok()?.id())
+    }
+    
+    /// Check both triangle identities, composing in `c` and `d`
+    pub fn check_triangle_identities(&self, c: &F::Source, d: &F::Target) -> LawReport {
+        let mut report = LawReport::default();
+        
+        for obj in c.objects() {
+            report.checked += 1;
+            if self.left_triangle(obj, d) != Some(true) {
+                report.violations.push(LawViolation::LeftTriangle { object: obj.id() });
+            }
+        }
+        
+        for obj in d.objects() {
+            report.checked += 1;
+            if self.right_triangle(obj, c) != Some(true) {
+                report.violations.push(LawViolation::RightTriangle { object: obj.id() });
+            }
+        }
+        
+        report
+    }
+    
+    /// Check that the unit and counit are natural and satisfy the triangle identities
+    pub fn verify(&self, c: &F::Source, d: &F::Target) -> LawReport {
+        let mut report = self.unit.verify_naturality(c, c);
+        report.merge(self.counit.verify_naturality(d, d));
+        report.merge(self.check_triangle_identities(c, d));
+        report
+    }
+}
+
+impl<F, G, U, E> Adjunction<F, G, U, E>
+where
+    F: Functor,
+    G: Functor<Source = F::Target, Target = F::Source>,
+    U: NaturalTransformation<
+        Source = F::Source,
+        Target = F::Source,
+        DomainFunctor = IdentityFunctor<F::Source>,
+        CodomainFunctor = ComposedFunctor<F, G>,
+    >,
+    E: NaturalTransformation<
+        Source = F::Target,
+        Target = F::Target,
+        DomainFunctor = ComposedFunctor<G, F>,
+        CodomainFunctor = IdentityFunctor<F::Target>,
+    >,
+    <F::Target as Category>::Mor: MetricMorphism,
+{
+    /// Length of the counit component ε_Y: F(G(Y)) → Y.
+    ///
+    /// When G forgets detail and F reconstructs it as well as it can, this is
+    /// how far the reconstruction falls short of `obj`.
+    pub fn information_loss(&self, obj: &<F::Target as Category>::Ob) -> Result<f64, CategoryError> {
+        Ok(self.counit.component_at(obj)?.length())
+    }
+}
@@ -1,6 +1,5 @@
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::collections::{HashMap, HashSet};
 use thiserror::Error;
 
 /// Error types related to category theory operations
@@ -20,6 +19,15 @@ pub enum CategoryError {
     
     #[error("Invalid morphism application: {0}")]
     InvalidApplication(String),
+    
+    #[error("Category axioms violated: {0}")]
+    AxiomViolation(String),
+    
+    #[error("Duplicate identifier in category: {0}")]
+    DuplicateId(String),
+    
+    #[error("Inconsistent composition table: {0}")]
+    InconsistentComposition(String),
 }
 
 /// Trait for objects in a category
@@ -27,6 +35,11 @@ pub trait Object: Clone + Debug {
     /// Get a unique identifier for this object
     fn id(&self) -> String;
     
+    /// Get a short human-readable label, which need not be unique
+    fn label(&self) -> String {
+        self.id()
+    }
+    
     /// Get the dimension of this object, if applicable
     fn dimension(&self) -> Option<usize> {
         None
@@ -44,9 +57,19 @@ pub trait Morphism: Clone + Debug {
     /// Get the codomain (target) of this morphism
     fn codomain(&self) -> &Self::ObjectType;
     
-    /// Apply this morphism to some data
-    fn apply<T>(&self, data: &T) -> Result<T, CategoryError> 
-    where T: Clone + Debug;
+    /// Get a stable identifier distinguishing this morphism from parallel ones
+    fn id(&self) -> String;
+    
+    /// Whether this morphism is the identity on its domain
+    fn is_identity(&self) -> bool;
+    
+    /// Get a short human-readable label, such as the kind of transformation
+    fn label(&self) -> String {
+        self.id()
+    }
+    
+    /// Apply the action of this morphism to an object, normally its domain
+    fn apply(&self, obj: &Self::ObjectType) -> Result<Self::ObjectType, CategoryError>;
 }
 
 /// A pair of morphisms that can be composed
@@ -78,22 +101,316 @@ pub trait Category: Clone + Debug {
     /// Compose two morphisms
     fn compose(&self, f: &Self::Mor, g: &Self::Mor) -> Result<&Self::Mor, CategoryError>;
     
-    /// Check if this category satisfies the composition associativity law
-    fn check_composition_associative(&self) -> bool {
+    /// Check that composition is defined and associative on every composable triple
+    fn check_composition_associative(&self) -> LawReport {
+        let mut report = LawReport::default();
+        let morphisms = self.morphisms();
+        
         // For every triple of morphisms f: A->B, g: B->C, h: C->D
         // verify that (h ∘ g) ∘ f = h ∘ (g ∘ f)
+        for (i, f) in morphisms.iter().enumerate() {
+            for (j, g) in morphisms.iter().enumerate() {
+                if f.codomain().id() != g.domain().id() {
+                    continue;
+                }
+                
+                let gf = match self.compose(f, g) {
+                    Ok(gf) => gf,
+                    Err(_) => {
+                        report.violations.push(LawViolation::MissingComposite { f: i, g: j });
+                        continue;
+                    }
+                };
+                
+                for (k, h) in morphisms.iter().enumerate() {
+                    if g.codomain().id() != h.domain().id() {
+                        continue;
+                    }
+                    report.checked += 1;
+                    
+                    let hg = match self.compose(g, h) {
+                        Ok(hg) => hg,
+                        // Reported when (g, h) is visited as the outer pair
+                        Err(_) => continue,
+                    };
+                    
+                    match (self.compose(gf, h), self.compose(f, hg)) {
+                        (Ok(left), Ok(right)) if same_morphism(left, right) => {}
+                        _ => report.violations.push(LawViolation::NonAssociative { f: i, g: j, h: k }),
+                    }
+                }
+            }
+        }
         
-        // This is a simplified implementation
-        true
+        report
     }
     
-    /// Check if this category satisfies the identity laws
-    fn check_identity_laws(&self) -> bool {
+    /// Check that every object has an identity and that identities are units for composition
+    fn check_identity_laws(&self) -> LawReport {
+        let mut report = LawReport::default();
+        
+        for obj in self.objects() {
+            report.checked += 1;
+            if self.identity(obj).is_err() {
+                report.violations.push(LawViolation::MissingIdentity { object: obj.id() });
+            }
+        }
+        
         // For every morphism f: A->B, verify that:
         // id_B ∘ f = f and f ∘ id_A = f
+        for (i, f) in self.morphisms().iter().enumerate() {
+            report.checked += 1;
+            
+            if let Ok(id_b) = self.identity(f.codomain()) {
+                match self.compose(f, id_b) {
+                    Ok(composite) if same_morphism(composite, f) => {}
+                    _ => report.violations.push(LawViolation::LeftIdentity { morphism: i }),
+                }
+            }
+            
+            if let Ok(id_a) = self.identity(f.domain()) {
+                match self.compose(id_a, f) {
+                    Ok(composite) if same_morphism(composite, f) => {}
+                    _ => report.violations.push(LawViolation::RightIdentity { morphism: i }),
+                }
+            }
+        }
+        
+        report
+    }
+    
+    /// Check that no two objects and no two morphisms share an identifier
+    fn check_distinct_ids(&self) -> LawReport {
+        let mut report = LawReport::default();
+        
+        let mut object_ids = HashSet::new();
+        for obj in self.objects() {
+            report.checked += 1;
+            if !object_ids.insert(obj.id()) {
+                report.violations.push(LawViolation::DuplicateObject { object: obj.id() });
+            }
+        }
+        
+        let mut morphism_ids = HashSet::new();
+        for (i, f) in self.morphisms().iter().enumerate() {
+            report.checked += 1;
+            if !morphism_ids.insert(f.id()) {
+                report.violations.push(LawViolation::DuplicateMorphism { morphism: i });
+            }
+        }
+        
+        report
+    }
+    
+    /// Check distinct identifiers, the associativity and the identity laws
+    fn check_laws(&self) -> LawReport {
+        let mut report = self.check_distinct_ids();
+        report.merge(self.check_identity_laws());
+        report.merge(self.check_composition_associative());
+        report
+    }
+    
+    /// Check that morphisms act as declared.
+    ///
+    /// Applying a morphism to its domain must give its codomain, and applying
+    /// `f` and then `g` must give the same object as applying `g ∘ f`.
+    fn check_actions(&self) -> LawReport {
+        let mut report = LawReport::default();
+        let morphisms = self.morphisms();
+        
+        for (i, f) in morphisms.iter().enumerate() {
+            report.checked += 1;
+            let image = f.apply(f.domain());
+            if !matches!(&image, Ok(obj) if obj.id() == f.codomain().id()) {
+                report.violations.push(LawViolation::ActionMismatch { morphism: i });
+            }
+            
+            for (j, g) in morphisms.iter().enumerate() {
+                if f.codomain().id() != g.domain().id() {
+                    continue;
+                }
+                // Undefined composites are reported by the associativity check
+                let Ok(gf) = self.compose(f, g) else {
+                    continue;
+                };
+                report.checked += 1;
+                
+                let sequential = image.as_ref().ok().and_then(|obj| g.apply(obj).ok());
+                match (sequential, gf.apply(f.domain())) {
+                    (Some(left), Ok(right)) if left.id() == right.id() => {}
+                    _ => report.violations.push(LawViolation::CompositeActionMismatch { f: i, g: j }),
+                }
+            }
+        }
         
-        // This is a simplified implementation
-        true
+        report
+    }
+}
+
+/// Whether two morphisms are the same morphism
+fn same_morphism<M: Morphism>(a: &M, b: &M) -> bool {
+    a.id() == b.id()
+}
+
+/// A single failure of the category axioms, the functor laws, naturality or
+/// the triangle identities of an adjunction.
+///
+/// Morphisms are referred to by their index in `Category::morphisms`, of the
+/// source category for functor and naturality violations.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LawViolation {
+    /// Another object has the same identifier
+    DuplicateObject { object: String },
+    
+    /// An earlier morphism has the same identifier
+    DuplicateMorphism { morphism: usize },
+    
+    /// `f: A->B` and `g: B->C` are composable but `g ∘ f` is not defined
+    MissingComposite { f: usize, g: usize },
+    
+    /// `(h ∘ g) ∘ f` and `h ∘ (g ∘ f)` differ or one of them is undefined
+    NonAssociative { f: usize, g: usize, h: usize },
+    
+    /// The object has no identity morphism
+    MissingIdentity { object: String },
+    
+    /// `id_B ∘ f` is not `f`
+    LeftIdentity { morphism: usize },
+    
+    /// `f ∘ id_A` is not `f`
+    RightIdentity { morphism: usize },
+    
+    /// Applying `f: A->B` to `A` fails or does not give `B`
+    ActionMismatch { morphism: usize },
+    
+    /// Applying `f` and then `g` does not give the same object as applying `g ∘ f`
+    CompositeActionMismatch { f: usize, g: usize },
+    
+    /// The identity has non-zero length
+    IdentityLength { morphism: usize },
+    
+    /// `g ∘ f` is longer than `f` and `g` together
+    TriangleInequality { f: usize, g: usize },
+    
+    /// `F(f)` for `f: A->B` is undefined, not a morphism of the target or not from `F(A)` to `F(B)`
+    UnmappedMorphism { morphism: usize },
+    
+    /// `F(id_A)` is not `id_F(A)`
+    IdentityNotPreserved { object: String },
+    
+    /// `F(g ∘ f)` is not `F(g) ∘ F(f)`
+    CompositionNotPreserved { f: usize, g: usize },
+    
+    /// The component `η_X` is undefined, not a morphism of the target or not from `F(X)` to `G(X)`
+    ComponentMismatch { object: String },
+    
+    /// `G(f) ∘ η_X` is not `η_Y ∘ F(f)`
+    NaturalitySquare { morphism: usize },
+    
+    /// The component `η_X` has no inverse
+    NonInvertibleComponent { object: String },
+    
+    /// `ε_F(X) ∘ F(η_X)` is not `id_F(X)` for the object `X`
+    LeftTriangle { object: String },
+    
+    /// `G(ε_Y) ∘ η_G(Y)` is not `id_G(Y)` for the object `Y`
+    RightTriangle { object: String },
+}
+
+impl fmt::Display for LawViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LawViolation::DuplicateObject { object } => {
+                write!(f, "object id {} is used more than once", object)
+            }
+            LawViolation::DuplicateMorphism { morphism } => {
+                write!(f, "morphism #{} reuses the id of an earlier morphism", morphism)
+            }
+            LawViolation::MissingComposite { f: i, g: j } => {
+                write!(f, "composite of morphisms #{} and #{} is undefined", i, j)
+            }
+            LawViolation::NonAssociative { f: i, g: j, h: k } => {
+                write!(f, "composition of morphisms #{}, #{}, #{} is not associative", i, j, k)
+            }
+            LawViolation::MissingIdentity { object } => {
+                write!(f, "object {} has no identity", object)
+            }
+            LawViolation::LeftIdentity { morphism } => {
+                write!(f, "left identity law fails for morphism #{}", morphism)
+            }
+            LawViolation::RightIdentity { morphism } => {
+                write!(f, "right identity law fails for morphism #{}", morphism)
+            }
+            LawViolation::ActionMismatch { morphism } => {
+                write!(f, "morphism #{} does not send its domain to its codomain", morphism)
+            }
+            LawViolation::CompositeActionMismatch { f: i, g: j } => {
+                write!(f, "applying morphisms #{} and #{} in turn differs from applying their composite", i, j)
+            }
+            LawViolation::IdentityLength { morphism } => {
+                write!(f, "identity morphism #{} has non-zero length", morphism)
+            }
+            LawViolation::TriangleInequality { f: i, g: j } => {
+                write!(f, "composite of morphisms #{} and #{} is longer than its parts", i, j)
+            }
+            LawViolation::UnmappedMorphism { morphism } => {
+                write!(f, "morphism #{} has no image between the images of its endpoints", morphism)
+            }
+            LawViolation::IdentityNotPreserved { object } => {
+                write!(f, "identity of object {} is not mapped to an identity", object)
+            }
+            LawViolation::CompositionNotPreserved { f: i, g: j } => {
+                write!(f, "composite of morphisms #{} and #{} is not mapped to the composite of their images", i, j)
+            }
+            LawViolation::ComponentMismatch { object } => {
+                write!(f, "component at object {} is undefined or does not go from F(X) to G(X) in the target", object)
+            }
+            LawViolation::NaturalitySquare { morphism } => {
+                write!(f, "naturality square of morphism #{} does not commute", morphism)
+            }
+            LawViolation::NonInvertibleComponent { object } => {
+                write!(f, "component at object {} is not invertible", object)
+            }
+            LawViolation::LeftTriangle { object } => {
+                write!(f, "triangle identity for the left adjoint fails at object {}", object)
+            }
+            LawViolation::RightTriangle { object } => {
+                write!(f, "triangle identity for the right adjoint fails at object {}", object)
+            }
+        }
+    }
+}
+
+/// Result of checking the category axioms
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LawReport {
+    /// Number of triples, morphisms and objects that were checked
+    pub checked: usize,
+    
+    /// Every violation that was found
+    pub violations: Vec<LawViolation>,
+}
+
+impl LawReport {
+    /// Whether no violations were found
+    pub fn is_lawful(&self) -> bool {
+        self.violations.is_empty()
+    }
+    
+    /// Append the results of another check to this report
+    pub fn merge(&mut self, other: LawReport) {
+        self.checked += other.checked;
+        self.violations.extend(other.violations);
+    }
+}
+
+impl fmt::Display for LawReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} violation(s) in {} checks", self.violations.len(), self.checked)?;
+        for violation in &self.violations {
+            write!(f, "; {}", violation)?;
+        }
+        Ok(())
     }
 }
 
@@ -112,6 +429,9 @@ pub struct FinCategory<O: Object, M: Morphism<ObjectType = O>> {
     /// Map from object IDs to their identity morphism indices
     identity_map: HashMap<String, usize>,
     
+    /// Map from morphism IDs to morphism indices
+    morphism_index: HashMap<String, usize>,
+    
     /// Map from (domain_id, codomain_id) to morphism indices
     morphism_map: HashMap<(String, String), Vec<usize>>,
     
@@ -120,57 +440,135 @@ pub struct FinCategory<O: Object, M: Morphism<ObjectType = O>> {
 }
 
 impl<O: Object, M: Morphism<ObjectType = O>> FinCategory<O, M> {
-    /// Create a new finite category
+    /// Create a new finite category.
+    ///
+    /// Composites are only filled in where they are forced: composing with an
+    /// identity, or landing in a hom-set with a single morphism. Use
+    /// [`FinCategoryBuilder`] to give the remaining composites explicitly.
     pub fn new(name: String, objects: Vec<O>, morphisms: Vec<M>) -> Self {
+        let mut category = Self::unlinked(name, objects, morphisms);
+        
+        let mut composition_map = HashMap::new();
+        for (i, j) in category.composable_pairs() {
+            if let Some(k) = category.forced_composite(i, j) {
+                composition_map.insert((i, j), k);
+            }
+        }
+        category.composition_map = composition_map;
+        
+        category
+    }
+    
+    /// Create a new finite category, rejecting it if it violates the category axioms
+    /// or reuses an object or morphism identifier
+    pub fn new_checked(name: String, objects: Vec<O>, morphisms: Vec<M>) -> Result<Self, CategoryError> {
+        let category = Self::new(name, objects, morphisms);
+        let report = category.check_laws();
+        
+        if report.is_lawful() {
+            Ok(category)
+        } else {
+            Err(CategoryError::AxiomViolation(format!("{}: {}", category.name, report)))
+        }
+    }
+    
+    /// Index objects and morphisms without any composites.
+    ///
+    /// A morphism whose id is already taken keeps its place in `morphisms` but
+    /// is not indexed, so lookups resolve to the first one. `check_distinct_ids`
+    /// reports the duplicate.
+    fn unlinked(name: String, objects: Vec<O>, morphisms: Vec<M>) -> Self {
         let mut identity_map = HashMap::new();
+        let mut morphism_index = HashMap::new();
         let mut morphism_map = HashMap::new();
         
-        // Build the identity map and morphism map
+        // Build the identity map and morphism maps
         for (idx, morph) in morphisms.iter().enumerate() {
+            if morphism_index.contains_key(&morph.id()) {
+                continue;
+            }
             let domain_id = morph.domain().id();
             let codomain_id = morph.codomain().id();
             
-            // If this is an identity morphism (domain = codomain)
-            if domain_id == codomain_id {
-                identity_map.insert(domain_id.clone(), idx);
+            if morph.is_identity() && domain_id == codomain_id {
+                identity_map.entry(domain_id.clone()).or_insert(idx);
             }
             
-            // Add to the morphism map
+            morphism_index.insert(morph.id(), idx);
             morphism_map
                 .entry((domain_id, codomain_id))
                 .or_insert_with(Vec::new)
                 .push(idx);
         }
         
-        // Compute composition map
-        let mut composition_map = HashMap::new();
-        for (i, f) in morphisms.iter().enumerate() {
-            for (j, g) in morphisms.iter().enumerate() {
-                // If f: A->B and g: B->C, then we can compose them
-                if f.codomain().id() == g.domain().id() {
-                    // Look for a morphism h: A->C that represents g ∘ f
-                    let a_id = f.domain().id();
-                    let c_id = g.codomain().id();
-                    
-                    if let Some(candidates) = morphism_map.get(&(a_id, c_id)) {
-                        // In a real implementation, we would need to check
-                        // which candidate is actually g ∘ f.
-                        // Here we'll just use the first one for simplicity
-                        if !candidates.is_empty() {
-                            composition_map.insert((i, j), candidates[0]);
-                        }
-                    }
-                }
-            }
-        }
-        
         Self {
             name,
             objects,
             morphisms,
             identity_map,
+            morphism_index,
             morphism_map,
-            composition_map,
+            composition_map: HashMap::new(),
+        }
+    }
+    
+    /// All pairs (f_idx, g_idx) with f: A->B and g: B->C
+    fn composable_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (i, f) in self.morphisms.iter().enumerate() {
+            for (j, g) in self.morphisms.iter().enumerate() {
+                if f.codomain().id() == g.domain().id() {
+                    pairs.push((i, j));
+                }
+            }
+        }
+        pairs
+    }
+    
+    /// The composite of a composable pair, if the category structure forces it
+    fn forced_composite(&self, i: usize, j: usize) -> Option<usize> {
+        if self.morphisms[i].is_identity() {
+            return Some(j);
+        }
+        if self.morphisms[j].is_identity() {
+            return Some(i);
+        }
+        
+        let a_id = self.morphisms[i].domain().id();
+        let c_id = self.morphisms[j].codomain().id();
+        match self.morphism_map.get(&(a_id, c_id)) {
+            Some(candidates) if candidates.len() == 1 => Some(candidates[0]),
+            _ => None,
+        }
+    }
+    
+    /// Record `morphisms[k] = morphisms[j] ∘ morphisms[i]`, checking endpoints and earlier entries
+    fn insert_composite(
+        &self,
+        composition_map: &mut HashMap<(usize, usize), usize>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> Result<(), CategoryError> {
+        let (f, g, gf) = (&self.morphisms[i], &self.morphisms[j], &self.morphisms[k]);
+        
+        if f.codomain().id() != g.domain().id() {
+            return Err(CategoryError::CompositionMismatch(format!(
+                "{} does not end where {} starts", f.id(), g.id()
+            )));
+        }
+        if gf.domain().id() != f.domain().id() || gf.codomain().id() != g.codomain().id() {
+            return Err(CategoryError::InconsistentComposition(format!(
+                "{} cannot be the composite of {} followed by {}", gf.id(), f.id(), g.id()
+            )));
+        }
+        
+        match composition_map.insert((i, j), k) {
+            Some(previous) if previous != k => Err(CategoryError::InconsistentComposition(format!(
+                "{} followed by {} given as both {} and {}",
+                f.id(), g.id(), self.morphisms[previous].id(), gf.id()
+            ))),
+            _ => Ok(()),
         }
     }
     
@@ -181,16 +579,20 @@ impl<O: Object, M: Morphism<ObjectType = O>> FinCategory<O, M> {
     
     /// Find a morphism by its domain and codomain objects
     pub fn find_morphism(&self, domain: &O, codomain: &O) -> Option<&M> {
-        let domain_id = domain.id();
-        let codomain_id = codomain.id();
-        
-        if let Some(indices) = self.morphism_map.get(&(domain_id, codomain_id)) {
-            if !indices.is_empty() {
-                return Some(&self.morphisms[indices[0]]);
-            }
-        }
-        
-        None
+        self.hom(domain, codomain).into_iter().next()
+    }
+    
+    /// Get all morphisms from `domain` to `codomain`
+    pub fn hom(&self, domain: &O, codomain: &O) -> Vec<&M> {
+        self.morphism_map
+            .get(&(domain.id(), codomain.id()))
+            .map(|indices| indices.iter().map(|&idx| &self.morphisms[idx]).collect())
+            .unwrap_or_default()
+    }
+    
+    /// Find a morphism by its ID
+    pub fn morphism(&self, id: &str) -> Option<&M> {
+        self.morphism_index.get(id).map(|&idx| &self.morphisms[idx])
     }
 }
 
@@ -217,27 +619,390 @@ impl<O: Object, M: Morphism<ObjectType = O>> Category for FinCategory<O, M> {
     }
     
     fn compose(&self, f: &Self::Mor, g: &Self::Mor) -> Result<&Self::Mor, CategoryError> {
-        // Find indices of f and g in the morphisms array
-        let f_idx = self.morphisms.iter().position(|m| 
-            m.domain().id() == f.domain().id() && 
-            m.codomain().id() == f.codomain().id()
-        );
+        // Resolve the morphisms themselves, not just their endpoints
+        let f_idx = *self.morphism_index.get(&f.id())
+            .ok_or_else(|| CategoryError::MorphismNotFound(f.id()))?;
+        let g_idx = *self.morphism_index.get(&g.id())
+            .ok_or_else(|| CategoryError::MorphismNotFound(g.id()))?;
         
-        let g_idx = self.morphisms.iter().position(|m| 
-            m.domain().id() == g.domain().id() && 
-            m.codomain().id() == g.codomain().id()
-        );
-        
-        if let (Some(f_idx), Some(g_idx)) = (f_idx, g_idx) {
-            if let Some(&comp_idx) = self.composition_map.get(&(f_idx, g_idx)) {
-                return Ok(&self.morphisms[comp_idx]);
-            }
+        if let Some(&comp_idx) = self.composition_map.get(&(f_idx, g_idx)) {
+            return Ok(&self.morphisms[comp_idx]);
         }
         
         Err(CategoryError::CompositionMismatch(format!(
-            "Cannot compose morphism from {} to {} with morphism from {} to {}",
-            f.domain().id(), f.codomain().id(),
-            g.domain().id(), g.codomain().id()
+            "Cannot compose morphism {} from {} to {} with morphism {} from {} to {}",
+            f.id(), f.domain().id(), f.codomain().id(),
+            g.id(), g.domain().id(), g.codomain().id()
         )))
     }
 }
+
+/// A user-supplied rule returning `g ∘ f` for `(f, g)`
+type CompositionFn<M> = Box<dyn Fn(&M, &M) -> Option<M>>;
+
+/// Builder for a finite category with an explicit composition table.
+///
+/// Composites are taken from identities, the explicit table, the composition
+/// function and finally from hom-sets containing a single morphism. Any
+/// disagreement between these sources, or a composable pair left without a
+/// composite, makes [`FinCategoryBuilder::build`] fail.
+pub struct FinCategoryBuilder<O: Object, M: Morphism<ObjectType = O>> {
+    name: String,
+    objects: Vec<O>,
+    morphisms: Vec<M>,
+    composites: Vec<(String, String, String)>,
+    composition_fn: Option<CompositionFn<M>>,
+}
+
+impl<O: Object, M: Morphism<ObjectType = O>> FinCategoryBuilder<O, M> {
+    /// Start building a category with the given name
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            objects: Vec::new(),
+            morphisms: Vec::new(),
+            composites: Vec::new(),
+            composition_fn: None,
+        }
+    }
+    
+    /// Add an object
+    pub fn object(mut self, obj: O) -> Self {
+        self.objects.push(obj);
+        self
+    }
+    
+    /// Add several objects
+    pub fn objects(mut self, objects: impl IntoIterator<Item = O>) -> Self {
+        self.objects.extend(objects);
+        self
+    }
+    
+    /// Add a morphism
+    pub fn morphism(mut self, morph: M) -> Self {
+        self.morphisms.push(morph);
+        self
+    }
+    
+    /// Add several morphisms
+    pub fn morphisms(mut self, morphisms: impl IntoIterator<Item = M>) -> Self {
+        self.morphisms.extend(morphisms);
+        self
+    }
+    
+    /// Declare that `g ∘ f = gf`
+    pub fn composite(mut self, f: &M, g: &M, gf: &M) -> Self {
+        self.composites.push((f.id(), g.id(), gf.id()));
+        self
+    }
+    
+    /// Compute composites with a function returning `g ∘ f` for `(f, g)`.
+    ///
+    /// The returned morphism must be one of the morphisms of the category.
+    pub fn composition_fn(mut self, compose: impl Fn(&M, &M) -> Option<M> + 'static) -> Self {
+        self.composition_fn = Some(Box::new(compose));
+        self
+    }
+    
+    /// Build the category, rejecting inconsistent or incomplete composition data
+    pub fn build(self) -> Result<FinCategory<O, M>, CategoryError> {
+        let mut object_ids = HashSet::new();
+        for obj in &self.objects {
+            if !object_ids.insert(obj.id()) {
+                return Err(CategoryError::DuplicateId(obj.id()));
+            }
+        }
+        
+        let mut morphism_ids = HashSet::new();
+        for morph in &self.morphisms {
+            if !morphism_ids.insert(morph.id()) {
+                return Err(CategoryError::DuplicateId(morph.id()));
+            }
+            for endpoint in [morph.domain(), morph.codomain()] {
+                if !object_ids.contains(&endpoint.id()) {
+                    return Err(CategoryError::ObjectNotFound(endpoint.id()));
+                }
+            }
+        }
+        
+        let mut category = FinCategory::unlinked(self.name, self.objects, self.morphisms);
+        
+        // Every object needs exactly one identity
+        for morph in category.morphisms.iter().filter(|m| m.is_identity()) {
+            let registered = category.identity_map.get(&morph.domain().id())
+                .map(|&idx| category.morphisms[idx].id());
+            if registered != Some(morph.id()) {
+                return Err(CategoryError::InconsistentComposition(format!(
+                    "{} is not the unique identity on its domain", morph.id()
+                )));
+            }
+        }
+        for obj in &category.objects {
+            category.identity(obj)?;
+        }
+        
+        let pairs = category.composable_pairs();
+        let mut composition_map = HashMap::new();
+        
+        for (f_id, g_id, gf_id) in &self.composites {
+            let lookup = |id: &String| category.morphism_index.get(id).copied()
+                .ok_or_else(|| CategoryError::MorphismNotFound(id.clone()));
+            let (i, j, k) = (lookup(f_id)?, lookup(g_id)?, lookup(gf_id)?);
+            category.insert_composite(&mut composition_map, i, j, k)?;
+        }
+        
+        if let Some(compose) = &self.composition_fn {
+            for &(i, j) in &pairs {
+                let Some(gf) = compose(&category.morphisms[i], &category.morphisms[j]) else {
+                    continue;
+                };
+                let k = *category.morphism_index.get(&gf.id())
+                    .ok_or_else(|| CategoryError::MorphismNotFound(gf.id()))?;
+                category.insert_composite(&mut composition_map, i, j, k)?;
+            }
+        }
+        
+        for &(i, j) in &pairs {
+            // Identities must act as units even if the table says otherwise
+            let forced = category.forced_composite(i, j);
+            let is_unit = category.morphisms[i].is_identity() || category.morphisms[j].is_identity();
+            
+            match (composition_map.get(&(i, j)).copied(), forced) {
+                (Some(k), Some(forced)) if is_unit && k != forced => {
+                    return Err(CategoryError::InconsistentComposition(format!(
+                        "{} followed by {} must be {}",
+                        category.morphisms[i].id(), category.morphisms[j].id(),
+                        category.morphisms[forced].id()
+                    )));
+                }
+                (Some(_), _) => {}
+                (None, Some(k)) => {
+                    composition_map.insert((i, j), k);
+                }
+                (None, None) => {
+                    return Err(CategoryError::InconsistentComposition(format!(
+                        "no composite given for {} followed by {}",
+                        category.morphisms[i].id(), category.morphisms[j].id()
+                    )));
+                }
+            }
+        }
+        category.composition_map = composition_map;
+        
+        let report = category.check_composition_associative();
+        if !report.is_lawful() {
+            return Err(CategoryError::AxiomViolation(format!("{}: {}", category.name, report)));
+        }
+        
+        Ok(category)
+    }
+}
+
+/// Small named objects and arrows for building categories by hand in tests
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::*;
+    
+    /// An object known only by its name
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Node(pub &'static str);
+    
+    impl Object for Node {
+        fn id(&self) -> String {
+            self.0.to_string()
+        }
+    }
+    
+    /// A named arrow between two nodes, acting by sending its domain to its codomain
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Edge {
+        pub name: String,
+        pub domain: Node,
+        pub codomain: Node,
+    }
+    
+    /// The arrow `name` from `domain` to `codomain`
+    pub fn edge(name: &str, domain: &'static str, codomain: &'static str) -> Edge {
+        Edge { name: name.to_string(), domain: Node(domain), codomain: Node(codomain) }
+    }
+    
+    /// The identity arrow `id_<node>`
+    pub fn identity(node: &'static str) -> Edge {
+        edge(&format!("id_{}", node), node, node)
+    }
+    
+    impl Morphism for Edge {
+        type ObjectType = Node;
+        
+        fn domain(&self) -> &Node {
+            &self.domain
+        }
+        
+        fn codomain(&self) -> &Node {
+            &self.codomain
+        }
+        
+        fn id(&self) -> String {
+            self.name.clone()
+        }
+        
+        fn is_identity(&self) -> bool {
+            self.name == format!("id_{}", self.domain.0)
+        }
+        
+        fn apply(&self, obj: &Node) -> Result<Node, CategoryError> {
+            if obj == &self.domain {
+                Ok(self.codomain.clone())
+            } else {
+                Err(CategoryError::InvalidApplication(format!("{} does not start at {}", self.name, obj.0)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[derive(Clone, Debug)]
+    struct Point;
+    
+    impl Object for Point {
+        fn id(&self) -> String {
+            "X".to_string()
+        }
+    }
+    
+    #[derive(Clone, Debug)]
+    struct Arrow(&'static str, Point);
+    
+    impl Morphism for Arrow {
+        type ObjectType = Point;
+        
+        fn domain(&self) -> &Point {
+            &self.1
+        }
+        
+        fn codomain(&self) -> &Point {
+            &self.1
+        }
+        
+        fn id(&self) -> String {
+            self.0.to_string()
+        }
+        
+        fn is_identity(&self) -> bool {
+            self.0 == "id"
+        }
+        
+        fn apply(&self, obj: &Point) -> Result<Point, CategoryError> {
+            Ok(obj.clone())
+        }
+    }
+    
+    /// The endomorphisms id, a and b of one object, composed by the given table
+    /// of (first, second) -> composite indices on top of the forced composites
+    fn monoid(table: &[((usize, usize), usize)]) -> FinCategory<Point, Arrow> {
+        let mut category = FinCategory::new(
+            "Monoid".to_string(),
+            vec![Point],
+            vec![Arrow("id", Point), Arrow("a", Point), Arrow("b", Point)],
+        );
+        category.composition_map.extend(table.iter().copied());
+        category
+    }
+    
+    #[test]
+    fn law_checker_finds_non_associative_table() {
+        let category = monoid(&[((1, 1), 2), ((1, 2), 1), ((2, 1), 2), ((2, 2), 2)]);
+        let report = category.check_laws();
+        
+        // (a a) a = b a = b but a (a a) = a b = a, and likewise for a b a
+        assert_eq!(report.violations, vec![
+            LawViolation::NonAssociative { f: 1, g: 1, h: 1 },
+            LawViolation::NonAssociative { f: 1, g: 2, h: 1 },
+        ]);
+    }
+    
+    #[test]
+    fn law_checker_finds_missing_composites_and_identities() {
+        use fixtures::{edge, identity, Node};
+        
+        // A -> B -> C with no composite A -> C and no identity on C
+        let category = FinCategory::new(
+            "Chain".to_string(),
+            vec![Node("A"), Node("B"), Node("C")],
+            vec![identity("A"), identity("B"), edge("f", "A", "B"), edge("g", "B", "C")],
+        );
+        let report = category.check_laws();
+        
+        assert!(report.violations.contains(&LawViolation::MissingIdentity { object: "C".to_string() }));
+        assert!(report.violations.contains(&LawViolation::MissingComposite { f: 2, g: 3 }));
+        assert!(FinCategory::new_checked("Chain".to_string(), category.objects, category.morphisms).is_err());
+    }
+    
+    #[test]
+    fn law_checker_finds_duplicate_ids() {
+        use fixtures::{edge, identity, Node};
+        
+        let category = FinCategory::new(
+            "Arrow".to_string(),
+            vec![Node("A"), Node("B"), Node("B")],
+            vec![identity("A"), identity("B"), edge("f", "A", "B"), edge("f", "B", "A")],
+        );
+        let report = category.check_distinct_ids();
+        
+        assert_eq!(report.violations, vec![
+            LawViolation::DuplicateObject { object: "B".to_string() },
+            LawViolation::DuplicateMorphism { morphism: 3 },
+        ]);
+        // The first morphism with a given id is the one that is found
+        assert_eq!(category.morphism("f").unwrap().codomain(), &Node("B"));
+        assert!(FinCategory::new_checked("Arrow".to_string(), category.objects, category.morphisms).is_err());
+    }
+    
+    #[test]
+    fn law_checker_finds_broken_identities() {
+        let lawful = monoid(&[((1, 1), 2), ((1, 2), 2), ((2, 1), 2), ((2, 2), 2)]);
+        assert!(lawful.check_laws().is_lawful());
+        
+        let broken = monoid(&[((0, 1), 2), ((1, 0), 2), ((1, 1), 2), ((1, 2), 2), ((2, 1), 2), ((2, 2), 2)]);
+        let report = broken.check_identity_laws();
+        assert!(report.violations.contains(&LawViolation::LeftIdentity { morphism: 1 }));
+        assert!(report.violations.contains(&LawViolation::RightIdentity { morphism: 1 }));
+        assert!(!report.violations.iter().any(|v| matches!(v, LawViolation::MissingIdentity { .. })));
+    }
+    
+    #[test]
+    fn builder_keeps_parallel_composites_apart() {
+        use fixtures::{edge, identity, Node};
+        
+        // Two parallel arrows A -> B, each followed by h, land on different arrows A -> C
+        let (f, g, h) = (edge("f", "A", "B"), edge("g", "A", "B"), edge("h", "B", "C"));
+        let (hf, hg) = (edge("hf", "A", "C"), edge("hg", "A", "C"));
+        let builder = || FinCategoryBuilder::new("Parallel".to_string())
+            .objects([Node("A"), Node("B"), Node("C")])
+            .morphisms([identity("A"), identity("B"), identity("C")])
+            .morphisms([f.clone(), g.clone(), h.clone(), hf.clone(), hg.clone()]);
+        
+        let category = builder().composite(&f, &h, &hf).composite(&g, &h, &hg).build().unwrap();
+        assert_eq!(category.compose(&f, &h).unwrap().id(), "hf");
+        assert_eq!(category.compose(&g, &h).unwrap().id(), "hg");
+        assert_eq!(category.compose(&identity("A"), &g).unwrap().id(), "g");
+        assert_eq!(category.hom(&Node("A"), &Node("C")).len(), 2);
+        assert!(category.check_laws().is_lawful());
+        
+        // The composite of g and h is ambiguous without the table
+        let incomplete = builder().composite(&f, &h, &hf).build();
+        assert!(matches!(incomplete, Err(CategoryError::InconsistentComposition(_))));
+        
+        let contradictory = builder().composite(&f, &h, &hf).composite(&f, &h, &hg).composite(&g, &h, &hg).build();
+        assert!(matches!(contradictory, Err(CategoryError::InconsistentComposition(_))));
+        
+        let not_a_unit = builder().composite(&f, &h, &hf).composite(&g, &h, &hg).composite(&identity("A"), &f, &g).build();
+        assert!(matches!(not_a_unit, Err(CategoryError::InconsistentComposition(_))));
+        
+        let duplicate = builder().morphism(edge("f", "B", "C")).build();
+        assert!(matches!(duplicate, Err(CategoryError::DuplicateId(id)) if id == "f"));
+    }
+}
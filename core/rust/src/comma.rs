@@ -0,0 +1,472 @@
+use crate::category::{Category, CategoryError, FinCategory, FinCategoryBuilder, Morphism, Object};
+use crate::functor::Functor;
+
+/// An object of a comma category: a pair of objects and an arrow F(a) -> G(b)
+#[derive(Clone, Debug)]
+pub struct CommaObject<A: Object, B: Object, H: Morphism> {
+    /// Object of the first source category
+    pub source: A,
+    
+    /// Object of the second source category
+    pub target: B,
+    
+    /// Arrow from the image of `source` to the image of `target`
+    pub arrow: H,
+}
+
+impl<A: Object, B: Object, H: Morphism> Object for CommaObject<A, B, H> {
+    fn id(&self) -> String {
+        format!("({}, {}, {})", self.source.id(), self.target.id(), self.arrow.id())
+    }
+    
+    fn label(&self) -> String {
+        format!("({}, {}, {})", self.source.label(), self.target.label(), self.arrow.label())
+    }
+}
+
+/// A morphism of a comma category: a pair of morphisms making the square commute
+#[derive(Clone, Debug)]
+pub struct CommaMorphism<F: Morphism, G: Morphism, H: Morphism> {
+    /// Component in the first source category
+    pub source: F,
+    
+    /// Component in the second source category
+    pub target: G,
+    
+    domain: CommaObject<F::ObjectType, G::ObjectType, H>,
+    codomain: CommaObject<F::ObjectType, G::ObjectType, H>,
+}
+
+impl<F: Morphism, G: Morphism, H: Morphism> Morphism for CommaMorphism<F, G, H> {
+    type ObjectType = CommaObject<F::ObjectType, G::ObjectType, H>;
+    
+    fn domain(&self) -> &Self::ObjectType {
+        &self.domain
+    }
+    
+    fn codomain(&self) -> &Self::ObjectType {
+        &self.codomain
+    }
+    
+    fn id(&self) -> String {
+        // The components alone do not fix the arrows at either end
+        format!("({}, {}):{}->{}", self.source.id(), self.target.id(), self.domain.id(), self.codomain.id())
+    }
+    
+    fn is_identity(&self) -> bool {
+        self.source.is_identity() && self.target.is_identity()
+    }
+    
+    fn apply(&self, obj: &Self::ObjectType) -> Result<Self::ObjectType, CategoryError> {
+        // The components act on their own; the arrow of the image is the declared one
+        Ok(CommaObject {
+            source: self.source.apply(&obj.source)?,
+            target: self.target.apply(&obj.target)?,
+            arrow: self.codomain.arrow.clone(),
+        })
+    }
+}
+
+/// An object of a slice category: a morphism into the base object
+#[derive(Clone, Debug)]
+pub struct SliceObject<M: Morphism> {
+    /// The structure morphism a -> X
+    pub arrow: M,
+}
+
+impl<M: Morphism> Object for SliceObject<M> {
+    fn id(&self) -> String {
+        format!("{}/{}", self.arrow.domain().id(), self.arrow.id())
+    }
+    
+    fn label(&self) -> String {
+        format!("{}/{}", self.arrow.domain().label(), self.arrow.label())
+    }
+    
+    fn dimension(&self) -> Option<usize> {
+        self.arrow.domain().dimension()
+    }
+}
+
+/// A morphism of a slice category: a morphism commuting with the structure morphisms
+#[derive(Clone, Debug)]
+pub struct SliceMorphism<M: Morphism> {
+    /// The underlying morphism
+    pub inner: M,
+    
+    domain: SliceObject<M>,
+    codomain: SliceObject<M>,
+}
+
+impl<M: Morphism> Morphism for SliceMorphism<M> {
+    type ObjectType = SliceObject<M>;
+    
+    fn domain(&self) -> &Self::ObjectType {
+        &self.domain
+    }
+    
+    fn codomain(&self) -> &Self::ObjectType {
+        &self.codomain
+    }
+    
+    fn id(&self) -> String {
+        format!("{}:{}->{}", self.inner.id(), self.domain.id(), self.codomain.id())
+    }
+    
+    fn is_identity(&self) -> bool {
+        self.inner.is_identity()
+    }
+    
+    fn label(&self) -> String {
+        self.inner.label()
+    }
+    
+    fn apply(&self, obj: &Self::ObjectType) -> Result<Self::ObjectType, CategoryError> {
+        // The structure morphism of the image is not determined by the action alone
+        let image = self.inner.apply(obj.arrow.domain())?;
+        if image.id() != self.codomain.arrow.domain().id() {
+            return Err(CategoryError::InvalidApplication(format!(
+                "{} sends {} outside its codomain", self.id(), obj.id()
+            )));
+        }
+        Ok(self.codomain.clone())
+    }
+}
+
+/// Objects of the comma category of two functors
+type CommaOb<F, G> = CommaObject<
+    <<F as Functor>::Source as Category>::Ob,
+    <<G as Functor>::Source as Category>::Ob,
+    <<F as Functor>::Target as Category>::Mor,
+>;
+
+/// Morphisms of the comma category of two functors
+type CommaMor<F, G> = CommaMorphism<
+    <<F as Functor>::Source as Category>::Mor,
+    <<G as Functor>::Source as Category>::Mor,
+    <<F as Functor>::Target as Category>::Mor,
+>;
+
+/// The composite of `f` followed by `g`, if the category has one.
+///
+/// A category built with `FinCategory::new` only stores the composites its
+/// structure forces, so a missing composite means there is no such arrow
+/// rather than an error.
+fn composite<'a, C: Category>(category: &'a C, f: &C::Mor, g: &C::Mor) -> Option<&'a C::Mor> {
+    category.compose(f, g).ok()
+}
+
+/// Whether `f` followed by `g` equals `h` followed by `k` in a category
+fn square_commutes<C: Category>(
+    category: &C,
+    f: &C::Mor,
+    g: &C::Mor,
+    h: &C::Mor,
+    k: &C::Mor,
+) -> bool {
+    match (composite(category, f, g), composite(category, h, k)) {
+        (Some(fg), Some(hk)) => fg.id() == hk.id(),
+        _ => false,
+    }
+}
+
+/// The morphisms of a category from `domain` to `codomain`
+fn hom_set<'a, C: Category>(category: &'a C, domain: &C::Ob, codomain: &C::Ob) -> Vec<&'a C::Mor> {
+    category.morphisms().iter()
+        .filter(|m| m.domain().id() == domain.id() && m.codomain().id() == codomain.id())
+        .collect()
+}
+
+/// The comma category (F ↓ G) of two functors F: A → C and G: B → C.
+///
+/// Objects are triples (a, b, h: F(a) → G(b)) and morphisms are pairs
+/// (f: a → a', g: b → b') with F(f) followed by h' equal to h followed by G(g).
+#[derive(Clone, Debug)]
+pub struct CommaCategory<F, G>
+where
+    F: Functor + Clone,
+    G: Functor<Target = F::Target> + Clone,
+{
+    left: F,
+    right: G,
+    inner: FinCategory<CommaOb<F, G>, CommaMor<F, G>>,
+}
+
+impl<F, G> CommaCategory<F, G>
+where
+    F: Functor + Clone,
+    G: Functor<Target = F::Target> + Clone,
+{
+    /// Form (F ↓ G) from the functors and their source and target categories
+    pub fn new(
+        left: F,
+        right: G,
+        left_source: &F::Source,
+        right_source: &G::Source,
+        target: &F::Target,
+    ) -> Result<Self, CategoryError> {
+        let mut objects = Vec::new();
+        for a in left_source.objects() {
+            for b in right_source.objects() {
+                let (fa, gb) = (left.map_object(a), right.map_object(b));
+                for h in hom_set(target, &fa, &gb) {
+                    objects.push(CommaObject { source: a.clone(), target: b.clone(), arrow: h.clone() });
+                }
+            }
+        }
+        
+        let mut morphisms = Vec::new();
+        for x in &objects {
+            for y in &objects {
+                for f in hom_set(left_source, &x.source, &y.source) {
+                    let ff = left.map_morphism(f)?;
+                    for g in hom_set(right_source, &x.target, &y.target) {
+                        let gg = right.map_morphism(g)?;
+                        if square_commutes(target, &ff, &y.arrow, &x.arrow, &gg) {
+                            morphisms.push(CommaMorphism {
+                                source: f.clone(),
+                                target: g.clone(),
+                                domain: x.clone(),
+                                codomain: y.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        
+        // Compose componentwise; pairs whose components have no composite are
+        // left to the builder, which fails if nothing else fixes them
+        let mut builder = FinCategoryBuilder::new("Comma".to_string()).objects(objects);
+        for p in &morphisms {
+            for q in morphisms.iter().filter(|q| p.codomain().id() == q.domain().id()) {
+                let components = (
+                    composite(left_source, &p.source, &q.source),
+                    composite(right_source, &p.target, &q.target),
+                );
+                if let (Some(source), Some(target)) = components {
+                    let qp = CommaMorphism {
+                        source: source.clone(),
+                        target: target.clone(),
+                        domain: p.domain.clone(),
+                        codomain: q.codomain.clone(),
+                    };
+                    builder = builder.composite(p, q, &qp);
+                }
+            }
+        }
+        
+        let inner = builder.morphisms(morphisms).build()?;
+        Ok(Self { left, right, inner })
+    }
+    
+    /// The functor on the left of the comma
+    pub fn left(&self) -> &F {
+        &self.left
+    }
+    
+    /// The functor on the right of the comma
+    pub fn right(&self) -> &G {
+        &self.right
+    }
+}
+
+impl<F, G> Category for CommaCategory<F, G>
+where
+    F: Functor + Clone,
+    G: Functor<Target = F::Target> + Clone,
+{
+    type Ob = CommaOb<F, G>;
+    type Mor = CommaMor<F, G>;
+    
+    fn objects(&self) -> &[Self::Ob] {
+        self.inner.objects()
+    }
+    
+    fn morphisms(&self) -> &[Self::Mor] {
+        self.inner.morphisms()
+    }
+    
+    fn identity(&self, obj: &Self::Ob) -> Result<&Self::Mor, CategoryError> {
+        self.inner.identity(obj)
+    }
+    
+    fn compose(&self, f: &Self::Mor, g: &Self::Mor) -> Result<&Self::Mor, CategoryError> {
+        self.inner.compose(f, g)
+    }
+}
+
+/// The slice category C/X of objects over a fixed object X.
+///
+/// Objects are morphisms a → X and morphisms are the f: a → a' that commute
+/// with them, e.g. every configuration that coarse-grains onto a given one.
+#[derive(Clone, Debug)]
+pub struct SliceCategory<C: Category> {
+    base: C::Ob,
+    inner: FinCategory<SliceObject<C::Mor>, SliceMorphism<C::Mor>>,
+}
+
+impl<C: Category> SliceCategory<C> {
+    /// Form the slice of a category over one of its objects
+    pub fn new(category: &C, base: C::Ob) -> Result<Self, CategoryError> {
+        let objects: Vec<SliceObject<C::Mor>> = category.morphisms().iter()
+            .filter(|h| h.codomain().id() == base.id())
+            .map(|h| SliceObject { arrow: h.clone() })
+            .collect();
+        
+        let mut morphisms = Vec::new();
+        for x in &objects {
+            for y in &objects {
+                for f in hom_set(category, x.arrow.domain(), y.arrow.domain()) {
+                    if composite(category, f, &y.arrow).is_some_and(|h| h.id() == x.arrow.id()) {
+                        morphisms.push(SliceMorphism {
+                            inner: f.clone(),
+                            domain: x.clone(),
+                            codomain: y.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        
+        let mut builder = FinCategoryBuilder::new(format!("Slice over {}", base.id())).objects(objects);
+        for f in &morphisms {
+            for g in morphisms.iter().filter(|g| f.codomain().id() == g.domain().id()) {
+                if let Some(inner) = composite(category, &f.inner, &g.inner) {
+                    let gf = SliceMorphism {
+                        inner: inner.clone(),
+                        domain: f.domain.clone(),
+                        codomain: g.codomain.clone(),
+                    };
+                    builder = builder.composite(f, g, &gf);
+                }
+            }
+        }
+        
+        let inner = builder.morphisms(morphisms).build()?;
+        Ok(Self { base, inner })
+    }
+    
+    /// The object this category is sliced over
+    pub fn base(&self) -> &C::Ob {
+        &self.base
+    }
+}
+
+impl<C: Category> Category for SliceCategory<C> {
+    type Ob = SliceObject<C::Mor>;
+    type Mor = SliceMorphism<C::Mor>;
+    
+    fn objects(&self) -> &[Self::Ob] {
+        self.inner.objects()
+    }
+    
+    fn morphisms(&self) -> &[Self::Mor] {
+        self.inner.morphisms()
+    }
+    
+    fn identity(&self, obj: &Self::Ob) -> Result<&Self::Mor, CategoryError> {
+        self.inner.identity(obj)
+    }
+    
+    fn compose(&self, f: &Self::Mor, g: &Self::Mor) -> Result<&Self::Mor, CategoryError> {
+        self.inner.compose(f, g)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::fixtures::{edge, identity, Edge, Node};
+    use crate::functor::{ConcreteFunctor, IdentityFunctor};
+    use crate::macroscopic::create_macroscopic_category;
+    
+    type Graph = FinCategory<Node, Edge>;
+    
+    /// A single arrow x: 0 -> 1
+    fn arrow() -> Graph {
+        FinCategory::new(
+            "Arrow".to_string(),
+            vec![Node("0"), Node("1")],
+            vec![identity("0"), identity("1"), edge("x", "0", "1")],
+        )
+    }
+    
+    #[test]
+    fn slice_over_demo_category() {
+        let category = create_macroscopic_category();
+        let base = category.objects()[1].clone();
+        let slice = SliceCategory::new(&category, base).unwrap();
+        
+        // The identity on the base and the defect motion into it, joined by the motion
+        assert_eq!(slice.objects().len(), 2);
+        assert_eq!(slice.morphisms().len(), 3);
+        assert!(slice.check_laws().is_lawful());
+    }
+    
+    #[test]
+    fn comma_of_two_functors_into_an_arrow() {
+        let category = arrow();
+        let point = FinCategory::new("Point".to_string(), vec![Node("*")], vec![identity("*")]);
+        
+        // (0 ↓ Id): arrows out of 0, joined by the squares under x
+        let start = ConcreteFunctor::new(
+            "Start".to_string(),
+            point.clone(),
+            category.clone(),
+            |_| Node("0"),
+            |_| Ok(identity("0")),
+        );
+        let under = CommaCategory::new(start, IdentityFunctor::<Graph>::new(), &point, &category, &category).unwrap();
+        let ids: Vec<String> = under.objects().iter().map(|obj| obj.id()).collect();
+        assert_eq!(ids, vec!["(*, 0, id_0)", "(*, 1, x)"]);
+        assert_eq!(under.morphisms().len(), 3);
+        assert!(under.morphisms().iter().any(|m| m.id() == "(id_*, x):(*, 0, id_0)->(*, 1, x)"));
+        assert!(under.check_laws().is_lawful());
+        
+        // (Id ↓ Id) is the arrow category: one object per arrow and one morphism per commuting square
+        let arrows = CommaCategory::new(
+            IdentityFunctor::<Graph>::new(),
+            IdentityFunctor::<Graph>::new(),
+            &category,
+            &category,
+            &category,
+        ).unwrap();
+        let ids: Vec<String> = arrows.objects().iter().map(|obj| obj.id()).collect();
+        assert_eq!(ids, vec!["(0, 0, id_0)", "(0, 1, x)", "(1, 1, id_1)"]);
+        let squares: Vec<String> = arrows.morphisms().iter()
+            .filter(|m| !m.is_identity())
+            .map(|m| m.id())
+            .collect();
+        assert_eq!(squares, vec![
+            "(id_0, x):(0, 0, id_0)->(0, 1, x)",
+            "(x, x):(0, 0, id_0)->(1, 1, id_1)",
+            "(x, id_1):(0, 1, x)->(1, 1, id_1)",
+        ]);
+        assert!(arrows.check_laws().is_lawful());
+        
+        // Following the first square with the last one goes all the way
+        let (first, last) = (&arrows.morphisms()[1], &arrows.morphisms()[4]);
+        assert_eq!(arrows.compose(first, last).unwrap().id(), squares[1]);
+    }
+    
+    #[test]
+    fn slice_skips_composites_the_category_leaves_open() {
+        // Two arrows A -> X leave the composite of A -> B -> X unforced
+        let category = FinCategory::new("Chain".to_string(), vec![Node("A"), Node("B"), Node("X")], vec![
+            identity("A"),
+            identity("B"),
+            identity("X"),
+            edge("f", "A", "B"),
+            edge("g", "B", "X"),
+            edge("h", "A", "X"),
+            edge("k", "A", "X"),
+        ]);
+        
+        let slice = SliceCategory::new(&category, Node("X")).unwrap();
+        let through_b = |f: &&SliceMorphism<Edge>| f.inner.domain() == &Node("A") && f.inner.codomain() == &Node("B");
+        assert_eq!(slice.objects().len(), 4);
+        assert_eq!(slice.morphisms().iter().filter(through_b).count(), 0);
+        assert_eq!(slice.morphisms().len(), 7);
+    }
+}
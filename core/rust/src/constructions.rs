@@ -0,0 +1,656 @@
+use crate::category::{Category, CategoryError, FinCategory, FinCategoryBuilder, Morphism, Object};
+use crate::functor::Functor;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+impl<A: Object, B: Object> Object for (A, B) {
+    fn id(&self) -> String {
+        format!("({}, {})", self.0.id(), self.1.id())
+    }
+    
+    fn label(&self) -> String {
+        format!("({}, {})", self.0.label(), self.1.label())
+    }
+    
+    fn dimension(&self) -> Option<usize> {
+        Some(self.0.dimension()? + self.1.dimension()?)
+    }
+}
+
+/// An object or morphism of a coproduct, tagged with the side it comes from
+#[derive(Clone, Debug, PartialEq)]
+pub enum Sum<A, B> {
+    Left(A),
+    Right(B),
+}
+
+impl<A: Object, B: Object> Object for Sum<A, B> {
+    fn id(&self) -> String {
+        match self {
+            Sum::Left(a) => format!("inl({})", a.id()),
+            Sum::Right(b) => format!("inr({})", b.id()),
+        }
+    }
+    
+    fn label(&self) -> String {
+        match self {
+            Sum::Left(a) => format!("inl({})", a.label()),
+            Sum::Right(b) => format!("inr({})", b.label()),
+        }
+    }
+    
+    fn dimension(&self) -> Option<usize> {
+        match self {
+            Sum::Left(a) => a.dimension(),
+            Sum::Right(b) => b.dimension(),
+        }
+    }
+}
+
+/// A pair of morphisms acting on a pair of objects
+#[derive(Clone, Debug)]
+pub struct ProductMorphism<F: Morphism, G: Morphism> {
+    /// Component in the first category
+    pub left: F,
+    
+    /// Component in the second category
+    pub right: G,
+    
+    domain: (F::ObjectType, G::ObjectType),
+    codomain: (F::ObjectType, G::ObjectType),
+}
+
+impl<F: Morphism, G: Morphism> ProductMorphism<F, G> {
+    /// Pair two morphisms
+    pub fn new(left: F, right: G) -> Self {
+        let domain = (left.domain().clone(), right.domain().clone());
+        let codomain = (left.codomain().clone(), right.codomain().clone());
+        Self { left, right, domain, codomain }
+    }
+}
+
+impl<F: Morphism, G: Morphism> Morphism for ProductMorphism<F, G> {
+    type ObjectType = (F::ObjectType, G::ObjectType);
+    
+    fn domain(&self) -> &Self::ObjectType {
+        &self.domain
+    }
+    
+    fn codomain(&self) -> &Self::ObjectType {
+        &self.codomain
+    }
+    
+    fn id(&self) -> String {
+        format!("({}, {})", self.left.id(), self.right.id())
+    }
+    
+    fn is_identity(&self) -> bool {
+        self.left.is_identity() && self.right.is_identity()
+    }
+    
+    fn label(&self) -> String {
+        format!("({}, {})", self.left.label(), self.right.label())
+    }
+    
+    fn apply(&self, obj: &Self::ObjectType) -> Result<Self::ObjectType, CategoryError> {
+        Ok((self.left.apply(&obj.0)?, self.right.apply(&obj.1)?))
+    }
+}
+
+/// A morphism of one side of a coproduct
+#[derive(Clone, Debug)]
+pub struct SumMorphism<F: Morphism, G: Morphism> {
+    /// The underlying morphism
+    pub inner: Sum<F, G>,
+    
+    domain: Sum<F::ObjectType, G::ObjectType>,
+    codomain: Sum<F::ObjectType, G::ObjectType>,
+}
+
+impl<F: Morphism, G: Morphism> SumMorphism<F, G> {
+    /// Include a morphism of the first category
+    pub fn left(f: F) -> Self {
+        let domain = Sum::Left(f.domain().clone());
+        let codomain = Sum::Left(f.codomain().clone());
+        Self { inner: Sum::Left(f), domain, codomain }
+    }
+    
+    /// Include a morphism of the second category
+    pub fn right(g: G) -> Self {
+        let domain = Sum::Right(g.domain().clone());
+        let codomain = Sum::Right(g.codomain().clone());
+        Self { inner: Sum::Right(g), domain, codomain }
+    }
+}
+
+impl<F: Morphism, G: Morphism> Morphism for SumMorphism<F, G> {
+    type ObjectType = Sum<F::ObjectType, G::ObjectType>;
+    
+    fn domain(&self) -> &Self::ObjectType {
+        &self.domain
+    }
+    
+    fn codomain(&self) -> &Self::ObjectType {
+        &self.codomain
+    }
+    
+    fn id(&self) -> String {
+        match &self.inner {
+            Sum::Left(f) => format!("inl({})", f.id()),
+            Sum::Right(g) => format!("inr({})", g.id()),
+        }
+    }
+    
+    fn is_identity(&self) -> bool {
+        match &self.inner {
+            Sum::Left(f) => f.is_identity(),
+            Sum::Right(g) => g.is_identity(),
+        }
+    }
+    
+    fn label(&self) -> String {
+        match &self.inner {
+            Sum::Left(f) => f.label(),
+            Sum::Right(g) => g.label(),
+        }
+    }
+    
+    fn apply(&self, obj: &Self::ObjectType) -> Result<Self::ObjectType, CategoryError> {
+        match (&self.inner, obj) {
+            (Sum::Left(f), Sum::Left(a)) => Ok(Sum::Left(f.apply(a)?)),
+            (Sum::Right(g), Sum::Right(b)) => Ok(Sum::Right(g.apply(b)?)),
+            _ => Err(CategoryError::InvalidApplication(format!(
+                "{} cannot act on an object of the other summand {}", self.id(), obj.id()
+            ))),
+        }
+    }
+}
+
+/// A morphism with its direction reversed
+#[derive(Clone, Debug)]
+pub struct OppositeMorphism<M: Morphism> {
+    /// The morphism of the original category
+    pub inner: M,
+}
+
+impl<M: Morphism> Morphism for OppositeMorphism<M> {
+    type ObjectType = M::ObjectType;
+    
+    fn domain(&self) -> &Self::ObjectType {
+        self.inner.codomain()
+    }
+    
+    fn codomain(&self) -> &Self::ObjectType {
+        self.inner.domain()
+    }
+    
+    fn id(&self) -> String {
+        format!("op({})", self.inner.id())
+    }
+    
+    fn is_identity(&self) -> bool {
+        self.inner.is_identity()
+    }
+    
+    fn label(&self) -> String {
+        format!("op({})", self.inner.label())
+    }
+    
+    fn apply(&self, obj: &Self::ObjectType) -> Result<Self::ObjectType, CategoryError> {
+        // Undo the inner action. Its inverse is only known at the image of the
+        // inner domain, and only means anything if the inner morphism is invertible.
+        let image = self.inner.apply(self.inner.domain())?;
+        if image.id() != self.inner.codomain().id() {
+            return Err(CategoryError::InvalidApplication(format!(
+                "{} cannot undo {}, which does not act as declared", self.id(), self.inner.id()
+            )));
+        }
+        if obj.id() != image.id() {
+            return Err(CategoryError::InvalidApplication(format!(
+                "{} only undoes its action on {}, not on {}", self.id(), image.id(), obj.id()
+            )));
+        }
+        Ok(self.inner.domain().clone())
+    }
+}
+
+/// Finite category of pairs that stores a product
+type ProductStorage<C, D> = FinCategory<
+    (<C as Category>::Ob, <D as Category>::Ob),
+    ProductMorphism<<C as Category>::Mor, <D as Category>::Mor>,
+>;
+
+/// Finite category of tagged objects that stores a coproduct
+type CoproductStorage<C, D> = FinCategory<
+    Sum<<C as Category>::Ob, <D as Category>::Ob>,
+    SumMorphism<<C as Category>::Mor, <D as Category>::Mor>,
+>;
+
+/// The product C × D of two categories
+#[derive(Clone, Debug)]
+pub struct ProductCategory<C: Category, D: Category> {
+    left: C,
+    right: D,
+    inner: ProductStorage<C, D>,
+}
+
+impl<C: Category, D: Category> ProductCategory<C, D> {
+    /// Form the product of two categories, composing componentwise
+    pub fn new(left: C, right: D) -> Result<Self, CategoryError> {
+        let objects: Vec<(C::Ob, D::Ob)> = left.objects().iter()
+            .flat_map(|a| right.objects().iter().map(move |b| (a.clone(), b.clone())))
+            .collect();
+        let morphisms: Vec<ProductMorphism<C::Mor, D::Mor>> = left.morphisms().iter()
+            .flat_map(|f| right.morphisms().iter().map(move |g| ProductMorphism::new(f.clone(), g.clone())))
+            .collect();
+        
+        let mut builder = FinCategoryBuilder::new("Product".to_string()).objects(objects);
+        for f in &morphisms {
+            for g in &morphisms {
+                if f.codomain().id() != g.domain().id() {
+                    continue;
+                }
+                let gf = ProductMorphism::new(
+                    left.compose(&f.left, &g.left)?.clone(),
+                    right.compose(&f.right, &g.right)?.clone(),
+                );
+                builder = builder.composite(f, g, &gf);
+            }
+        }
+        
+        let inner = builder.morphisms(morphisms).build()?;
+        Ok(Self { left, right, inner })
+    }
+    
+    /// The first factor
+    pub fn left(&self) -> &C {
+        &self.left
+    }
+    
+    /// The second factor
+    pub fn right(&self) -> &D {
+        &self.right
+    }
+    
+    /// The projection onto the first factor
+    pub fn left_projection(&self) -> LeftProjection<C, D> {
+        LeftProjection::new()
+    }
+    
+    /// The projection onto the second factor
+    pub fn right_projection(&self) -> RightProjection<C, D> {
+        RightProjection::new()
+    }
+}
+
+impl<C: Category, D: Category> Category for ProductCategory<C, D> {
+    type Ob = (C::Ob, D::Ob);
+    type Mor = ProductMorphism<C::Mor, D::Mor>;
+    
+    fn objects(&self) -> &[Self::Ob] {
+        self.inner.objects()
+    }
+    
+    fn morphisms(&self) -> &[Self::Mor] {
+        self.inner.morphisms()
+    }
+    
+    fn identity(&self, obj: &Self::Ob) -> Result<&Self::Mor, CategoryError> {
+        self.inner.identity(obj)
+    }
+    
+    fn compose(&self, f: &Self::Mor, g: &Self::Mor) -> Result<&Self::Mor, CategoryError> {
+        self.inner.compose(f, g)
+    }
+}
+
+/// The coproduct C + D of two categories, with no morphisms between the two sides
+#[derive(Clone, Debug)]
+pub struct CoproductCategory<C: Category, D: Category> {
+    left: C,
+    right: D,
+    inner: CoproductStorage<C, D>,
+}
+
+impl<C: Category, D: Category> CoproductCategory<C, D> {
+    /// Form the disjoint union of two categories
+    pub fn new(left: C, right: D) -> Result<Self, CategoryError> {
+        let objects = left.objects().iter().cloned().map(Sum::Left)
+            .chain(right.objects().iter().cloned().map(Sum::Right));
+        
+        let mut builder = FinCategoryBuilder::new("Coproduct".to_string()).objects(objects);
+        for f in left.morphisms() {
+            builder = builder.morphism(SumMorphism::left(f.clone()));
+            for g in left.morphisms().iter().filter(|g| f.codomain().id() == g.domain().id()) {
+                let gf = left.compose(f, g)?;
+                builder = builder.composite(
+                    &SumMorphism::left(f.clone()),
+                    &SumMorphism::left(g.clone()),
+                    &SumMorphism::left(gf.clone()),
+                );
+            }
+        }
+        for f in right.morphisms() {
+            builder = builder.morphism(SumMorphism::right(f.clone()));
+            for g in right.morphisms().iter().filter(|g| f.codomain().id() == g.domain().id()) {
+                let gf = right.compose(f, g)?;
+                builder = builder.composite(
+                    &SumMorphism::right(f.clone()),
+                    &SumMorphism::right(g.clone()),
+                    &SumMorphism::right(gf.clone()),
+                );
+            }
+        }
+        
+        let inner = builder.build()?;
+        Ok(Self { left, right, inner })
+    }
+    
+    /// The first summand
+    pub fn left(&self) -> &C {
+        &self.left
+    }
+    
+    /// The second summand
+    pub fn right(&self) -> &D {
+        &self.right
+    }
+    
+    /// The inclusion of the first summand
+    pub fn left_injection(&self) -> LeftInjection<C, D> {
+        LeftInjection::new()
+    }
+    
+    /// The inclusion of the second summand
+    pub fn right_injection(&self) -> RightInjection<C, D> {
+        RightInjection::new()
+    }
+}
+
+impl<C: Category, D: Category> Category for CoproductCategory<C, D> {
+    type Ob = Sum<C::Ob, D::Ob>;
+    type Mor = SumMorphism<C::Mor, D::Mor>;
+    
+    fn objects(&self) -> &[Self::Ob] {
+        self.inner.objects()
+    }
+    
+    fn morphisms(&self) -> &[Self::Mor] {
+        self.inner.morphisms()
+    }
+    
+    fn identity(&self, obj: &Self::Ob) -> Result<&Self::Mor, CategoryError> {
+        self.inner.identity(obj)
+    }
+    
+    fn compose(&self, f: &Self::Mor, g: &Self::Mor) -> Result<&Self::Mor, CategoryError> {
+        self.inner.compose(f, g)
+    }
+}
+
+/// The opposite category, e.g. refinement instead of coarse-graining
+#[derive(Clone, Debug)]
+pub struct Opposite<C: Category> {
+    original: C,
+    inner: FinCategory<C::Ob, OppositeMorphism<C::Mor>>,
+}
+
+impl<C: Category> Opposite<C> {
+    /// Reverse every morphism of a category
+    pub fn new(original: C) -> Result<Self, CategoryError> {
+        let mut builder = FinCategoryBuilder::new("Opposite".to_string())
+            .objects(original.objects().iter().cloned());
+        
+        for f in original.morphisms() {
+            builder = builder.morphism(OppositeMorphism { inner: f.clone() });
+            
+            // f° followed by g° is (f ∘ g)°, i.e. g followed by f in the original
+            for g in original.morphisms().iter().filter(|g| g.codomain().id() == f.domain().id()) {
+                let fg = original.compose(g, f)?;
+                builder = builder.composite(
+                    &OppositeMorphism { inner: f.clone() },
+                    &OppositeMorphism { inner: g.clone() },
+                    &OppositeMorphism { inner: fg.clone() },
+                );
+            }
+        }
+        
+        let inner = builder.build()?;
+        Ok(Self { original, inner })
+    }
+    
+    /// The category before reversing its morphisms
+    pub fn original(&self) -> &C {
+        &self.original
+    }
+}
+
+impl<C: Category> Category for Opposite<C> {
+    type Ob = C::Ob;
+    type Mor = OppositeMorphism<C::Mor>;
+    
+    fn objects(&self) -> &[Self::Ob] {
+        self.inner.objects()
+    }
+    
+    fn morphisms(&self) -> &[Self::Mor] {
+        self.inner.morphisms()
+    }
+    
+    fn identity(&self, obj: &Self::Ob) -> Result<&Self::Mor, CategoryError> {
+        self.inner.identity(obj)
+    }
+    
+    fn compose(&self, f: &Self::Mor, g: &Self::Mor) -> Result<&Self::Mor, CategoryError> {
+        self.inner.compose(f, g)
+    }
+}
+
+/// Projection C × D → C
+#[derive(Clone, Debug)]
+pub struct LeftProjection<C: Category, D: Category> {
+    _phantom: PhantomData<(C, D)>,
+}
+
+impl<C: Category, D: Category> LeftProjection<C, D> {
+    /// Create the projection onto the first factor
+    pub fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+impl<C: Category, D: Category> Default for LeftProjection<C, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Category, D: Category> Functor for LeftProjection<C, D> {
+    type Source = ProductCategory<C, D>;
+    type Target = C;
+    
+    fn map_object(&self, obj: &(C::Ob, D::Ob)) -> C::Ob {
+        obj.0.clone()
+    }
+    
+    fn map_morphism(&self, morph: &ProductMorphism<C::Mor, D::Mor>) -> Result<C::Mor, CategoryError> {
+        Ok(morph.left.clone())
+    }
+}
+
+/// Projection C × D → D
+#[derive(Clone, Debug)]
+pub struct RightProjection<C: Category, D: Category> {
+    _phantom: PhantomData<(C, D)>,
+}
+
+impl<C: Category, D: Category> RightProjection<C, D> {
+    /// Create the projection onto the second factor
+    pub fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+impl<C: Category, D: Category> Default for RightProjection<C, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Category, D: Category> Functor for RightProjection<C, D> {
+    type Source = ProductCategory<C, D>;
+    type Target = D;
+    
+    fn map_object(&self, obj: &(C::Ob, D::Ob)) -> D::Ob {
+        obj.1.clone()
+    }
+    
+    fn map_morphism(&self, morph: &ProductMorphism<C::Mor, D::Mor>) -> Result<D::Mor, CategoryError> {
+        Ok(morph.right.clone())
+    }
+}
+
+/// Injection C → C + D
+#[derive(Clone, Debug)]
+pub struct LeftInjection<C: Category, D: Category> {
+    _phantom: PhantomData<(C, D)>,
+}
+
+impl<C: Category, D: Category> LeftInjection<C, D> {
+    /// Create the inclusion of the first summand
+    pub fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+impl<C: Category, D: Category> Default for LeftInjection<C, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Category, D: Category> Functor for LeftInjection<C, D> {
+    type Source = C;
+    type Target = CoproductCategory<C, D>;
+    
+    fn map_object(&self, obj: &C::Ob) -> Sum<C::Ob, D::Ob> {
+        Sum::Left(obj.clone())
+    }
+    
+    fn map_morphism(&self, morph: &C::Mor) -> Result<SumMorphism<C::Mor, D::Mor>, CategoryError> {
+        Ok(SumMorphism::left(morph.clone()))
+    }
+}
+
+/// Injection D → C + D
+#[derive(Clone, Debug)]
+pub struct RightInjection<C: Category, D: Category> {
+    _phantom: PhantomData<(C, D)>,
+}
+
+impl<C: Category, D: Category> RightInjection<C, D> {
+    /// Create the inclusion of the second summand
+    pub fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+impl<C: Category, D: Category> Default for RightInjection<C, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Category, D: Category> Functor for RightInjection<C, D> {
+    type Source = D;
+    type Target = CoproductCategory<C, D>;
+    
+    fn map_object(&self, obj: &D::Ob) -> Sum<C::Ob, D::Ob> {
+        Sum::Right(obj.clone())
+    }
+    
+    fn map_morphism(&self, morph: &D::Mor) -> Result<SumMorphism<C::Mor, D::Mor>, CategoryError> {
+        Ok(SumMorphism::right(morph.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::fixtures::{edge, identity, Edge, Node};
+    use crate::category::FinCategory;
+    
+    /// The arrow `f: A -> B`
+    fn arrow() -> FinCategory<Node, Edge> {
+        FinCategory::new(
+            "Arrow".to_string(),
+            vec![Node("A"), Node("B")],
+            vec![identity("A"), identity("B"), edge("f", "A", "B")],
+        )
+    }
+    
+    /// The composable pair `g: X -> Y`, `h: Y -> Z` with composite `hg`
+    fn chain() -> FinCategory<Node, Edge> {
+        FinCategory::new(
+            "Chain".to_string(),
+            vec![Node("X"), Node("Y"), Node("Z")],
+            vec![
+                identity("X"), identity("Y"), identity("Z"),
+                edge("g", "X", "Y"), edge("h", "Y", "Z"), edge("hg", "X", "Z"),
+            ],
+        )
+    }
+    
+    #[test]
+    fn product_is_lawful_and_projects_onto_its_factors() {
+        let product = ProductCategory::new(arrow(), chain()).unwrap();
+        
+        assert_eq!(product.objects().len(), 6);
+        assert_eq!(product.morphisms().len(), 3 * 6);
+        assert!(product.check_laws().is_lawful());
+        
+        // (f, g) followed by (id_B, h) is (f, hg)
+        let fg = product.morphisms().iter().find(|m| m.id() == "(f, g)").unwrap();
+        let h = product.morphisms().iter().find(|m| m.id() == "(id_B, h)").unwrap();
+        assert_eq!(product.compose(fg, h).unwrap().id(), "(f, hg)");
+        
+        assert!(product.left_projection().verify_functor_laws(&product, product.left()).is_lawful());
+        assert!(product.right_projection().verify_functor_laws(&product, product.right()).is_lawful());
+    }
+    
+    #[test]
+    fn coproduct_is_lawful_and_includes_its_summands() {
+        let coproduct = CoproductCategory::new(arrow(), chain()).unwrap();
+        
+        assert_eq!(coproduct.objects().len(), 2 + 3);
+        assert_eq!(coproduct.morphisms().len(), 3 + 6);
+        assert!(coproduct.check_laws().is_lawful());
+        
+        // Nothing connects the two sides
+        let a = Sum::Left(Node("A"));
+        assert!(coproduct.morphisms().iter()
+            .filter(|m| m.domain() == &a)
+            .all(|m| matches!(m.codomain(), Sum::Left(_))));
+        
+        assert!(coproduct.left_injection().verify_functor_laws(coproduct.left(), &coproduct).is_lawful());
+        assert!(coproduct.right_injection().verify_functor_laws(coproduct.right(), &coproduct).is_lawful());
+    }
+    
+    #[test]
+    fn opposite_morphisms_undo_invertible_actions() {
+        let category = FinCategory::new(
+            "Arrow".to_string(),
+            vec![Node("A"), Node("B")],
+            vec![identity("A"), identity("B"), edge("f", "A", "B")],
+        );
+        let opposite = Opposite::new(category.clone()).unwrap();
+        
+        assert!(category.check_actions().is_lawful());
+        assert!(opposite.check_actions().is_lawful());
+        
+        // op(f) takes B back to A and has no action anywhere else
+        let back = opposite.morphisms().iter().find(|f| !f.is_identity()).unwrap();
+        assert_eq!(back.apply(&Node("B")).unwrap(), Node("A"));
+        assert!(back.apply(back.codomain()).is_err());
+    }
+}
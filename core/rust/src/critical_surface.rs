@@ -0,0 +1,287 @@
+use crate::rg_flow::{IntegrationOptions, ParameterSpace, RGFixedPoint, RGFlow, RGFlowError, Relevance};
+use nalgebra::DVector;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Options for [`trace_critical_surface`]
+#[derive(Clone, Debug)]
+pub struct CriticalSurfaceOptions {
+    /// Distance from the fixed point at which the backward flows start
+    pub offset: f64,
+    
+    /// Number of backward flows; the first two per irrelevant direction go along ±v,
+    /// the rest along random combinations
+    pub directions: usize,
+    
+    /// How far in ℓ to flow backwards
+    pub length: f64,
+    
+    /// Drop samples further than this from the fixed point
+    pub max_distance: f64,
+    
+    /// Seed for the random combinations of irrelevant directions
+    pub seed: u64,
+    
+    /// Options for the backward flow; `sample_interval` sets the spacing of samples
+    pub integration: IntegrationOptions,
+}
+
+impl Default for CriticalSurfaceOptions {
+    fn default() -> Self {
+        Self {
+            offset: 1e-4,
+            directions: 16,
+            length: 10.0,
+            max_distance: 1e3,
+            seed: 0,
+            integration: IntegrationOptions { sample_interval: Some(0.1), ..IntegrationOptions::default() },
+        }
+    }
+}
+
+/// The stable manifold of a fixed point, sampled along backward flows
+#[derive(Clone, Debug)]
+pub struct CriticalSurface<P: ParameterSpace> {
+    /// The fixed point the surface flows into
+    pub fixed_point: P,
+    
+    /// Irrelevant eigendirections spanning the surface at the fixed point
+    pub tangent: Vec<DVector<f64>>,
+    
+    /// Backward trajectories from the fixed point, each lying in the surface
+    pub curves: Vec<Vec<P>>,
+}
+
+impl<P: ParameterSpace> CriticalSurface<P> {
+    /// All sampled points of the surface
+    pub fn points(&self) -> impl Iterator<Item = &P> {
+        self.curves.iter().flatten()
+    }
+    
+    /// Number of sampled points
+    pub fn len(&self) -> usize {
+        self.curves.iter().map(Vec::len).sum()
+    }
+    
+    /// Whether the surface has no samples
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Sample the critical surface of `fixed_point`: the initial couplings that flow into it.
+///
+/// Starting just off the fixed point along its irrelevant directions, the flow
+/// is run backwards; relevant perturbations shrink in that direction, so the
+/// trajectories stay on the surface as they move away from the fixed point.
+/// Marginal directions and complex eigenvalue pairs are left out.
+pub fn trace_critical_surface<P, R>(
+    flow: &R,
+    fixed_point: &RGFixedPoint<P>,
+    options: &CriticalSurfaceOptions,
+) -> Result<CriticalSurface<P>, RGFlowError>
+where
+    P: ParameterSpace,
+    R: RGFlow<P> + ?Sized,
+{
+    let tangent: Vec<DVector<f64>> = fixed_point.directions.iter()
+        .filter(|direction| direction.relevance == Relevance::Irrelevant)
+        .filter_map(|direction| direction.eigenvector.clone())
+        .collect();
+    if tangent.is_empty() {
+        return Err(RGFlowError::ParameterOutOfRange(
+            "Fixed point has no real irrelevant directions".to_string()
+        ));
+    }
+    
+    // ±v for each irrelevant direction, then random combinations
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    let starts: Vec<DVector<f64>> = (0..options.directions)
+        .map(|index| {
+            if index < 2 * tangent.len() {
+                let sign = if index % 2 == 0 { 1.0 } else { -1.0 };
+                &tangent[index / 2] * sign
+            } else {
+                tangent.iter()
+                    .fold(DVector::zeros(tangent[0].len()), |sum, v| sum + v * rng.gen_range(-1.0..1.0))
+            }
+        })
+        .filter(|v| v.norm() > 0.0)
+        .map(|v| fixed_point.parameters.as_vector() + v.normalize() * options.offset)
+        .collect();
+    
+    let dim = flow.spatial_dimension();
+    let mut curves = Vec::with_capacity(starts.len());
+    for start in starts {
+        let start = P::from_vector(start, dim)?;
+        let trajectory = flow.integrate(&start, -options.length.abs(), &options.integration)?;
+        let curve: Vec<P> = trajectory.points.into_iter()
+            .take_while(|p| p.distance(&fixed_point.parameters) <= options.max_distance)
+            .collect();
+        curves.push(curve);
+    }
+    
+    Ok(CriticalSurface {
+        fixed_point: fixed_point.parameters.clone(),
+        tangent,
+        curves,
+    })
+}
+
+/// Options for [`critical_value`]
+#[derive(Clone, Debug)]
+pub struct SeparatrixOptions {
+    /// How far in ℓ to flow each trial point
+    pub length: f64,
+    
+    /// A flow this close to the fixed point is on the critical surface
+    pub capture_radius: f64,
+    
+    /// Stop bisecting once the bracket is narrower than this, or once it
+    /// cannot be split in floating point; must not be negative
+    pub tolerance: f64,
+    
+    /// Options for the forward flow
+    pub integration: IntegrationOptions,
+}
+
+impl Default for SeparatrixOptions {
+    fn default() -> Self {
+        Self {
+            length: 30.0,
+            capture_radius: 1e-6,
+            tolerance: 1e-8,
+            integration: IntegrationOptions::default(),
+        }
+    }
+}
+
+/// Which side of the critical surface of `fixed_point` the flow from `start` leaves on.
+///
+/// Returns the sign of the final displacement along the most relevant direction,
+/// or zero if the flow is captured by the fixed point.
+pub fn separatrix_side<P, R>(
+    flow: &R,
+    fixed_point: &RGFixedPoint<P>,
+    start: &P,
+    options: &SeparatrixOptions,
+) -> Result<f64, RGFlowError>
+where
+    P: ParameterSpace,
+    R: RGFlow<P> + ?Sized,
+{
+    let relevant = fixed_point.directions.iter()
+        .find(|direction| direction.relevance == Relevance::Relevant)
+        .and_then(|direction| direction.eigenvector.as_ref())
+        .ok_or_else(|| RGFlowError::ParameterOutOfRange(
+            "Fixed point has no real relevant direction".to_string()
+        ))?;
+    
+    // Wherever the flow stopped, including at a blow-up, its last point shows the side
+    let trajectory = flow.integrate(start, options.length, &options.integration)?;
+    let end = trajectory.points.last().unwrap_or(start);
+    
+    if end.distance(&fixed_point.parameters) < options.capture_radius {
+        return Ok(0.0);
+    }
+    let displacement = relevant.dot(&(end.as_vector() - fixed_point.parameters.as_vector()));
+    Ok(if displacement > 0.0 { 1.0 } else if displacement < 0.0 { -1.0 } else { 0.0 })
+}
+
+/// Where the critical surface of `fixed_point` crosses the line through `start`
+/// along coupling `parameter`, searching between `lower` and `upper`.
+///
+/// With `parameter` the temperature this gives the transition temperature at
+/// the other couplings of `start`. Returns `None` if both ends of the bracket
+/// flow off on the same side.
+pub fn critical_value<P, R>(
+    flow: &R,
+    fixed_point: &RGFixedPoint<P>,
+    start: &P,
+    parameter: usize,
+    lower: f64,
+    upper: f64,
+    options: &SeparatrixOptions,
+) -> Result<Option<f64>, RGFlowError>
+where
+    P: ParameterSpace,
+    R: RGFlow<P> + ?Sized,
+{
+    let n = start.dimension();
+    if parameter >= n {
+        return Err(RGFlowError::ParameterOutOfRange(
+            format!("Parameter {} of a {}-dimensional flow", parameter, n)
+        ));
+    }
+    if options.tolerance.is_nan() || options.tolerance < 0.0 {
+        return Err(RGFlowError::ParameterOutOfRange(
+            format!("Bisection tolerance must not be negative, got {}", options.tolerance)
+        ));
+    }
+    
+    let dim = flow.spatial_dimension();
+    let side_at = |value: f64| -> Result<f64, RGFlowError> {
+        let mut v = start.as_vector();
+        v[parameter] = value;
+        separatrix_side(flow, fixed_point, &P::from_vector(v, dim)?, options)
+    };
+    
+    let (mut lo, mut hi) = (lower, upper);
+    let lo_side = side_at(lo)?;
+    let hi_side = side_at(hi)?;
+    if lo_side == 0.0 {
+        return Ok(Some(lo));
+    }
+    if hi_side == 0.0 {
+        return Ok(Some(hi));
+    }
+    if lo_side == hi_side {
+        return Ok(None);
+    }
+    
+    while (hi - lo).abs() > options.tolerance {
+        let mid = 0.5 * (lo + hi);
+        // Adjacent floats: the bracket cannot shrink any further
+        if mid == lo || mid == hi {
+            break;
+        }
+        let side = side_at(mid)?;
+        if side == 0.0 {
+            return Ok(Some(mid));
+        }
+        if side == lo_side {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    
+    Ok(Some(0.5 * (lo + hi)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rg_flow::fixtures::{saddle_flow, Couplings};
+    
+    #[test]
+    fn critical_surface_of_saddle_is_the_line_g1_zero() {
+        // The saddle at g₁ = 0 attracts along g₂ and repels along g₁
+        let flow = saddle_flow();
+        let saddle = flow.analyze_fixed_point(&Couplings::new(&[0.0, 0.0, 0.7])).unwrap();
+        
+        let surface = trace_critical_surface(&flow, &saddle, &CriticalSurfaceOptions::default()).unwrap();
+        assert_eq!(surface.tangent.len(), 1);
+        assert_eq!(surface.curves.len(), 16);
+        assert!(surface.points().all(|p| p.0[0].abs() < 1e-9 && (p.0[2] - 0.7).abs() < 1e-12));
+        let reach = surface.points().map(|p| p.0[1].abs()).fold(0.0, f64::max);
+        assert!(reach > 1.0, "backward flows only reached |g₂| = {}", reach);
+        
+        // Along g₁ the separatrix is crossed at g₁ = 0, whatever g₂
+        let start = Couplings::new(&[0.3, 0.5, 0.7]);
+        let options = SeparatrixOptions::default();
+        let critical = critical_value(&flow, &saddle, &start, 0, -0.3, 0.4, &options).unwrap().unwrap();
+        assert!(critical.abs() < 1e-6, "critical g₁ = {}", critical);
+        assert_eq!(critical_value(&flow, &saddle, &start, 0, 0.1, 0.4, &options).unwrap(), None);
+    }
+}
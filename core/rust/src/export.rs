@@ -0,0 +1,299 @@
+use crate::category::{Category, Morphism, Object};
+use crate::functor::{ConcreteFunctor, Functor};
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+/// What to include when exporting a category
+#[derive(Clone, Debug, Default)]
+pub struct ExportOptions {
+    /// Draw identity morphisms as loops in DOT output
+    pub identities: bool,
+    
+    /// Include the composites of non-identity morphisms
+    pub compositions: bool,
+}
+
+/// Data format for an object of an exported category
+#[derive(Serialize, Deserialize)]
+pub struct ObjectData {
+    pub id: String,
+    pub label: String,
+    pub dimension: Option<usize>,
+}
+
+/// Data format for a morphism of an exported category
+#[derive(Serialize, Deserialize)]
+pub struct MorphismData {
+    pub id: String,
+    pub label: String,
+    pub domain: String,
+    pub codomain: String,
+    pub identity: bool,
+}
+
+/// Data format for one entry of a composition table, `first` applied before `second`
+#[derive(Serialize, Deserialize)]
+pub struct CompositionData {
+    pub first: String,
+    pub second: String,
+    pub composite: String,
+}
+
+/// Data format for an exported category
+#[derive(Serialize, Deserialize)]
+pub struct CategoryData {
+    pub name: String,
+    pub objects: Vec<ObjectData>,
+    pub morphisms: Vec<MorphismData>,
+    pub compositions: Vec<CompositionData>,
+    pub metadata: HashMap<String, String>,
+}
+
+/// Escape backslashes and double quotes for use inside a quoted DOT string
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Quote a string for use as a DOT identifier or label
+fn quote(s: &str) -> String {
+    format!("\"{}\"", escape(s))
+}
+
+/// Composites of non-identity morphisms that the category defines
+fn composition_table<C: Category>(category: &C) -> Vec<(&C::Mor, &C::Mor, &C::Mor)> {
+    let morphisms = category.morphisms();
+    let mut table = Vec::new();
+    
+    for f in morphisms.iter().filter(|f| !f.is_identity()) {
+        for g in morphisms.iter().filter(|g| !g.is_identity()) {
+            if f.codomain().id() != g.domain().id() {
+                continue;
+            }
+            // Categories built without a full table may leave some composites undefined
+            if let Ok(gf) = category.compose(f, g) {
+                table.push((f, g, gf));
+            }
+        }
+    }
+    
+    table
+}
+
+/// Write the nodes and edges of a category, returning the node name of each object ID
+fn write_category_body<C: Category>(
+    out: &mut String,
+    category: &C,
+    prefix: &str,
+    indent: &str,
+    options: &ExportOptions,
+) -> HashMap<String, String> {
+    let mut nodes = HashMap::new();
+    
+    for (idx, obj) in category.objects().iter().enumerate() {
+        let node = format!("{}{}", prefix, idx);
+        out.push_str(&format!("{}{} [label={}, tooltip={}];\n", indent, node, quote(&obj.label()), quote(&obj.id())));
+        nodes.insert(obj.id(), node);
+    }
+    
+    for morph in category.morphisms() {
+        if morph.is_identity() && !options.identities {
+            continue;
+        }
+        let (Some(source), Some(target)) = (nodes.get(&morph.domain().id()), nodes.get(&morph.codomain().id())) else {
+            continue;
+        };
+        out.push_str(&format!(
+            "{}{} -> {} [label={}, tooltip={}];\n",
+            indent, source, target, quote(&morph.label()), quote(&morph.id())
+        ));
+    }
+    
+    if options.compositions {
+        let rows: Vec<String> = composition_table(category).iter()
+            .map(|(f, g, gf)| format!("{} ; {} = {}", f.label(), g.label(), gf.label()))
+            .collect();
+        if !rows.is_empty() {
+            let label = rows.iter().map(|row| format!("{}\\l", escape(row))).collect::<String>();
+            out.push_str(&format!("{}{}compositions [shape=note, label=\"{}\"];\n", indent, prefix, label));
+        }
+    }
+    
+    nodes
+}
+
+/// Render a category as a GraphViz DOT digraph.
+///
+/// Objects and morphisms are drawn with their labels, with their full IDs as
+/// tooltips.
+pub fn category_to_dot<C: Category>(category: &C, name: &str, options: &ExportOptions) -> String {
+    let mut out = format!("digraph {} {{\n", quote(name));
+    out.push_str("    node [shape=box];\n");
+    write_category_body(&mut out, category, "o", "    ", options);
+    out.push_str("}\n");
+    out
+}
+
+/// Collect a category into a serializable form.
+///
+/// All morphisms are listed, identities included; the composition table is
+/// only filled in if requested.
+pub fn category_to_data<C: Category>(category: &C, name: &str, options: &ExportOptions) -> CategoryData {
+    let objects = category.objects().iter()
+        .map(|obj| ObjectData { id: obj.id(), label: obj.label(), dimension: obj.dimension() })
+        .collect();
+    
+    let morphisms = category.morphisms().iter()
+        .map(|morph| MorphismData {
+            id: morph.id(),
+            label: morph.label(),
+            domain: morph.domain().id(),
+            codomain: morph.codomain().id(),
+            identity: morph.is_identity(),
+        })
+        .collect();
+    
+    let compositions = if options.compositions {
+        composition_table(category).iter()
+            .map(|(f, g, gf)| CompositionData { first: f.id(), second: g.id(), composite: gf.id() })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    
+    let mut metadata = HashMap::new();
+    metadata.insert("object_count".to_string(), category.objects().len().to_string());
+    metadata.insert("morphism_count".to_string(), category.morphisms().len().to_string());
+    metadata.insert("visualization_type".to_string(), "category".to_string());
+    
+    CategoryData {
+        name: name.to_string(),
+        objects,
+        morphisms,
+        compositions,
+        metadata,
+    }
+}
+
+/// Render a functor as a DOT digraph with one cluster per category and
+/// dashed arrows from each source object to its image.
+///
+/// Images that are not objects of the target category are drawn as dashed nodes.
+pub fn functor_to_dot<F: Functor>(
+    functor: &F,
+    name: &str,
+    source: &F::Source,
+    target: &F::Target,
+    options: &ExportOptions,
+) -> String {
+    let mut out = format!("digraph {} {{\n", quote(name));
+    out.push_str("    compound=true;\n");
+    out.push_str("    node [shape=box];\n");
+    
+    out.push_str("    subgraph cluster_source {\n");
+    out.push_str("        label=\"source\";\n");
+    let source_nodes = write_category_body(&mut out, source, "s", "        ", options);
+    out.push_str("    }\n");
+    
+    out.push_str("    subgraph cluster_target {\n");
+    out.push_str("        label=\"target\";\n");
+    let mut target_nodes = write_category_body(&mut out, target, "t", "        ", options);
+    let mut missing = 0;
+    for obj in source.objects() {
+        let image = functor.map_object(obj);
+        if let Entry::Vacant(entry) = target_nodes.entry(image.id()) {
+            let node = format!("x{}", missing);
+            out.push_str(&format!(
+                "        {} [label={}, tooltip={}, style=dashed];\n",
+                node, quote(&image.label()), quote(entry.key())
+            ));
+            entry.insert(node);
+            missing += 1;
+        }
+    }
+    out.push_str("    }\n");
+    
+    for obj in source.objects() {
+        let image = functor.map_object(obj).id();
+        out.push_str(&format!(
+            "    {} -> {} [style=dashed, color=gray, constraint=false];\n",
+            source_nodes[&obj.id()], target_nodes[&image]
+        ));
+    }
+    
+    out.push_str("}\n");
+    out
+}
+
+/// Render a concrete functor between its own source and target categories
+pub fn concrete_functor_to_dot<S: Category, T: Category>(
+    functor: &ConcreteFunctor<S, T>,
+    options: &ExportOptions,
+) -> String {
+    functor_to_dot(functor, functor.name(), functor.source_category(), functor.target_category(), options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::fixtures::{edge, identity, Node};
+    use crate::category::FinCategory;
+    
+    #[test]
+    fn exports_escape_labels_and_list_requested_composites() {
+        let chain = FinCategory::new(
+            "Chain".to_string(),
+            vec![Node("A"), Node("B"), Node("C \"end\"")],
+            vec![
+                identity("A"), identity("B"), identity("C \"end\""),
+                edge("f", "A", "B"), edge("g", "B", "C \"end\""), edge("gf", "A", "C \"end\""),
+            ],
+        );
+        
+        let plain = category_to_dot(&chain, "Chain", &ExportOptions::default());
+        assert!(plain.starts_with("digraph \"Chain\" {\n"));
+        assert!(plain.contains("    o2 [label=\"C \\\"end\\\"\", tooltip=\"C \\\"end\\\"\"];\n"));
+        assert!(plain.contains("    o0 -> o1 [label=\"f\", tooltip=\"f\"];\n"));
+        assert!(!plain.contains("o0 -> o0") && !plain.contains("compositions"));
+        
+        let full = ExportOptions { identities: true, compositions: true };
+        let dot = category_to_dot(&chain, "Chain", &full);
+        assert!(dot.contains("    o0 -> o0 [label=\"id_A\", tooltip=\"id_A\"];\n"));
+        assert!(dot.contains("ocompositions [shape=note, label=\"f ; g = gf\\l\"];"));
+        
+        let data = category_to_data(&chain, "Chain", &full);
+        let json: serde_json::Value = serde_json::from_str(&serde_json::to_string(&data).unwrap()).unwrap();
+        assert_eq!(json["morphisms"].as_array().unwrap().len(), 6);
+        assert_eq!(json["morphisms"][0]["identity"], true);
+        assert_eq!(json["compositions"][0]["composite"], "gf");
+        assert_eq!(json["metadata"]["object_count"], "3");
+        
+        // The image of C lies outside the target, so it is drawn dashed
+        let target = FinCategory::new("Target".to_string(), vec![Node("A"), Node("B")], vec![identity("A"), identity("B")]);
+        let functor = ConcreteFunctor::new(
+            "Drop".to_string(),
+            chain,
+            target,
+            |obj: &Node| obj.clone(),
+            |morph| Ok(morph.clone()),
+        );
+        let dot = concrete_functor_to_dot(&functor, &ExportOptions::default());
+        assert!(dot.contains("        x0 [label=\"C \\\"end\\\"\", tooltip=\"C \\\"end\\\"\", style=dashed];\n"));
+        assert!(dot.contains("    s2 -> x0 [style=dashed, color=gray, constraint=false];\n"));
+    }
+    
+    #[test]
+    fn composition_note_escapes_backslashes() {
+        let chain = FinCategory::new(
+            "Chain".to_string(),
+            vec![Node("A"), Node("B"), Node("C")],
+            vec![
+                identity("A"), identity("B"), identity("C"),
+                edge("f\\", "A", "B"), edge("\"g\"", "B", "C"), edge("gf", "A", "C"),
+            ],
+        );
+        let options = ExportOptions { identities: false, compositions: true };
+        let dot = category_to_dot(&chain, "Chain", &options);
+        assert!(dot.contains("ocompositions [shape=note, label=\"f\\\\ ; \\\"g\\\" = gf\\l\"];"));
+    }
+}
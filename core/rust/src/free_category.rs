@@ -0,0 +1,412 @@
+use crate::category::{CategoryError, FinCategory, FinCategoryBuilder, Morphism, Object};
+use std::collections::HashMap;
+
+/// A composable sequence of generating morphisms, applied first to last
+#[derive(Clone, Debug)]
+pub struct Path<M: Morphism> {
+    /// Start of the path
+    domain: M::ObjectType,
+    
+    /// End of the path
+    codomain: M::ObjectType,
+    
+    /// Generators along the path, in the order they are applied
+    steps: Vec<M>,
+}
+
+impl<M: Morphism> Path<M> {
+    /// The empty path at an object
+    pub fn empty(obj: M::ObjectType) -> Self {
+        Self {
+            domain: obj.clone(),
+            codomain: obj,
+            steps: Vec::new(),
+        }
+    }
+    
+    /// The generators along this path, in the order they are applied
+    pub fn steps(&self) -> &[M] {
+        &self.steps
+    }
+    
+    /// Number of generators in this path
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+    
+    /// Whether this is an empty path
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl<M: Morphism> Morphism for Path<M> {
+    type ObjectType = M::ObjectType;
+    
+    fn domain(&self) -> &Self::ObjectType {
+        &self.domain
+    }
+    
+    fn codomain(&self) -> &Self::ObjectType {
+        &self.codomain
+    }
+    
+    fn id(&self) -> String {
+        if self.steps.is_empty() {
+            return format!("id[{}]", self.domain.id());
+        }
+        self.steps.iter().map(|m| m.id()).collect::<Vec<_>>().join(" ; ")
+    }
+    
+    fn is_identity(&self) -> bool {
+        self.steps.is_empty()
+    }
+    
+    fn label(&self) -> String {
+        if self.steps.is_empty() {
+            return "Identity".to_string();
+        }
+        self.steps.iter().map(|m| m.label()).collect::<Vec<_>>().join(" ; ")
+    }
+    
+    fn apply(&self, obj: &Self::ObjectType) -> Result<Self::ObjectType, CategoryError> {
+        self.steps.iter().try_fold(obj.clone(), |current, step| step.apply(&current))
+    }
+}
+
+/// An equation between two parallel paths of generators.
+///
+/// An empty side stands for the identity on the endpoint of the other side.
+pub type PathEquation<M> = (Vec<M>, Vec<M>);
+
+/// A path as a start object and generator indices
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Word {
+    start: usize,
+    steps: Vec<usize>,
+}
+
+/// Directed graph of generators, indexed for path enumeration
+struct Graph<'a, O: Object, M: Morphism<ObjectType = O>> {
+    objects: &'a [O],
+    generators: &'a [M],
+    sources: Vec<usize>,
+    targets: Vec<usize>,
+}
+
+impl<'a, O: Object, M: Morphism<ObjectType = O>> Graph<'a, O, M> {
+    fn new(objects: &'a [O], generators: &'a [M]) -> Result<Self, CategoryError> {
+        let object_index: HashMap<String, usize> = objects.iter()
+            .enumerate()
+            .map(|(idx, obj)| (obj.id(), idx))
+            .collect();
+        let lookup = |obj: &O| object_index.get(&obj.id()).copied()
+            .ok_or_else(|| CategoryError::ObjectNotFound(obj.id()));
+        
+        let mut sources = Vec::with_capacity(generators.len());
+        let mut targets = Vec::with_capacity(generators.len());
+        for generator in generators {
+            sources.push(lookup(generator.domain())?);
+            targets.push(lookup(generator.codomain())?);
+        }
+        
+        Ok(Self { objects, generators, sources, targets })
+    }
+    
+    /// Object reached after following the first `k` steps of a word
+    fn object_at(&self, word: &Word, k: usize) -> usize {
+        if k == 0 { word.start } else { self.targets[word.steps[k - 1]] }
+    }
+    
+    /// End object of a word
+    fn end(&self, word: &Word) -> usize {
+        self.object_at(word, word.steps.len())
+    }
+    
+    /// Turn one side of an equation into a word, or `None` if it is empty
+    fn word(&self, side: &[M]) -> Result<Option<Word>, CategoryError> {
+        let generator_index: HashMap<String, usize> = self.generators.iter()
+            .enumerate()
+            .map(|(idx, m)| (m.id(), idx))
+            .collect();
+        
+        let mut steps: Vec<usize> = Vec::with_capacity(side.len());
+        for m in side {
+            let idx = *generator_index.get(&m.id())
+                .ok_or_else(|| CategoryError::MorphismNotFound(m.id()))?;
+            if let Some(&previous) = steps.last() {
+                if self.targets[previous] != self.sources[idx] {
+                    return Err(CategoryError::CompositionMismatch(format!(
+                        "{} does not start where {} ends", m.id(), self.generators[previous].id()
+                    )));
+                }
+            }
+            steps.push(idx);
+        }
+        
+        match steps.first() {
+            Some(&first) => Ok(Some(Word { start: self.sources[first], steps })),
+            None => Ok(None),
+        }
+    }
+    
+    /// All words of length at most `max_length`, shortest first
+    fn words_up_to(&self, max_length: usize) -> Vec<Word> {
+        let mut words: Vec<Word> = (0..self.objects.len())
+            .map(|start| Word { start, steps: Vec::new() })
+            .collect();
+        
+        let mut frontier = words.clone();
+        for _ in 0..max_length {
+            let mut next = Vec::new();
+            for word in &frontier {
+                let end = self.end(word);
+                for (idx, &source) in self.sources.iter().enumerate() {
+                    if source == end {
+                        let mut steps = word.steps.clone();
+                        steps.push(idx);
+                        next.push(Word { start: word.start, steps });
+                    }
+                }
+            }
+            words.extend(next.iter().cloned());
+            frontier = next;
+        }
+        
+        words
+    }
+    
+    /// Every word obtained from `word` by replacing one occurrence of `lhs` with `rhs`
+    fn rewrites(&self, word: &Word, lhs: &Word, rhs: &Word) -> Vec<Word> {
+        let n = lhs.steps.len();
+        let mut results = Vec::new();
+        if n > word.steps.len() {
+            return results;
+        }
+        
+        for k in 0..=(word.steps.len() - n) {
+            let matches = if n == 0 {
+                self.object_at(word, k) == lhs.start
+            } else {
+                word.steps[k..k + n] == lhs.steps[..]
+            };
+            if matches {
+                let mut steps = word.steps[..k].to_vec();
+                steps.extend_from_slice(&rhs.steps);
+                steps.extend_from_slice(&word.steps[k + n..]);
+                results.push(Word { start: word.start, steps });
+            }
+        }
+        
+        results
+    }
+    
+    /// The path a word stands for
+    fn path(&self, word: &Word) -> Path<M> {
+        Path {
+            domain: self.objects[word.start].clone(),
+            codomain: self.objects[self.end(word)].clone(),
+            steps: word.steps.iter().map(|&idx| self.generators[idx].clone()).collect(),
+        }
+    }
+}
+
+/// Find the representative of `x` in a union-find forest
+fn find(parent: &mut [usize], x: usize) -> usize {
+    let mut root = x;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    let mut current = x;
+    while parent[current] != root {
+        let next = parent[current];
+        parent[current] = root;
+        current = next;
+    }
+    root
+}
+
+impl<O: Object, M: Morphism<ObjectType = O>> FinCategory<O, Path<M>> {
+    /// Create the category of paths in a directed graph of generators modulo path equations.
+    ///
+    /// The morphisms are the classes of paths of at most `max_length` generators,
+    /// where two paths are identified if a chain of substitutions of one side of
+    /// an equation for the other links them. The chain may pass through paths
+    /// longer than the bound, so paths are enumerated up to `max_length` plus the
+    /// longest side of an equation to find it. Each morphism of the result is the
+    /// shortest path of its class. Composites whose representatives are longer
+    /// than the enumerated paths are reduced with the length-decreasing direction
+    /// of the equations; if that fails, or a composite is only equal to paths
+    /// longer than `max_length`, the category is not finite within the bound and
+    /// an error is returned.
+    pub fn free(
+        name: String,
+        objects: Vec<O>,
+        generators: Vec<M>,
+        relations: Vec<PathEquation<M>>,
+        max_length: usize,
+    ) -> Result<Self, CategoryError> {
+        let graph = Graph::new(&objects, &generators)?;
+        
+        // Orient each equation in both directions
+        let mut rules = Vec::new();
+        for (lhs, rhs) in &relations {
+            let (left, right) = match (graph.word(lhs)?, graph.word(rhs)?) {
+                (Some(left), Some(right)) => (left, right),
+                (Some(left), None) => {
+                    let right = Word { start: left.start, steps: Vec::new() };
+                    (left, right)
+                }
+                (None, Some(right)) => {
+                    let left = Word { start: right.start, steps: Vec::new() };
+                    (left, right)
+                }
+                (None, None) => {
+                    return Err(CategoryError::InconsistentComposition(
+                        "path equation with two empty sides".to_string()
+                    ));
+                }
+            };
+            
+            if left.start != right.start || graph.end(&left) != graph.end(&right) {
+                return Err(CategoryError::CompositionMismatch(format!(
+                    "sides of path equation are not parallel: {} and {}",
+                    graph.path(&left).id(), graph.path(&right).id()
+                )));
+            }
+            rules.push((left.clone(), right.clone()));
+            rules.push((right, left));
+        }
+        
+        // Identify words related by a single substitution, looking past the bound
+        // for the words that link shorter ones
+        let longest_side = rules.iter().map(|(lhs, _)| lhs.steps.len()).max().unwrap_or(0);
+        let words = graph.words_up_to(max_length + longest_side);
+        let index: HashMap<Word, usize> = words.iter()
+            .cloned()
+            .enumerate()
+            .map(|(idx, word)| (word, idx))
+            .collect();
+        let mut parent: Vec<usize> = (0..words.len()).collect();
+        
+        for (idx, word) in words.iter().enumerate() {
+            for (lhs, rhs) in &rules {
+                for rewritten in graph.rewrites(word, lhs, rhs) {
+                    if let Some(&other) = index.get(&rewritten) {
+                        let (a, b) = (find(&mut parent, idx), find(&mut parent, other));
+                        parent[a.max(b)] = a.min(b);
+                    }
+                }
+            }
+        }
+        
+        // Words are enumerated shortest first, so the root of each class is its
+        // shortest member, and classes without a member within the bound are left out
+        let mut representatives = Vec::new();
+        let mut class_index = HashMap::new();
+        let class_of: Vec<Option<usize>> = (0..words.len())
+            .map(|idx| {
+                let root = find(&mut parent, idx);
+                if words[root].steps.len() > max_length {
+                    return None;
+                }
+                Some(*class_index.entry(root).or_insert_with(|| {
+                    representatives.push(idx);
+                    representatives.len() - 1
+                }))
+            })
+            .collect();
+        
+        // Compose representatives, shortening composites that leave the bound
+        let shortening: Vec<&(Word, Word)> = rules.iter()
+            .filter(|(lhs, rhs)| rhs.steps.len() < lhs.steps.len())
+            .collect();
+        let reduce = |mut word: Word| -> Option<usize> {
+            loop {
+                if let Some(&idx) = index.get(&word) {
+                    return class_of[idx];
+                }
+                let shorter = shortening.iter()
+                    .flat_map(|(lhs, rhs)| graph.rewrites(&word, lhs, rhs))
+                    .next()?;
+                word = shorter;
+            }
+        };
+        
+        let paths: Vec<Path<M>> = representatives.iter()
+            .map(|&idx| graph.path(&words[idx]))
+            .collect();
+        let mut builder = FinCategoryBuilder::new(name).objects(objects.iter().cloned());
+        
+        for (f_class, &f_idx) in representatives.iter().enumerate() {
+            for (g_class, &g_idx) in representatives.iter().enumerate() {
+                let (f, g) = (&words[f_idx], &words[g_idx]);
+                if graph.end(f) != g.start {
+                    continue;
+                }
+                
+                let mut steps = f.steps.clone();
+                steps.extend_from_slice(&g.steps);
+                let gf_class = reduce(Word { start: f.start, steps }).ok_or_else(|| {
+                    CategoryError::InconsistentComposition(format!(
+                        "composite of {} followed by {} is longer than {} generators",
+                        paths[f_class].id(), paths[g_class].id(), max_length
+                    ))
+                })?;
+                builder = builder.composite(&paths[f_class], &paths[g_class], &paths[gf_class]);
+            }
+        }
+        
+        builder.morphisms(paths.iter().cloned()).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::fixtures::{edge, Edge, Node};
+    use crate::category::Category;
+    
+    /// Generators p: A → B, q: B → C, r: C → D, x: A → C and s: B → D with
+    /// x = p ; q and q ; r = s
+    fn two_squares(max_length: usize) -> Result<FinCategory<Node, Path<Edge>>, CategoryError> {
+        let generators = vec![
+            edge("p", "A", "B"),
+            edge("q", "B", "C"),
+            edge("r", "C", "D"),
+            edge("x", "A", "C"),
+            edge("s", "B", "D"),
+        ];
+        let relations = vec![
+            (vec![generators[3].clone()], vec![generators[0].clone(), generators[1].clone()]),
+            (vec![generators[1].clone(), generators[2].clone()], vec![generators[4].clone()]),
+        ];
+        FinCategory::free(
+            "TwoSquares".to_string(),
+            vec![Node("A"), Node("B"), Node("C"), Node("D")],
+            generators,
+            relations,
+            max_length,
+        )
+    }
+    
+    #[test]
+    fn free_category_links_paths_through_longer_ones() {
+        // x ; r and p ; s are only linked through p ; q ; r, one generator past the bound
+        let category = two_squares(2).unwrap();
+        assert!(category.check_laws().is_lawful());
+        
+        let morphism = |id: &str| category.morphisms().iter().find(|m| m.id() == id).unwrap();
+        assert_eq!(category.compose(morphism("x"), morphism("r")).unwrap().id(), "p ; s");
+        assert_eq!(category.compose(morphism("p"), morphism("q")).unwrap().id(), "x");
+        
+        // Four identities, the generators p, q, r, x and s, and p ; s
+        assert_eq!(category.morphisms().len(), 10);
+    }
+    
+    #[test]
+    fn free_category_reports_composites_beyond_the_bound() {
+        let result = two_squares(1);
+        assert!(matches!(result, Err(CategoryError::InconsistentComposition(_))));
+    }
+}
@@ -1,5 +1,8 @@
-use crate::category::{Category, CategoryError};
-use std::fmt::Debug;
+use crate::category::{Category, CategoryError, FinCategory, FinCategoryBuilder, LawReport, LawViolation, Morphism, Object};
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// Trait representing a functor between categories
 pub trait Functor: Debug {
@@ -17,18 +20,262 @@ pub trait Functor: Debug {
     fn map_morphism(&self, morph: &<Self::Source as Category>::Mor) 
         -> Result<<Self::Target as Category>::Mor, CategoryError>;
     
-    /// Verify that the functor preserves composition and identities
-    fn verify_functor_laws(&self, source: &Self::Source, target: &Self::Target) -> bool;
+    /// Check that the functor preserves identities and composition.
+    ///
+    /// Every morphism `f: A->B` of `source` must map to a morphism of `target`
+    /// from `F(A)` to `F(B)`, identities must map to identities, and every
+    /// composite `g ∘ f` defined in `source` must map to `F(g) ∘ F(f)` as
+    /// composed in `target`. Violations refer to morphisms of `source`.
+    fn verify_functor_laws(&self, source: &Self::Source, target: &Self::Target) -> LawReport {
+        let mut report = LawReport::default();
+        let morphisms = source.morphisms();
+        
+        // Composites are taken in the target, so images must be found there
+        let target_index: HashMap<String, &<Self::Target as Category>::Mor> = target.morphisms().iter()
+            .map(|m| (m.id(), m))
+            .collect();
+        let images: Vec<_> = morphisms.iter()
+            .map(|f| self.map_morphism(f).ok().and_then(|image| target_index.get(&image.id()).copied()))
+            .collect();
+        
+        for (i, f) in morphisms.iter().enumerate() {
+            report.checked += 1;
+            let preserves_endpoints = images[i].is_some_and(|image| {
+                image.domain().id() == self.map_object(f.domain()).id()
+                    && image.codomain().id() == self.map_object(f.codomain()).id()
+            });
+            if !preserves_endpoints {
+                report.violations.push(LawViolation::UnmappedMorphism { morphism: i });
+            }
+        }
+        
+        for obj in source.objects() {
+            // Missing source identities are reported by the source's own law check
+            let Ok(id) = source.identity(obj) else {
+                continue;
+            };
+            report.checked += 1;
+            
+            match (self.map_morphism(id), target.identity(&self.map_object(obj))) {
+                (Ok(image), Ok(expected)) if image.id() == expected.id() => {}
+                _ => report.violations.push(LawViolation::IdentityNotPreserved { object: obj.id() }),
+            }
+        }
+        
+        for (i, f) in morphisms.iter().enumerate() {
+            for (j, g) in morphisms.iter().enumerate() {
+                if f.codomain().id() != g.domain().id() {
+                    continue;
+                }
+                // Undefined composites are reported by the source's own law check,
+                // and unmapped morphisms above
+                let (Ok(gf), Some(image_f), Some(image_g)) = (source.compose(f, g), images[i], images[j]) else {
+                    continue;
+                };
+                report.checked += 1;
+                
+                match (self.map_morphism(gf), target.compose(image_f, image_g)) {
+                    (Ok(image), Ok(expected)) if image.id() == expected.id() => {}
+                    _ => report.violations.push(LawViolation::CompositionNotPreserved { f: i, g: j }),
+                }
+            }
+        }
+        
+        report
+    }
+    
+    /// Compare the hom-sets of `source` with those of `target`, and find which
+    /// objects of `target` are reached up to isomorphism.
+    ///
+    /// Morphisms without an image are skipped; they are reported by
+    /// [`Functor::verify_functor_laws`].
+    fn analyze(&self, source: &Self::Source, target: &Self::Target) -> FunctorProperties {
+        let objects = source.objects();
+        let morphisms = source.morphisms();
+        let object_images: Vec<String> = objects.iter().map(|obj| self.map_object(obj).id()).collect();
+        let images: Vec<Option<String>> = morphisms.iter()
+            .map(|f| self.map_morphism(f).ok().map(|image| image.id()))
+            .collect();
+        let mut properties = FunctorProperties::default();
+        
+        // Faithfulness: distinct parallel morphisms must have distinct images
+        for (i, f) in morphisms.iter().enumerate() {
+            for (j, g) in morphisms.iter().enumerate().skip(i + 1) {
+                let parallel = f.domain().id() == g.domain().id() && f.codomain().id() == g.codomain().id();
+                if parallel && images[i].is_some() && images[i] == images[j] {
+                    properties.identified.push((i, j));
+                }
+            }
+        }
+        
+        // Fullness: every morphism F(A) -> F(B) must be the image of some A -> B
+        for (a, obj_a) in objects.iter().enumerate() {
+            for (b, obj_b) in objects.iter().enumerate() {
+                let reached: Vec<&String> = morphisms.iter().zip(&images)
+                    .filter(|(f, _)| f.domain().id() == obj_a.id() && f.codomain().id() == obj_b.id())
+                    .filter_map(|(_, image)| image.as_ref())
+                    .collect();
+                for (k, m) in target.morphisms().iter().enumerate() {
+                    let between = m.domain().id() == object_images[a] && m.codomain().id() == object_images[b];
+                    if between && !reached.contains(&&m.id()) {
+                        properties.missed.push((a, b, k));
+                    }
+                }
+            }
+        }
+        
+        // Essential surjectivity: every target object must be isomorphic to some F(A)
+        for (y, obj) in target.objects().iter().enumerate() {
+            let reached = target.morphisms().iter().any(|m| {
+                m.codomain().id() == obj.id()
+                    && object_images.contains(&m.domain().id())
+                    && is_invertible(target, m)
+            });
+            if !reached {
+                properties.unreached.push(y);
+            }
+        }
+        
+        properties
+    }
+    
+    /// Whether distinct parallel morphisms of `source` always have distinct images
+    fn is_faithful(&self, source: &Self::Source, target: &Self::Target) -> bool {
+        self.analyze(source, target).is_faithful()
+    }
+    
+    /// Whether every morphism of `target` between images is the image of a morphism of `source`
+    fn is_full(&self, source: &Self::Source, target: &Self::Target) -> bool {
+        self.analyze(source, target).is_full()
+    }
+    
+    /// Whether every object of `target` is isomorphic to the image of an object of `source`
+    fn is_essentially_surjective(&self, source: &Self::Source, target: &Self::Target) -> bool {
+        self.analyze(source, target).is_essentially_surjective()
+    }
+    
+    /// The smallest subcategory of `target` containing the images of all objects
+    /// and morphisms of `source`.
+    ///
+    /// The images need not be closed under composition, so composites are taken
+    /// in `target` until they are. Images that are not part of `target` are errors.
+    fn image(
+        &self,
+        source: &Self::Source,
+        target: &Self::Target,
+    ) -> Result<Subcategory<Self::Target>, CategoryError> {
+        let mut objects: Vec<<Self::Target as Category>::Ob> = Vec::new();
+        for obj in source.objects() {
+            let image = self.map_object(obj);
+            let found = target.objects().iter()
+                .find(|y| y.id() == image.id())
+                .ok_or_else(|| CategoryError::ObjectNotFound(image.id()))?;
+            if !objects.iter().any(|y| y.id() == found.id()) {
+                objects.push(found.clone());
+            }
+        }
+        
+        let mut pending = Vec::new();
+        for obj in &objects {
+            pending.push(target.identity(obj)?.clone());
+        }
+        for f in source.morphisms() {
+            pending.push(self.map_morphism(f)?);
+        }
+        
+        // Add each new morphism together with its composites with those already present
+        let mut morphisms: Vec<<Self::Target as Category>::Mor> = Vec::new();
+        let mut composites = Vec::new();
+        while let Some(m) = pending.pop() {
+            let m = find_morphism(target, &m).ok_or_else(|| CategoryError::MorphismNotFound(m.id()))?;
+            if morphisms.iter().any(|n| n.id() == m.id()) {
+                continue;
+            }
+            morphisms.push(m.clone());
+            
+            for n in &morphisms {
+                let pairs = if n.id() == m.id() { vec![(m, n)] } else { vec![(m, n), (n, m)] };
+                for (f, g) in pairs {
+                    if f.codomain().id() == g.domain().id() {
+                        let gf = target.compose(f, g)?;
+                        composites.push((f.clone(), g.clone(), gf.clone()));
+                        pending.push(gf.clone());
+                    }
+                }
+            }
+        }
+        
+        let mut builder = FinCategoryBuilder::new("Image".to_string())
+            .objects(objects)
+            .morphisms(morphisms);
+        for (f, g, gf) in &composites {
+            builder = builder.composite(f, g, gf);
+        }
+        builder.build()
+    }
 }
 
-/// A concrete functor implementation
-#[derive(Clone, Debug)]
+/// A finite subcategory of `C`, such as the image computed by [`Functor::image`]
+pub type Subcategory<C> = FinCategory<<C as Category>::Ob, <C as Category>::Mor>;
+
+/// What a functor between finite categories preserves, as found by [`Functor::analyze`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FunctorProperties {
+    /// Pairs of distinct parallel source morphisms with the same image, by index in the source
+    pub identified: Vec<(usize, usize)>,
+    
+    /// Target morphisms `F(A) -> F(B)` that are not the image of any `A -> B`, as the
+    /// indices of `A` and `B` in the source and of the morphism in the target
+    pub missed: Vec<(usize, usize, usize)>,
+    
+    /// Indices of target objects not isomorphic to any image
+    pub unreached: Vec<usize>,
+}
+
+impl FunctorProperties {
+    /// Whether no two parallel morphisms are identified
+    pub fn is_faithful(&self) -> bool {
+        self.identified.is_empty()
+    }
+    
+    /// Whether every morphism between images is an image
+    pub fn is_full(&self) -> bool {
+        self.missed.is_empty()
+    }
+    
+    /// Whether every target object is reached up to isomorphism
+    pub fn is_essentially_surjective(&self) -> bool {
+        self.unreached.is_empty()
+    }
+    
+    /// Whether the functor is an equivalence of categories
+    pub fn is_equivalence(&self) -> bool {
+        self.is_faithful() && self.is_full() && self.is_essentially_surjective()
+    }
+}
+
+/// Shared object map of a [`ConcreteFunctor`]
+type ObjectMapping<S, T> = Arc<dyn Fn(&<S as Category>::Ob) -> <T as Category>::Ob + Send + Sync>;
+
+/// Shared morphism map of a [`ConcreteFunctor`]
+type MorphismMapping<S, T> =
+    Arc<dyn Fn(&<S as Category>::Mor) -> Result<<T as Category>::Mor, CategoryError> + Send + Sync>;
+
+/// Shared components of a [`ConcreteNaturalTransformation`]
+type ComponentMapping<S, T> =
+    Arc<dyn Fn(&<S as Category>::Ob) -> Result<<T as Category>::Mor, CategoryError> + Send + Sync>;
+
+/// A concrete functor implementation.
+///
+/// The object and morphism maps are closures, so they can capture parameters
+/// such as a coarse-graining factor. Clones share the same closures.
+#[derive(Clone)]
 pub struct ConcreteFunctor<S: Category, T: Category> {
     name: String,
     source_category: S,
     target_category: T,
-    object_mapping: fn(&S::Ob) -> T::Ob,
-    morphism_mapping: fn(&S::Mor) -> Result<T::Mor, CategoryError>,
+    object_mapping: ObjectMapping<S, T>,
+    morphism_mapping: MorphismMapping<S, T>,
 }
 
 impl<S: Category, T: Category> ConcreteFunctor<S, T> {
@@ -37,15 +284,15 @@ impl<S: Category, T: Category> ConcreteFunctor<S, T> {
         name: String,
         source_category: S,
         target_category: T,
-        object_mapping: fn(&S::Ob) -> T::Ob,
-        morphism_mapping: fn(&S::Mor) -> Result<T::Mor, CategoryError>,
+        object_mapping: impl Fn(&S::Ob) -> T::Ob + Send + Sync + 'static,
+        morphism_mapping: impl Fn(&S::Mor) -> Result<T::Mor, CategoryError> + Send + Sync + 'static,
     ) -> Self {
         Self {
             name,
             source_category,
             target_category,
-            object_mapping,
-            morphism_mapping,
+            object_mapping: Arc::new(object_mapping),
+            morphism_mapping: Arc::new(morphism_mapping),
         }
     }
     
@@ -53,6 +300,26 @@ impl<S: Category, T: Category> ConcreteFunctor<S, T> {
     pub fn name(&self) -> &str {
         &self.name
     }
+    
+    /// Get the source category
+    pub fn source_category(&self) -> &S {
+        &self.source_category
+    }
+    
+    /// Get the target category
+    pub fn target_category(&self) -> &T {
+        &self.target_category
+    }
+}
+
+impl<S: Category, T: Category> Debug for ConcreteFunctor<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcreteFunctor")
+            .field("name", &self.name)
+            .field("source_category", &self.source_category)
+            .field("target_category", &self.target_category)
+            .finish_non_exhaustive()
+    }
 }
 
 impl<S: Category, T: Category> Functor for ConcreteFunctor<S, T> {
@@ -67,13 +334,108 @@ impl<S: Category, T: Category> Functor for ConcreteFunctor<S, T> {
         -> Result<<Self::Target as Category>::Mor, CategoryError> {
         (self.morphism_mapping)(morph)
     }
+}
+
+/// The identity functor on a category
+#[derive(Clone, Debug)]
+pub struct IdentityFunctor<C: Category> {
+    _phantom: PhantomData<C>,
+}
+
+impl<C: Category> IdentityFunctor<C> {
+    /// Create the identity functor
+    pub fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+impl<C: Category> Default for IdentityFunctor<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Category> Functor for IdentityFunctor<C> {
+    type Source = C;
+    type Target = C;
+    
+    fn map_object(&self, obj: &C::Ob) -> C::Ob {
+        obj.clone()
+    }
+    
+    fn map_morphism(&self, morph: &C::Mor) -> Result<C::Mor, CategoryError> {
+        Ok(morph.clone())
+    }
+}
+
+/// The composite functor G ∘ F, applying `first` and then `second`
+#[derive(Clone, Debug)]
+pub struct ComposedFunctor<F, G>
+where
+    F: Functor,
+    G: Functor<Source = F::Target>,
+{
+    first: F,
+    second: G,
+}
+
+impl<F, G> ComposedFunctor<F, G>
+where
+    F: Functor,
+    G: Functor<Source = F::Target>,
+{
+    /// Compose two functors, `first` applied before `second`
+    pub fn new(first: F, second: G) -> Self {
+        Self { first, second }
+    }
     
-    fn verify_functor_laws(&self, _source: &Self::Source, _target: &Self::Target) -> bool {
-        // Would check:
-        // 1. F(g ∘ f) = F(g) ∘ F(f)
-        // 2. F(id_A) = id_F(A)
-        true // Simplified for now
+    /// The functor applied first
+    pub fn first(&self) -> &F {
+        &self.first
     }
+    
+    /// The functor applied second
+    pub fn second(&self) -> &G {
+        &self.second
+    }
+}
+
+impl<F, G> Functor for ComposedFunctor<F, G>
+where
+    F: Functor,
+    G: Functor<Source = F::Target>,
+{
+    type Source = F::Source;
+    type Target = G::Target;
+    
+    fn map_object(&self, obj: &<Self::Source as Category>::Ob) -> <Self::Target as Category>::Ob {
+        self.second.map_object(&self.first.map_object(obj))
+    }
+    
+    fn map_morphism(&self, morph: &<Self::Source as Category>::Mor)
+        -> Result<<Self::Target as Category>::Mor, CategoryError> {
+        self.second.map_morphism(&self.first.map_morphism(morph)?)
+    }
+}
+
+/// Find the morphism of `category` with the same ID as `morph`
+pub(crate) fn find_morphism<'a, C: Category>(category: &'a C, morph: &C::Mor) -> Option<&'a C::Mor> {
+    let id = morph.id();
+    category.morphisms().iter().find(|m| m.id() == id)
+}
+
+/// Whether `f` has a two-sided inverse among the morphisms of `category`
+pub fn is_invertible<C: Category>(category: &C, f: &C::Mor) -> bool {
+    let (Ok(id_a), Ok(id_b)) = (category.identity(f.domain()), category.identity(f.codomain())) else {
+        return false;
+    };
+    
+    category.morphisms().iter()
+        .filter(|g| g.domain().id() == f.codomain().id() && g.codomain().id() == f.domain().id())
+        .any(|g| {
+            matches!(category.compose(f, g), Ok(gf) if gf.id() == id_a.id())
+                && matches!(category.compose(g, f), Ok(fg) if fg.id() == id_b.id())
+        })
 }
 
 /// Trait representing a natural transformation between functors
@@ -90,16 +452,89 @@ pub trait NaturalTransformation: Debug {
     /// The codomain functor (G in η: F ⟹ G)
     type CodomainFunctor: Functor<Source = Self::Source, Target = Self::Target>;
     
-    /// Get the component of this natural transformation at a given object
+    /// Get the domain functor
+    fn domain_functor(&self) -> &Self::DomainFunctor;
+    
+    /// Get the codomain functor
+    fn codomain_functor(&self) -> &Self::CodomainFunctor;
+    
+    /// Get the component η_X: F(X) → G(X) of this natural transformation at a given object
     fn component_at(&self, obj: &<Self::Source as Category>::Ob) 
-        -> <Self::Target as Category>::Mor;
+        -> Result<<Self::Target as Category>::Mor, CategoryError>;
+    
+    /// Check that every component goes from F(X) to G(X) and that every naturality square commutes.
+    ///
+    /// For each morphism `f: X->Y` of `source`, `G(f) ∘ η_X` and `η_Y ∘ F(f)`
+    /// must be the same morphism of `target`. Violations refer to morphisms of `source`.
+    fn verify_naturality(&self, source: &Self::Source, target: &Self::Target) -> LawReport {
+        let mut report = LawReport::default();
+        let domain = self.domain_functor();
+        let codomain = self.codomain_functor();
+        
+        // Squares are composed in the target, so components must be found there
+        let mut components = HashMap::new();
+        for obj in source.objects() {
+            report.checked += 1;
+            let component = self.component_at(obj).ok()
+                .and_then(|eta| find_morphism(target, &eta))
+                .filter(|eta| {
+                    eta.domain().id() == domain.map_object(obj).id()
+                        && eta.codomain().id() == codomain.map_object(obj).id()
+                });
+            match component {
+                Some(eta) => {
+                    components.insert(obj.id(), eta);
+                }
+                None => report.violations.push(LawViolation::ComponentMismatch { object: obj.id() }),
+            }
+        }
+        
+        for (i, f) in source.morphisms().iter().enumerate() {
+            // Missing components were reported above
+            let (Some(eta_x), Some(eta_y)) = (components.get(&f.domain().id()), components.get(&f.codomain().id())) else {
+                continue;
+            };
+            report.checked += 1;
+            
+            let image_f = domain.map_morphism(f).ok().and_then(|m| find_morphism(target, &m));
+            let image_g = codomain.map_morphism(f).ok().and_then(|m| find_morphism(target, &m));
+            let commutes = match (image_f, image_g) {
+                (Some(ff), Some(gf)) => matches!(
+                    (target.compose(eta_x, gf), target.compose(ff, eta_y)),
+                    (Ok(left), Ok(right)) if left.id() == right.id()
+                ),
+                _ => false,
+            };
+            if !commutes {
+                report.violations.push(LawViolation::NaturalitySquare { morphism: i });
+            }
+        }
+        
+        report
+    }
     
-    /// Verify the naturality condition for this transformation
-    fn verify_naturality(&self, f: &<Self::Source as Category>::Mor) -> bool;
+    /// Check that this is a natural isomorphism: natural, and with every component invertible in `target`
+    fn verify_natural_isomorphism(&self, source: &Self::Source, target: &Self::Target) -> LawReport {
+        let mut report = self.verify_naturality(source, target);
+        
+        for obj in source.objects() {
+            // Components outside the target were reported by the naturality check
+            let Some(eta) = self.component_at(obj).ok().and_then(|eta| find_morphism(target, &eta).cloned()) else {
+                continue;
+            };
+            report.checked += 1;
+            
+            if !is_invertible(target, &eta) {
+                report.violations.push(LawViolation::NonInvertibleComponent { object: obj.id() });
+            }
+        }
+        
+        report
+    }
 }
 
-/// Concrete natural transformation implementation
-#[derive(Debug)]
+/// Concrete natural transformation implementation, with components given by a closure
+#[derive(Clone)]
 pub struct ConcreteNaturalTransformation<S, T, F, G>
 where
     S: Category,
@@ -110,7 +545,7 @@ where
     name: String,
     domain_functor: F,
     codomain_functor: G,
-    components: fn(&S::Ob) -> T::Mor,
+    components: ComponentMapping<S, T>,
 }
 
 impl<S, T, F, G> ConcreteNaturalTransformation<S, T, F, G>
@@ -125,15 +560,36 @@ where
         name: String,
         domain_functor: F,
         codomain_functor: G,
-        components: fn(&S::Ob) -> T::Mor,
+        components: impl Fn(&S::Ob) -> Result<T::Mor, CategoryError> + Send + Sync + 'static,
     ) -> Self {
         Self {
             name,
             domain_functor,
             codomain_functor,
-            components,
+            components: Arc::new(components),
         }
     }
+    
+    /// Get the name of this natural transformation
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<S, T, F, G> Debug for ConcreteNaturalTransformation<S, T, F, G>
+where
+    S: Category,
+    T: Category,
+    F: Functor<Source = S, Target = T>,
+    G: Functor<Source = S, Target = T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcreteNaturalTransformation")
+            .field("name", &self.name)
+            .field("domain_functor", &self.domain_functor)
+            .field("codomain_functor", &self.codomain_functor)
+            .finish_non_exhaustive()
+    }
 }
 
 impl<S, T, F, G> NaturalTransformation for ConcreteNaturalTransformation<S, T, F, G>
@@ -148,12 +604,518 @@ where
     type DomainFunctor = F;
     type CodomainFunctor = G;
     
-    fn component_at(&self, obj: &<Self::Source as Category>::Ob) -> <Self::Target as Category>::Mor {
+    fn domain_functor(&self) -> &F {
+        &self.domain_functor
+    }
+    
+    fn codomain_functor(&self) -> &G {
+        &self.codomain_functor
+    }
+    
+    fn component_at(&self, obj: &<Self::Source as Category>::Ob) 
+        -> Result<<Self::Target as Category>::Mor, CategoryError> {
         (self.components)(obj)
     }
+}
+
+/// Vertical composite β · α of α: F ⟹ G and β: G ⟹ H, with components β_X ∘ α_X
+#[derive(Clone, Debug)]
+pub struct VerticalComposite<A, B>
+where
+    A: NaturalTransformation,
+    B: NaturalTransformation<Source = A::Source, Target = A::Target, DomainFunctor = A::CodomainFunctor>,
+{
+    first: A,
+    second: B,
+    target: A::Target,
+}
+
+impl<A, B> VerticalComposite<A, B>
+where
+    A: NaturalTransformation,
+    B: NaturalTransformation<Source = A::Source, Target = A::Target, DomainFunctor = A::CodomainFunctor>,
+{
+    /// Compose two natural transformations, `first` applied before `second`.
+    ///
+    /// Components are composed in `target`, which must contain the components of both.
+    pub fn new(first: A, second: B, target: A::Target) -> Self {
+        Self { first, second, target }
+    }
+    
+    /// The transformation applied first
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+    
+    /// The transformation applied second
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<A, B> NaturalTransformation for VerticalComposite<A, B>
+where
+    A: NaturalTransformation,
+    B: NaturalTransformation<Source = A::Source, Target = A::Target, DomainFunctor = A::CodomainFunctor>,
+{
+    type Source = A::Source;
+    type Target = A::Target;
+    type DomainFunctor = A::DomainFunctor;
+    type CodomainFunctor = B::CodomainFunctor;
+    
+    fn domain_functor(&self) -> &A::DomainFunctor {
+        self.first.domain_functor()
+    }
+    
+    fn codomain_functor(&self) -> &B::CodomainFunctor {
+        self.second.codomain_functor()
+    }
+    
+    fn component_at(&self, obj: &<Self::Source as Category>::Ob) 
+        -> Result<<Self::Target as Category>::Mor, CategoryError> {
+        let alpha = self.first.component_at(obj)?;
+        let beta = self.second.component_at(obj)?;
+        let alpha = find_morphism(&self.target, &alpha).ok_or_else(|| CategoryError::MorphismNotFound(alpha.id()))?;
+        let beta = find_morphism(&self.target, &beta).ok_or_else(|| CategoryError::MorphismNotFound(beta.id()))?;
+        self.target.compose(alpha, beta).cloned()
+    }
+}
+
+/// Whiskering H ∘ α of α: F ⟹ G by a functor H, with components H(α_X)
+#[derive(Clone, Debug)]
+pub struct LeftWhiskered<H, A>
+where
+    A: NaturalTransformation,
+    A::DomainFunctor: Clone,
+    A::CodomainFunctor: Clone,
+    H: Functor<Source = A::Target> + Clone,
+{
+    inner: A,
+    domain_functor: ComposedFunctor<A::DomainFunctor, H>,
+    codomain_functor: ComposedFunctor<A::CodomainFunctor, H>,
+}
+
+impl<H, A> LeftWhiskered<H, A>
+where
+    A: NaturalTransformation,
+    A::DomainFunctor: Clone,
+    A::CodomainFunctor: Clone,
+    H: Functor<Source = A::Target> + Clone,
+{
+    /// Whisker `inner` by `functor`, applied after it
+    pub fn new(functor: H, inner: A) -> Self {
+        Self {
+            domain_functor: ComposedFunctor::new(inner.domain_functor().clone(), functor.clone()),
+            codomain_functor: ComposedFunctor::new(inner.codomain_functor().clone(), functor),
+            inner,
+        }
+    }
+}
+
+impl<H, A> NaturalTransformation for LeftWhiskered<H, A>
+where
+    A: NaturalTransformation,
+    A::DomainFunctor: Clone,
+    A::CodomainFunctor: Clone,
+    H: Functor<Source = A::Target> + Clone,
+{
+    type Source = A::Source;
+    type Target = H::Target;
+    type DomainFunctor = ComposedFunctor<A::DomainFunctor, H>;
+    type CodomainFunctor = ComposedFunctor<A::CodomainFunctor, H>;
+    
+    fn domain_functor(&self) -> &Self::DomainFunctor {
+        &self.domain_functor
+    }
+    
+    fn codomain_functor(&self) -> &Self::CodomainFunctor {
+        &self.codomain_functor
+    }
+    
+    fn component_at(&self, obj: &<Self::Source as Category>::Ob) 
+        -> Result<<Self::Target as Category>::Mor, CategoryError> {
+        self.domain_functor.second().map_morphism(&self.inner.component_at(obj)?)
+    }
+}
+
+/// Whiskering α ∘ K of α: F ⟹ G by a functor K, with components α_K(X)
+#[derive(Clone, Debug)]
+pub struct RightWhiskered<A, K>
+where
+    A: NaturalTransformation,
+    A::DomainFunctor: Clone,
+    A::CodomainFunctor: Clone,
+    K: Functor<Target = A::Source> + Clone,
+{
+    inner: A,
+    domain_functor: ComposedFunctor<K, A::DomainFunctor>,
+    codomain_functor: ComposedFunctor<K, A::CodomainFunctor>,
+}
+
+impl<A, K> RightWhiskered<A, K>
+where
+    A: NaturalTransformation,
+    A::DomainFunctor: Clone,
+    A::CodomainFunctor: Clone,
+    K: Functor<Target = A::Source> + Clone,
+{
+    /// Whisker `inner` by `functor`, applied before it
+    pub fn new(inner: A, functor: K) -> Self {
+        Self {
+            domain_functor: ComposedFunctor::new(functor.clone(), inner.domain_functor().clone()),
+            codomain_functor: ComposedFunctor::new(functor, inner.codomain_functor().clone()),
+            inner,
+        }
+    }
+}
+
+impl<A, K> NaturalTransformation for RightWhiskered<A, K>
+where
+    A: NaturalTransformation,
+    A::DomainFunctor: Clone,
+    A::CodomainFunctor: Clone,
+    K: Functor<Target = A::Source> + Clone,
+{
+    type Source = K::Source;
+    type Target = A::Target;
+    type DomainFunctor = ComposedFunctor<K, A::DomainFunctor>;
+    type CodomainFunctor = ComposedFunctor<K, A::CodomainFunctor>;
+    
+    fn domain_functor(&self) -> &Self::DomainFunctor {
+        &self.domain_functor
+    }
+    
+    fn codomain_functor(&self) -> &Self::CodomainFunctor {
+        &self.codomain_functor
+    }
+    
+    fn component_at(&self, obj: &<Self::Source as Category>::Ob) 
+        -> Result<<Self::Target as Category>::Mor, CategoryError> {
+        self.inner.component_at(&self.domain_functor.first().map_object(obj))
+    }
+}
+
+/// Horizontal composite β * α, as the vertical composite of the whiskerings β ∘ F and K ∘ α
+pub type HorizontalComposite<A, B> = VerticalComposite<
+    RightWhiskered<B, <A as NaturalTransformation>::DomainFunctor>,
+    LeftWhiskered<<B as NaturalTransformation>::CodomainFunctor, A>,
+>;
+
+/// Horizontal composite β * α of α: F ⟹ G between functors C → D and
+/// β: H ⟹ K between functors D → E.
+///
+/// The component at X is K(α_X) ∘ β_F(X), composed in `target`.
+pub fn horizontal_composite<A, B>(alpha: A, beta: B, target: B::Target) -> HorizontalComposite<A, B>
+where
+    A: NaturalTransformation,
+    A::DomainFunctor: Clone,
+    A::CodomainFunctor: Clone,
+    B: NaturalTransformation<Source = A::Target>,
+    B::DomainFunctor: Clone,
+    B::CodomainFunctor: Clone,
+{
+    let outer = beta.codomain_functor().clone();
+    let first = RightWhiskered::new(beta, alpha.domain_functor().clone());
+    let second = LeftWhiskered::new(outer, alpha);
+    VerticalComposite::new(first, second, target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::fixtures::{edge, identity, Edge, Node};
+    
+    type Graph = FinCategory<Node, Edge>;
+    
+    /// A -f-> B -g-> C with g ∘ f = gf
+    fn chain() -> Graph {
+        FinCategory::new(
+            "Chain".to_string(),
+            vec![Node("A"), Node("B"), Node("C")],
+            vec![
+                identity("A"), identity("B"), identity("C"),
+                edge("f", "A", "B"), edge("g", "B", "C"), edge("gf", "A", "C"),
+            ],
+        )
+    }
+    
+    /// A single arrow x: 0 -> 1
+    fn arrow() -> Graph {
+        FinCategory::new(
+            "Arrow".to_string(),
+            vec![Node("0"), Node("1")],
+            vec![identity("0"), identity("1"), edge("x", "0", "1")],
+        )
+    }
+    
+    /// One object and its identity
+    fn point() -> Graph {
+        FinCategory::new("Point".to_string(), vec![Node("*")], vec![identity("*")])
+    }
+    
+    /// Sends A to 0 and B, C to 1, so that f and gf become x and g an identity
+    fn squash() -> ConcreteFunctor<Graph, Graph> {
+        ConcreteFunctor::new(
+            "Squash".to_string(),
+            chain(),
+            arrow(),
+            |obj| Node(if obj.0 == "A" { "0" } else { "1" }),
+            |morph| Ok(match morph.name.as_str() {
+                "f" | "gf" => edge("x", "0", "1"),
+                "id_A" => identity("0"),
+                _ => identity("1"),
+            }),
+        )
+    }
+    
+    /// A functor given by lookup tables of object and morphism names, captured by its closures
+    fn relabel(
+        source: Graph,
+        target: Graph,
+        objects: &[(&'static str, &'static str)],
+        morphisms: &[(&'static str, Edge)],
+    ) -> ConcreteFunctor<Graph, Graph> {
+        let objects: HashMap<&str, &'static str> = objects.iter().copied().collect();
+        let morphisms: HashMap<String, Edge> = morphisms.iter().map(|(name, image)| (name.to_string(), image.clone())).collect();
+        ConcreteFunctor::new(
+            "Relabel".to_string(),
+            source,
+            target,
+            move |obj| Node(objects[obj.0]),
+            move |morph| morphisms.get(&morph.name).cloned()
+                .ok_or_else(|| CategoryError::MorphismNotFound(morph.name.clone())),
+        )
+    }
+    
+    /// The functor from the chain sending everything to `node` of the arrow
+    fn constant(node: &'static str) -> ConcreteFunctor<Graph, Graph> {
+        let objects = [("A", node), ("B", node), ("C", node)];
+        let morphisms = ["id_A", "id_B", "id_C", "f", "g", "gf"].map(|name| (name, identity(node)));
+        relabel(chain(), arrow(), &objects, &morphisms)
+    }
+    
+    /// A transformation between functors into `Graph` with components looked up by object name
+    fn transformation<F, G>(
+        domain: F,
+        codomain: G,
+        components: &[(&'static str, Edge)],
+    ) -> ConcreteNaturalTransformation<F::Source, Graph, F, G>
+    where
+        F: Functor<Target = Graph>,
+        G: Functor<Source = F::Source, Target = Graph>,
+    {
+        let components: HashMap<String, Edge> = components.iter().map(|(obj, eta)| (obj.to_string(), eta.clone())).collect();
+        ConcreteNaturalTransformation::new(
+            "Components".to_string(),
+            domain,
+            codomain,
+            move |obj| components.get(&obj.id()).cloned().ok_or_else(|| CategoryError::ObjectNotFound(obj.id())),
+        )
+    }
+    
+    #[test]
+    fn law_check_finds_broken_identities_and_composites() {
+        // One object with an idempotent e ∘ e = e besides its identity
+        let (id, e) = (identity("*"), edge("e", "*", "*"));
+        let idempotent = FinCategoryBuilder::new("Idempotent".to_string())
+            .object(Node("*"))
+            .morphisms([id.clone(), e.clone()])
+            .composite(&e, &e, &e)
+            .build()
+            .unwrap();
+        let collapse = |images: [&Edge; 6]| {
+            let names = ["id_A", "id_B", "id_C", "f", "g", "gf"];
+            let morphisms: Vec<(&'static str, Edge)> = names.into_iter().zip(images.map(Edge::clone)).collect();
+            relabel(chain(), idempotent.clone(), &[("A", "*"), ("B", "*"), ("C", "*")], &morphisms)
+        };
+        
+        let lawful = collapse([&id, &id, &id, &e, &e, &e]);
+        assert!(lawful.verify_functor_laws(&chain(), &idempotent).is_lawful());
+        
+        // id_A goes to e, which then fails to be a unit for the images of f and gf
+        let no_identity = collapse([&e, &id, &id, &id, &id, &id]);
+        assert_eq!(no_identity.verify_functor_laws(&chain(), &idempotent).violations, vec![
+            LawViolation::IdentityNotPreserved { object: "A".to_string() },
+            LawViolation::CompositionNotPreserved { f: 0, g: 3 },
+            LawViolation::CompositionNotPreserved { f: 0, g: 5 },
+        ]);
+        
+        // f and g go to e but gf goes to the identity, while e ∘ e = e
+        let no_composite = collapse([&id, &id, &id, &e, &e, &id]);
+        assert_eq!(no_composite.verify_functor_laws(&chain(), &idempotent).violations, vec![
+            LawViolation::CompositionNotPreserved { f: 3, g: 4 },
+        ]);
+    }
+    
+    #[test]
+    fn composed_functors_apply_in_order_and_stay_lawful() {
+        let to_point = ConcreteFunctor::new(
+            "Collapse".to_string(),
+            arrow(),
+            point(),
+            |_| Node("*"),
+            |_| Ok(identity("*")),
+        );
+        assert!(squash().verify_functor_laws(&chain(), &arrow()).is_lawful());
+        
+        let composed = ComposedFunctor::new(squash(), to_point);
+        assert_eq!(composed.map_object(&Node("C")), Node("*"));
+        assert_eq!(composed.map_morphism(&edge("gf", "A", "C")).unwrap(), identity("*"));
+        assert!(composed.verify_functor_laws(&chain(), &point()).is_lawful());
+        
+        // Precomposing with the identity changes nothing
+        let unit = ComposedFunctor::new(IdentityFunctor::<Graph>::new(), squash());
+        for morph in chain().morphisms() {
+            assert_eq!(unit.map_morphism(morph).unwrap(), squash().map_morphism(morph).unwrap());
+        }
+        assert!(unit.verify_functor_laws(&chain(), &arrow()).is_lawful());
+        
+        // Sending gf to an arrow with the wrong domain is still caught after composing
+        let broken = ConcreteFunctor::new(
+            "Broken".to_string(),
+            chain(),
+            arrow(),
+            |obj| Node(if obj.0 == "A" { "0" } else { "1" }),
+            |morph| Ok(if morph.name == "gf" { identity("1") } else { squash().map_morphism(morph)? }),
+        );
+        let report = ComposedFunctor::new(broken, IdentityFunctor::<Graph>::new()).verify_functor_laws(&chain(), &arrow());
+        assert!(report.violations.contains(&LawViolation::UnmappedMorphism { morphism: 5 }));
+    }
+    
+    #[test]
+    fn closures_capture_their_tables_and_clones_share_them() {
+        let objects = [("A", "0"), ("B", "1"), ("C", "1")];
+        let morphisms = [
+            ("id_A", identity("0")), ("id_B", identity("1")), ("id_C", identity("1")),
+            ("f", edge("x", "0", "1")), ("g", identity("1")), ("gf", edge("x", "0", "1")),
+        ];
+        let table = relabel(chain(), arrow(), &objects, &morphisms);
+        let copy = table.clone();
+        for morph in chain().morphisms() {
+            assert_eq!(table.map_morphism(morph).unwrap(), squash().map_morphism(morph).unwrap());
+            assert_eq!(copy.map_morphism(morph).unwrap(), squash().map_morphism(morph).unwrap());
+        }
+        assert!(copy.verify_functor_laws(&chain(), &arrow()).is_lawful());
+        
+        // Dropping an entry leaves that morphism without an image
+        let partial = relabel(chain(), arrow(), &objects, &morphisms[..5]);
+        assert!(matches!(partial.map_morphism(&edge("gf", "A", "C")), Err(CategoryError::MorphismNotFound(_))));
+        
+        // Components can capture a table too: from everything at 0 to the squash
+        let components: HashMap<&str, Edge> = [("A", identity("0")), ("B", edge("x", "0", "1")), ("C", edge("x", "0", "1"))].into_iter().collect();
+        let at_zero = relabel(chain(), arrow(), &[("A", "0"), ("B", "0"), ("C", "0")], &[
+            ("id_A", identity("0")), ("id_B", identity("0")), ("id_C", identity("0")),
+            ("f", identity("0")), ("g", identity("0")), ("gf", identity("0")),
+        ]);
+        let eta = ConcreteNaturalTransformation::new(
+            "Spread".to_string(),
+            at_zero,
+            table,
+            move |obj: &Node| components.get(obj.0).cloned().ok_or_else(|| CategoryError::ObjectNotFound(obj.0.to_string())),
+        );
+        assert!(eta.verify_naturality(&chain(), &arrow()).is_lawful());
+    }
+    
+    #[test]
+    fn naturality_catches_broken_squares_and_survives_composition() {
+        // α spreads the constant functor at 0 out to the squash, β gathers the squash up at 1
+        let alpha = transformation(constant("0"), squash(), &[("A", identity("0")), ("B", edge("x", "0", "1")), ("C", edge("x", "0", "1"))]);
+        let beta = transformation(squash(), constant("1"), &[("A", edge("x", "0", "1")), ("B", identity("1")), ("C", identity("1"))]);
+        assert!(alpha.verify_naturality(&chain(), &arrow()).is_lawful());
+        assert!(beta.verify_naturality(&chain(), &arrow()).is_lawful());
+        let report = alpha.verify_natural_isomorphism(&chain(), &arrow());
+        assert!(report.violations.contains(&LawViolation::NonInvertibleComponent { object: "B".to_string() }));
+        
+        // Identity components between two functors that pick different parallel arrows
+        let parallel = FinCategory::new(
+            "Parallel".to_string(),
+            vec![Node("0"), Node("1")],
+            vec![identity("0"), identity("1"), edge("a", "0", "1"), edge("b", "0", "1")],
+        );
+        let pick = |name: &'static str| relabel(arrow(), parallel.clone(), &[("0", "0"), ("1", "1")], &[
+            ("id_0", identity("0")), ("id_1", identity("1")), ("x", edge(name, "0", "1")),
+        ]);
+        let broken = transformation(pick("a"), pick("b"), &[("0", identity("0")), ("1", identity("1"))]);
+        assert_eq!(broken.verify_naturality(&arrow(), &parallel).violations, vec![LawViolation::NaturalitySquare { morphism: 2 }]);
+        let misplaced = transformation(pick("a"), pick("b"), &[("0", identity("0")), ("1", identity("0"))]);
+        assert!(misplaced.verify_naturality(&arrow(), &parallel).violations.contains(&LawViolation::ComponentMismatch { object: "1".to_string() }));
+        
+        // β · α runs from everything at 0 to everything at 1 through x
+        let vertical = VerticalComposite::new(alpha.clone(), beta, arrow());
+        for obj in chain().objects() {
+            assert_eq!(vertical.component_at(obj).unwrap(), edge("x", "0", "1"));
+        }
+        assert!(vertical.verify_naturality(&chain(), &arrow()).is_lawful());
+        
+        // Whiskering by the collapse onto a point, and by the inclusion of B -g-> C
+        let to_point = relabel(arrow(), point(), &[("0", "*"), ("1", "*")], &[
+            ("id_0", identity("*")), ("id_1", identity("*")), ("x", identity("*")),
+        ]);
+        let left = LeftWhiskered::new(to_point, alpha.clone());
+        assert_eq!(left.component_at(&Node("B")).unwrap(), identity("*"));
+        assert!(left.verify_naturality(&chain(), &point()).is_lawful());
+        
+        let tail = relabel(arrow(), chain(), &[("0", "B"), ("1", "C")], &[
+            ("id_0", identity("B")), ("id_1", identity("C")), ("x", edge("g", "B", "C")),
+        ]);
+        let right = RightWhiskered::new(alpha, tail);
+        assert_eq!(right.component_at(&Node("0")).unwrap(), edge("x", "0", "1"));
+        assert!(right.verify_naturality(&arrow(), &arrow()).is_lawful());
+    }
+    
+    #[test]
+    fn horizontal_composite_follows_beta_with_the_image_of_alpha() {
+        // α: F ⟹ G gathering the squash up at 1, between functors chain → arrow
+        let alpha = transformation(squash(), constant("1"), &[("A", edge("x", "0", "1")), ("B", identity("1")), ("C", identity("1"))]);
+        
+        // β: H ⟹ K from the collapse onto 0 to the identity, between functors arrow → arrow
+        let collapse = relabel(arrow(), arrow(), &[("0", "0"), ("1", "0")], &[
+            ("id_0", identity("0")), ("id_1", identity("0")), ("x", identity("0")),
+        ]);
+        let same = relabel(arrow(), arrow(), &[("0", "0"), ("1", "1")], &[
+            ("id_0", identity("0")), ("id_1", identity("1")), ("x", edge("x", "0", "1")),
+        ]);
+        let beta = transformation(collapse, same.clone(), &[("0", identity("0")), ("1", edge("x", "0", "1"))]);
+        assert!(beta.verify_naturality(&arrow(), &arrow()).is_lawful());
+        
+        let composite = horizontal_composite(alpha.clone(), beta.clone(), arrow());
+        for obj in chain().objects() {
+            let beta_fx = beta.component_at(&squash().map_object(obj)).unwrap();
+            let k_alpha = same.map_morphism(&alpha.component_at(obj).unwrap()).unwrap();
+            let expected = arrow().compose(&beta_fx, &k_alpha).unwrap().clone();
+            assert_eq!(composite.component_at(obj).unwrap(), expected);
+        }
+        // At B the component is β_1 = x even though α_B is an identity
+        assert_eq!(composite.component_at(&Node("B")).unwrap(), edge("x", "0", "1"));
+        assert!(composite.verify_naturality(&chain(), &arrow()).is_lawful());
+    }
     
-    fn verify_naturality(&self, _f: &<Self::Source as Category>::Mor) -> bool {
-        // Would check G(f) ∘ η_X = η_Y ∘ F(f) for f: X → Y
-        true // Simplified for now
+    #[test]
+    fn analysis_finds_identified_missed_and_unreached_arrows() {
+        let squashed = squash().analyze(&chain(), &arrow());
+        assert!(squashed.is_faithful() && squashed.is_essentially_surjective());
+        // id_1 runs from F(C) to F(B), but nothing runs from C to B
+        assert_eq!(squashed.missed, vec![(2, 1, 1)]);
+        
+        let parallel = FinCategory::new(
+            "Parallel".to_string(),
+            vec![Node("0"), Node("1")],
+            vec![identity("0"), identity("1"), edge("a", "0", "1"), edge("b", "0", "1")],
+        );
+        let merge = relabel(parallel.clone(), arrow(), &[("0", "0"), ("1", "1")], &[
+            ("id_0", identity("0")), ("id_1", identity("1")), ("a", edge("x", "0", "1")), ("b", edge("x", "0", "1")),
+        ]);
+        let merged = merge.analyze(&parallel, &arrow());
+        assert_eq!(merged.identified, vec![(2, 3)]);
+        assert!(merged.is_full() && !merged.is_equivalence());
+        
+        let start = relabel(point(), arrow(), &[("*", "0")], &[("id_*", identity("0"))]);
+        let included = start.analyze(&point(), &arrow());
+        assert!(included.is_faithful() && included.is_full());
+        assert_eq!(included.unreached, vec![1]);
+        assert_eq!(start.image(&point(), &arrow()).unwrap().objects(), &[Node("0")]);
+        
+        assert!(IdentityFunctor::<Graph>::new().analyze(&arrow(), &arrow()).is_equivalence());
+        assert_eq!(squash().image(&chain(), &arrow()).unwrap().morphisms().len(), 3);
     }
 }
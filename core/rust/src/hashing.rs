@@ -0,0 +1,85 @@
+/// Tolerance below which floats are considered equal in content-addressed
+/// object IDs, unless a configuration is given its own
+pub const DEFAULT_ID_TOLERANCE: f64 = 1e-9;
+
+/// Stable 64-bit FNV-1a hasher for configuration data.
+///
+/// Unlike `std::hash::DefaultHasher` the result does not change between runs or
+/// Rust versions. Floats are rounded to a multiple of the tolerance before
+/// hashing, so values closer than the tolerance usually hash the same; values
+/// straddling a rounding boundary can still differ.
+#[derive(Clone, Debug)]
+pub struct ContentHasher {
+    state: u64,
+    tolerance: f64,
+}
+
+impl ContentHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    
+    /// Create a hasher rounding floats to the given tolerance
+    pub fn new(tolerance: f64) -> Self {
+        Self {
+            state: Self::OFFSET_BASIS,
+            tolerance: tolerance.abs(),
+        }
+    }
+    
+    /// Feed raw bytes
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state ^= byte as u64;
+            self.state = self.state.wrapping_mul(Self::PRIME);
+        }
+    }
+    
+    /// Feed an unsigned integer
+    pub fn write_usize(&mut self, x: usize) {
+        self.write_bytes(&(x as u64).to_le_bytes());
+    }
+    
+    /// Feed a float, rounded to the tolerance
+    pub fn write_f64(&mut self, x: f64) {
+        if x.is_nan() {
+            self.write_bytes(b"nan");
+        } else if x.is_infinite() {
+            self.write_bytes(if x > 0.0 { b"+inf" } else { b"-inf" });
+        } else if self.tolerance > 0.0 {
+            // Adding 0 turns -0 into +0
+            let quantized = (x / self.tolerance).round() + 0.0;
+            self.write_bytes(&quantized.to_bits().to_le_bytes());
+        } else {
+            self.write_bytes(&(x + 0.0).to_bits().to_le_bytes());
+        }
+    }
+    
+    /// Feed a sequence of floats
+    pub fn write_f64s<'a>(&mut self, values: impl IntoIterator<Item = &'a f64>) {
+        for &x in values {
+            self.write_f64(x);
+        }
+    }
+    
+    /// Feed a string, length-prefixed so that concatenations cannot collide
+    pub fn write_str(&mut self, s: &str) {
+        self.write_usize(s.len());
+        self.write_bytes(s.as_bytes());
+    }
+    
+    /// Feed an optional value, distinguishing `None` from every `Some`
+    pub fn write_option<T>(&mut self, value: Option<&T>, write: impl FnOnce(&mut Self, &T)) {
+        match value {
+            Some(v) => {
+                self.write_bytes(&[1]);
+                write(self, v);
+            }
+            None => self.write_bytes(&[0]),
+        }
+    }
+    
+    /// The hash of everything written so far
+    pub fn finish(&self) -> u64 {
+        self.state
+    }
+}
@@ -0,0 +1,412 @@
+use crate::macroscopic::MacroscopicParameters;
+use crate::rg_flow::{
+    self, FlowEvent, FlowTermination, IntegrationOptions, ParameterSpace, RGFixedPoint, RGFlow, RGFlowError,
+};
+use nalgebra::{DMatrix, DVector};
+use std::f64::consts::PI;
+
+/// Couplings of a 2D film on the scale of its disclinations
+#[derive(Clone, Debug, PartialEq)]
+pub struct KTParameters {
+    /// Frank stiffness in units of k_B T, K/k_B T
+    pub stiffness: f64,
+    
+    /// Disclination fugacity, exp(-E_core/k_B T)
+    pub fugacity: f64,
+}
+
+impl KTParameters {
+    /// Create from a stiffness and fugacity
+    pub fn new(stiffness: f64, fugacity: f64) -> Self {
+        Self { stiffness, fugacity }
+    }
+    
+    /// The film described by 2D macroscopic parameters, in units where k_B = 1.
+    ///
+    /// Only splay and bend deform a director confined to the film, so the
+    /// stiffness is their mean.
+    pub fn from_macroscopic(params: &MacroscopicParameters) -> Result<Self, RGFlowError> {
+        if params.spatial_dimension != 2 {
+            return Err(RGFlowError::DimensionMismatch { expected: 2, actual: params.spatial_dimension });
+        }
+        if params.temperature.is_nan() || params.temperature <= 0.0 {
+            return Err(RGFlowError::ParameterOutOfRange(
+                format!("Temperature must be positive, got {}", params.temperature)
+            ));
+        }
+        
+        let stiffness = 0.5 * (params.k1 + params.k3);
+        Ok(Self {
+            stiffness: stiffness / params.temperature,
+            fugacity: (-params.core_energy / params.temperature).exp(),
+        })
+    }
+}
+
+impl ParameterSpace for KTParameters {
+    fn dimension(&self) -> usize {
+        2 // stiffness, fugacity
+    }
+    
+    fn spatial_dimension(&self) -> usize {
+        2
+    }
+    
+    fn as_vector(&self) -> DVector<f64> {
+        DVector::from_vec(vec![self.stiffness, self.fugacity])
+    }
+    
+    fn from_vector(vec: DVector<f64>, dim: usize) -> Result<Self, RGFlowError> {
+        if dim != 2 {
+            return Err(RGFlowError::DimensionMismatch { expected: 2, actual: dim });
+        }
+        if vec.len() != 2 {
+            return Err(RGFlowError::ParameterOutOfRange(
+                format!("Expected 2 parameters, got {}", vec.len())
+            ));
+        }
+        
+        Ok(Self {
+            stiffness: vec[0],
+            fugacity: vec[1],
+        })
+    }
+    
+    fn distance(&self, other: &Self) -> f64 {
+        (self.as_vector() - other.as_vector()).norm()
+    }
+}
+
+/// Kosterlitz-Thouless flow of the stiffness and defect fugacity of a 2D film.
+///
+/// With K the reduced stiffness, y the fugacity and s the charge of the
+/// unbinding defects,
+///
+/// dK⁻¹/dℓ = 4π³ s² y²,  dy/dℓ = (2 - π s² K) y.
+///
+/// Every point with y = 0 is fixed. Pairs stay bound where π s² K > 2 and the
+/// fugacity dies out; elsewhere free defects proliferate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KosterlitzThoulessFlow {
+    /// Charge of the defects that unbind
+    pub charge: f64,
+}
+
+impl Default for KosterlitzThoulessFlow {
+    /// ±1/2 disclinations, the cheapest defects of a nematic film
+    fn default() -> Self {
+        Self { charge: 0.5 }
+    }
+}
+
+/// Options for [`KosterlitzThoulessFlow::phase`] and [`KosterlitzThoulessFlow::stiffness_jump`]
+#[derive(Clone, Debug)]
+pub struct UnbindingOptions {
+    /// How far in ℓ to flow before classifying
+    pub length: f64,
+    
+    /// Defects count as free once the fugacity grows past this; the
+    /// recursion relations stop being reliable around here anyway
+    pub free_fugacity: f64,
+    
+    /// Stop bisecting the temperature once the bracket is narrower than this,
+    /// or once it cannot be split in floating point; must not be negative
+    pub tolerance: f64,
+    
+    /// Options for the flow; an event on the fugacity is added
+    pub integration: IntegrationOptions,
+}
+
+impl Default for UnbindingOptions {
+    fn default() -> Self {
+        Self {
+            length: 50.0,
+            free_fugacity: 1.0,
+            tolerance: 1e-8,
+            integration: IntegrationOptions::default(),
+        }
+    }
+}
+
+/// Fate of a film under the Kosterlitz-Thouless flow
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DefectPhase {
+    /// Defect pairs stay bound and the flow ends on the fixed line
+    Bound {
+        /// Stiffness K_R once all pairs are screened out
+        renormalized_stiffness: f64,
+    },
+    
+    /// Free defects proliferate
+    Unbound {
+        /// Distance, in core sizes, at which the fugacity reached
+        /// [`UnbindingOptions::free_fugacity`], if it did within the flow
+        correlation_length: Option<f64>,
+    },
+}
+
+impl DefectPhase {
+    /// Whether defects stay bound
+    pub fn is_bound(&self) -> bool {
+        matches!(self, DefectPhase::Bound { .. })
+    }
+    
+    /// Renormalized stiffness, zero once defects unbind
+    pub fn renormalized_stiffness(&self) -> f64 {
+        match *self {
+            DefectPhase::Bound { renormalized_stiffness } => renormalized_stiffness,
+            DefectPhase::Unbound { .. } => 0.0,
+        }
+    }
+}
+
+/// The jump of the renormalized stiffness at a defect-unbinding transition
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StiffnessJump {
+    /// Transition temperature
+    pub temperature: f64,
+    
+    /// Renormalized stiffness just below the transition; it vanishes above
+    pub stiffness: f64,
+    
+    /// The universal value 2/(π s²) predicted by the flow
+    pub universal: f64,
+}
+
+impl StiffnessJump {
+    /// Relative deviation of the measured jump from the universal value
+    pub fn deviation(&self) -> f64 {
+        (self.stiffness - self.universal) / self.universal
+    }
+    
+    /// Whether the measured jump matches the universal value to `tolerance`
+    pub fn is_universal(&self, tolerance: f64) -> bool {
+        self.deviation().abs() <= tolerance
+    }
+}
+
+impl KosterlitzThoulessFlow {
+    /// Flow for defects of the given charge
+    pub fn new(charge: f64) -> Self {
+        Self { charge }
+    }
+    
+    /// Renormalized stiffness at which defects unbind, 2/(π s²)
+    pub fn universal_stiffness(&self) -> f64 {
+        2.0 / (PI * self.charge * self.charge)
+    }
+    
+    /// Flow `initial` and decide whether its defects stay bound.
+    ///
+    /// Near the transition x = π s² K - 2 and y obey x² - 16π² y² = const, so
+    /// a flow that ends with x ≥ 4π y is bound and settles at x = √(x² - 16π² y²).
+    pub fn phase(&self, initial: &KTParameters, options: &UnbindingOptions) -> Result<DefectPhase, RGFlowError> {
+        let mut integration = options.integration.clone();
+        integration.events.push(FlowEvent::Crosses { parameter: 1, value: options.free_fugacity });
+        let unbinding = integration.events.len() - 1;
+        
+        let trajectory = self.integrate(initial, options.length, &integration)?;
+        let end = trajectory.points.last().unwrap_or(initial);
+        
+        match trajectory.termination {
+            FlowTermination::Event { index } if index == unbinding => {
+                let scale = trajectory.scales.last().copied().unwrap_or(0.0);
+                return Ok(DefectPhase::Unbound { correlation_length: Some(scale.exp()) });
+            }
+            FlowTermination::NonFinite | FlowTermination::StepSizeTooSmall => {
+                return Ok(DefectPhase::Unbound { correlation_length: None });
+            }
+            _ => {}
+        }
+        
+        let s2 = self.charge * self.charge;
+        let x = PI * s2 * end.stiffness - 2.0;
+        let invariant = x * x - 16.0 * PI * PI * end.fugacity * end.fugacity;
+        if x > 0.0 && invariant >= 0.0 {
+            Ok(DefectPhase::Bound { renormalized_stiffness: (2.0 + invariant.sqrt()) / (PI * s2) })
+        } else {
+            Ok(DefectPhase::Unbound { correlation_length: None })
+        }
+    }
+    
+    /// Locate the unbinding temperature between `lower` and `upper` by bisection
+    /// and measure the stiffness jump there.
+    ///
+    /// `film` gives the bare couplings at each temperature. Returns `None` unless
+    /// defects are bound at `lower` and free at `upper`.
+    pub fn stiffness_jump(
+        &self,
+        film: impl Fn(f64) -> Result<KTParameters, RGFlowError>,
+        lower: f64,
+        upper: f64,
+        options: &UnbindingOptions,
+    ) -> Result<Option<StiffnessJump>, RGFlowError> {
+        if options.tolerance.is_nan() || options.tolerance < 0.0 {
+            return Err(RGFlowError::ParameterOutOfRange(
+                format!("Bisection tolerance must not be negative, got {}", options.tolerance)
+            ));
+        }
+        let phase_at = |temperature: f64| self.phase(&film(temperature)?, options);
+        
+        let mut below = phase_at(lower)?;
+        if !below.is_bound() || phase_at(upper)?.is_bound() {
+            return Ok(None);
+        }
+        
+        let (mut lo, mut hi) = (lower, upper);
+        while (hi - lo).abs() > options.tolerance {
+            let mid = 0.5 * (lo + hi);
+            // Adjacent floats: the bracket cannot shrink any further
+            if mid == lo || mid == hi {
+                break;
+            }
+            let phase = phase_at(mid)?;
+            if phase.is_bound() {
+                lo = mid;
+                below = phase;
+            } else {
+                hi = mid;
+            }
+        }
+        
+        Ok(Some(StiffnessJump {
+            temperature: 0.5 * (lo + hi),
+            stiffness: below.renormalized_stiffness(),
+            universal: self.universal_stiffness(),
+        }))
+    }
+}
+
+impl RGFlow<KTParameters> for KosterlitzThoulessFlow {
+    fn spatial_dimension(&self) -> usize {
+        2
+    }
+    
+    fn do_step(&self, params: &KTParameters) -> Result<KTParameters, RGFlowError> {
+        // Scale factor for this RG step
+        let scale: f64 = 1.5;
+        
+        rg_flow::rescale_by(|p| self.beta_function(p), params, scale)
+    }
+    
+    fn analyze_fixed_point(&self, fixed_point: &KTParameters) -> Result<RGFixedPoint<KTParameters>, RGFlowError> {
+        let stability_matrix = self.jacobian(fixed_point)?;
+        rg_flow::linearized_stability(fixed_point, stability_matrix, 2)
+    }
+    
+    fn beta_function(&self, params: &KTParameters) -> Result<DVector<f64>, RGFlowError> {
+        let s2 = self.charge * self.charge;
+        let (k, y) = (params.stiffness, params.fugacity);
+        
+        // Bound pairs screen the stiffness; the fugacity grows unless the stiffness beats the entropy
+        let beta_k = -4.0 * PI.powi(3) * s2 * k * k * y * y;
+        let beta_y = (2.0 - PI * s2 * k) * y;
+        
+        Ok(DVector::from_vec(vec![beta_k, beta_y]))
+    }
+    
+    fn jacobian(&self, params: &KTParameters) -> Result<DMatrix<f64>, RGFlowError> {
+        let s2 = self.charge * self.charge;
+        let (k, y) = (params.stiffness, params.fugacity);
+        let screening = 4.0 * PI.powi(3) * s2;
+        
+        Ok(DMatrix::from_row_slice(2, 2, &[
+            -2.0 * screening * k * y * y, -2.0 * screening * k * k * y,
+            -PI * s2 * y, 2.0 - PI * s2 * k,
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// A film with reduced stiffness 1/T and core energy 2
+    fn film(temperature: f64) -> Result<KTParameters, RGFlowError> {
+        Ok(KTParameters::new(1.0 / temperature, (-2.0 / temperature).exp()))
+    }
+    
+    fn macroscopic_film(temperature: f64) -> MacroscopicParameters {
+        MacroscopicParameters {
+            k1: 2.0,
+            k2: 3.0,
+            k3: 4.0,
+            chi_a: 0.0,
+            temperature,
+            core_energy: 1.0,
+            spatial_dimension: 2,
+        }
+    }
+    
+    #[test]
+    fn stiffness_jump_is_universal() {
+        let flow = KosterlitzThoulessFlow::default();
+        let jump = flow.stiffness_jump(film, 0.01, 10.0, &UnbindingOptions::default()).unwrap().unwrap();
+        assert!((flow.universal_stiffness() - 8.0 / PI).abs() < 1e-12);
+        assert!(jump.temperature > 0.01 && jump.temperature < 10.0);
+        
+        // Just below the transition the flow itself screens the stiffness down to the universal value
+        let initial = film(jump.temperature * (1.0 - 1e-4)).unwrap();
+        let trajectory = flow.integrate(&initial, 5000.0, &IntegrationOptions::default()).unwrap();
+        assert_eq!(trajectory.termination, FlowTermination::Completed);
+        let end = trajectory.points.last().unwrap();
+        assert!(end.fugacity < 1e-12);
+        let deviation = (end.stiffness - jump.universal) / jump.universal;
+        assert!(deviation > 0.0 && deviation < 5e-3, "flowed to {} against {}", end.stiffness, jump.universal);
+        assert!((jump.stiffness - end.stiffness).abs() / end.stiffness < 5e-3);
+    }
+    
+    #[test]
+    fn bound_phase_settles_at_the_flowed_stiffness() {
+        let flow = KosterlitzThoulessFlow::default();
+        // x = π s² K - 2 = 0.2 and 4π y ≈ 0.13, well inside the bound phase
+        let initial = KTParameters::new(2.2 * 4.0 / PI, 0.01);
+        let phase = flow.phase(&initial, &UnbindingOptions::default()).unwrap();
+        
+        let trajectory = flow.integrate(&initial, 1000.0, &IntegrationOptions::default()).unwrap();
+        let end = trajectory.points.last().unwrap();
+        assert!(end.fugacity < 1e-12);
+        
+        let estimate = phase.renormalized_stiffness();
+        assert!((estimate - end.stiffness).abs() / end.stiffness < 1e-6, "{} against {}", estimate, end.stiffness);
+        assert!(estimate < initial.stiffness && estimate > flow.universal_stiffness());
+    }
+    
+    #[test]
+    fn phase_changes_across_the_transition() {
+        let flow = KosterlitzThoulessFlow::default();
+        let options = UnbindingOptions::default();
+        
+        let cold = flow.phase(&film(0.2).unwrap(), &options).unwrap();
+        assert!(cold.is_bound());
+        assert!(cold.renormalized_stiffness() > flow.universal_stiffness());
+        assert!(cold.renormalized_stiffness() <= 5.0);
+        
+        let hot = flow.phase(&film(1.0).unwrap(), &options).unwrap();
+        assert!(!hot.is_bound());
+        assert_eq!(hot.renormalized_stiffness(), 0.0);
+        match hot {
+            DefectPhase::Unbound { correlation_length: Some(length) } => assert!(length > 1.0),
+            other => panic!("expected free defects within the flow, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn film_couplings_come_from_splay_bend_and_core_energy() {
+        let params = KTParameters::from_macroscopic(&macroscopic_film(0.5)).unwrap();
+        assert!((params.stiffness - 6.0).abs() < 1e-12);
+        assert!((params.fugacity - (-2.0f64).exp()).abs() < 1e-12);
+        
+        let bulk = MacroscopicParameters { spatial_dimension: 3, ..macroscopic_film(0.5) };
+        assert!(matches!(
+            KTParameters::from_macroscopic(&bulk),
+            Err(RGFlowError::DimensionMismatch { expected: 2, actual: 3 })
+        ));
+        for temperature in [0.0, -1.0, f64::NAN] {
+            assert!(matches!(
+                KTParameters::from_macroscopic(&macroscopic_film(temperature)),
+                Err(RGFlowError::ParameterOutOfRange(_))
+            ));
+        }
+    }
+}
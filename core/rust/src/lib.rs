@@ -4,11 +4,23 @@
 //! at different scales using category theory and renormalization group flow.
 
 pub mod category;
+pub mod hashing;
+pub mod free_category;
+pub mod constructions;
+pub mod limits;
+pub mod comma;
+pub mod export;
+pub mod transformation;
+pub mod metric;
 pub mod functor;
+pub mod adjunction;
 pub mod rg_flow;
+pub mod phase_diagram;
+pub mod critical_surface;
 pub mod microscopic;
 pub mod mesoscopic;
 pub mod macroscopic;
+pub mod kosterlitz_thouless;
 pub mod manifold;
 pub mod visualization_data;
 
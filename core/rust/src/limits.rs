@@ -0,0 +1,380 @@
+use crate::category::{Category, CategoryError, FinCategory, Morphism, Object};
+
+/// A finite diagram in a category: objects and arrows between them by index
+#[derive(Clone, Debug)]
+pub struct Diagram<O: Object, M: Morphism<ObjectType = O>> {
+    /// Objects of the diagram
+    pub objects: Vec<O>,
+    
+    /// Arrows as (source index, target index, morphism)
+    pub arrows: Vec<(usize, usize, M)>,
+}
+
+impl<O: Object, M: Morphism<ObjectType = O>> Diagram<O, M> {
+    /// Check that every arrow connects the objects it claims to
+    fn validate(&self) -> Result<(), CategoryError> {
+        for (i, j, arrow) in &self.arrows {
+            let (source, target) = match (self.objects.get(*i), self.objects.get(*j)) {
+                (Some(source), Some(target)) => (source, target),
+                _ => return Err(CategoryError::ObjectNotFound(format!(
+                    "diagram object #{} or #{}", i, j
+                ))),
+            };
+            if arrow.domain().id() != source.id() || arrow.codomain().id() != target.id() {
+                return Err(CategoryError::CompositionMismatch(format!(
+                    "{} does not go from {} to {}", arrow.id(), source.id(), target.id()
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A universal cone over a diagram.
+///
+/// For limits the legs run from the apex to the diagram objects; for colimits
+/// they run from the diagram objects to the apex. Legs are in the order of the
+/// diagram objects.
+#[derive(Clone, Debug)]
+pub struct Cone<O: Object, M: Morphism<ObjectType = O>> {
+    /// The limit or colimit object
+    pub apex: O,
+    
+    /// One leg per diagram object
+    pub legs: Vec<M>,
+}
+
+/// Whether a leg is read as leaving the apex or entering it
+#[derive(Clone, Copy)]
+enum Direction {
+    Limit,
+    Colimit,
+}
+
+impl<O: Object, M: Morphism<ObjectType = O>> FinCategory<O, M> {
+    /// Whether two chains of morphisms, each applied first to last, are equal.
+    ///
+    /// A category built with `FinCategory::new` only stores the composites its
+    /// structure forces, so a chain without a composite is not an arrow and
+    /// agrees with nothing.
+    fn paths_agree(&self, left: &[&M], right: &[&M]) -> bool {
+        let reduce = |path: &[&M]| -> Option<String> {
+            let mut current = path[0];
+            for next in &path[1..] {
+                current = self.compose(current, next).ok()?;
+            }
+            Some(current.id())
+        };
+        match (reduce(left), reduce(right)) {
+            (Some(left), Some(right)) => left == right,
+            _ => false,
+        }
+    }
+    
+    /// Every cone (or cocone) over the diagram with the given apex
+    fn cones_at<'a>(
+        &'a self,
+        diagram: &Diagram<O, M>,
+        apex: &O,
+        direction: Direction,
+    ) -> Vec<Vec<&'a M>> {
+        let mut cones: Vec<Vec<&M>> = vec![Vec::new()];
+        for obj in &diagram.objects {
+            let legs = match direction {
+                Direction::Limit => self.hom(apex, obj),
+                Direction::Colimit => self.hom(obj, apex),
+            };
+            cones = cones.into_iter()
+                .flat_map(|cone| legs.iter().map(move |&leg| {
+                    let mut extended = cone.clone();
+                    extended.push(leg);
+                    extended
+                }))
+                .collect();
+        }
+        
+        // Keep only the cones that commute with every arrow of the diagram
+        let mut commuting = Vec::new();
+        'cones: for cone in cones {
+            for (i, j, arrow) in &diagram.arrows {
+                let agree = match direction {
+                    Direction::Limit => self.paths_agree(&[cone[*i], arrow], &[cone[*j]]),
+                    Direction::Colimit => self.paths_agree(&[arrow, cone[*j]], &[cone[*i]]),
+                };
+                if !agree {
+                    continue 'cones;
+                }
+            }
+            commuting.push(cone);
+        }
+        
+        commuting
+    }
+    
+    /// Search for a cone through which every other cone factors uniquely
+    fn universal_cone(
+        &self,
+        diagram: &Diagram<O, M>,
+        direction: Direction,
+    ) -> Result<Option<Cone<O, M>>, CategoryError> {
+        diagram.validate()?;
+        
+        let mut all_cones = Vec::new();
+        for obj in self.objects() {
+            for cone in self.cones_at(diagram, obj, direction) {
+                all_cones.push((obj, cone));
+            }
+        }
+        
+        'candidates: for (apex, legs) in &all_cones {
+            for (other_apex, other_legs) in &all_cones {
+                let mediators = match direction {
+                    Direction::Limit => self.hom(other_apex, apex),
+                    Direction::Colimit => self.hom(apex, other_apex),
+                };
+                
+                let mut factorizations = 0;
+                for u in mediators {
+                    let mut factors = true;
+                    for (leg, other_leg) in legs.iter().zip(other_legs) {
+                        let agree = match direction {
+                            Direction::Limit => self.paths_agree(&[u, leg], &[other_leg]),
+                            Direction::Colimit => self.paths_agree(&[leg, u], &[other_leg]),
+                        };
+                        if !agree {
+                            factors = false;
+                            break;
+                        }
+                    }
+                    if factors {
+                        factorizations += 1;
+                    }
+                }
+                
+                if factorizations != 1 {
+                    continue 'candidates;
+                }
+            }
+            
+            return Ok(Some(Cone {
+                apex: (*apex).clone(),
+                legs: legs.iter().map(|&leg| leg.clone()).collect(),
+            }));
+        }
+        
+        Ok(None)
+    }
+    
+    /// Compute the limit of a finite diagram, if it exists
+    pub fn limit(&self, diagram: &Diagram<O, M>) -> Result<Option<Cone<O, M>>, CategoryError> {
+        self.universal_cone(diagram, Direction::Limit)
+    }
+    
+    /// Compute the colimit of a finite diagram, if it exists
+    pub fn colimit(&self, diagram: &Diagram<O, M>) -> Result<Option<Cone<O, M>>, CategoryError> {
+        self.universal_cone(diagram, Direction::Colimit)
+    }
+    
+    /// Find a terminal object, as the limit of the empty diagram
+    pub fn terminal_object(&self) -> Result<Option<O>, CategoryError> {
+        let empty = Diagram { objects: Vec::new(), arrows: Vec::new() };
+        Ok(self.limit(&empty)?.map(|cone| cone.apex))
+    }
+    
+    /// Find an initial object, as the colimit of the empty diagram
+    pub fn initial_object(&self) -> Result<Option<O>, CategoryError> {
+        let empty = Diagram { objects: Vec::new(), arrows: Vec::new() };
+        Ok(self.colimit(&empty)?.map(|cone| cone.apex))
+    }
+    
+    /// Compute the product of two objects, with legs to `a` and `b`
+    pub fn product(&self, a: &O, b: &O) -> Result<Option<Cone<O, M>>, CategoryError> {
+        self.limit(&Diagram { objects: vec![a.clone(), b.clone()], arrows: Vec::new() })
+    }
+    
+    /// Compute the coproduct of two objects, with legs from `a` and `b`
+    pub fn coproduct(&self, a: &O, b: &O) -> Result<Option<Cone<O, M>>, CategoryError> {
+        self.colimit(&Diagram { objects: vec![a.clone(), b.clone()], arrows: Vec::new() })
+    }
+    
+    /// Compute the equalizer of two parallel morphisms `f, g: A->B`.
+    ///
+    /// The cone has legs to `A` and `B`; the first one is the equalizing morphism.
+    pub fn equalizer(&self, f: &M, g: &M) -> Result<Option<Cone<O, M>>, CategoryError> {
+        self.limit(&Diagram {
+            objects: vec![f.domain().clone(), f.codomain().clone()],
+            arrows: vec![(0, 1, f.clone()), (0, 1, g.clone())],
+        })
+    }
+    
+    /// Compute the coequalizer of two parallel morphisms `f, g: A->B`.
+    ///
+    /// The cocone has legs from `A` and `B`; the second one is the coequalizing morphism.
+    pub fn coequalizer(&self, f: &M, g: &M) -> Result<Option<Cone<O, M>>, CategoryError> {
+        self.colimit(&Diagram {
+            objects: vec![f.domain().clone(), f.codomain().clone()],
+            arrows: vec![(0, 1, f.clone()), (0, 1, g.clone())],
+        })
+    }
+    
+    /// Compute the pullback of `f: A->C` and `g: B->C`, e.g. the shared refinement of two configurations.
+    ///
+    /// The cone has legs to `A`, `B` and `C`.
+    pub fn pullback(&self, f: &M, g: &M) -> Result<Option<Cone<O, M>>, CategoryError> {
+        self.limit(&Diagram {
+            objects: vec![f.domain().clone(), g.domain().clone(), f.codomain().clone()],
+            arrows: vec![(0, 2, f.clone()), (1, 2, g.clone())],
+        })
+    }
+    
+    /// Compute the pushout of `f: C->A` and `g: C->B`, e.g. the common coarse-graining of two configurations.
+    ///
+    /// The cocone has legs from `A`, `B` and `C`.
+    pub fn pushout(&self, f: &M, g: &M) -> Result<Option<Cone<O, M>>, CategoryError> {
+        self.colimit(&Diagram {
+            objects: vec![f.codomain().clone(), g.codomain().clone(), f.domain().clone()],
+            arrows: vec![(2, 0, f.clone()), (2, 1, g.clone())],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::fixtures::{edge, identity, Edge, Node};
+    
+    /// The span A <-f- C -g-> B under the square to P, which maps on to Q.
+    /// Every hom-set has at most one arrow, so all composites are forced.
+    fn square(with_mediator: bool) -> FinCategory<Node, Edge> {
+        let mut morphisms = vec![
+            identity("A"), identity("B"), identity("C"), identity("P"), identity("Q"),
+            edge("f", "C", "A"), edge("g", "C", "B"),
+            edge("p", "A", "P"), edge("q", "B", "P"), edge("d", "C", "P"),
+            edge("a", "A", "Q"), edge("b", "B", "Q"), edge("c", "C", "Q"),
+        ];
+        if with_mediator {
+            morphisms.push(edge("u", "P", "Q"));
+        }
+        FinCategory::new(
+            "Square".to_string(),
+            vec![Node("A"), Node("B"), Node("C"), Node("P"), Node("Q")],
+            morphisms,
+        )
+    }
+    
+    /// The cospan A -f-> C <-g- B under the square from P, which Q maps into.
+    /// Every hom-set has at most one arrow, so all composites are forced.
+    fn cosquare(with_mediator: bool) -> FinCategory<Node, Edge> {
+        let mut morphisms = vec![
+            identity("A"), identity("B"), identity("C"), identity("P"), identity("Q"),
+            edge("f", "A", "C"), edge("g", "B", "C"),
+            edge("p", "P", "A"), edge("q", "P", "B"), edge("d", "P", "C"),
+            edge("a", "Q", "A"), edge("b", "Q", "B"), edge("c", "Q", "C"),
+        ];
+        if with_mediator {
+            morphisms.push(edge("u", "Q", "P"));
+        }
+        FinCategory::new(
+            "Cosquare".to_string(),
+            vec![Node("A"), Node("B"), Node("C"), Node("P"), Node("Q")],
+            morphisms,
+        )
+    }
+    
+    /// Two parallel arrows f, g: A -> B equalized by e: E -> A, through which
+    /// x: X -> A factors. Y -> A has two arrows to B, so neither `y;f` nor
+    /// `y;g` is forced and both composites are missing.
+    fn parallel_arrows() -> FinCategory<Node, Edge> {
+        FinCategory::new(
+            "Parallel".to_string(),
+            vec![Node("A"), Node("B"), Node("E"), Node("X"), Node("Y")],
+            vec![
+                identity("A"), identity("B"), identity("E"), identity("X"), identity("Y"),
+                edge("f", "A", "B"), edge("g", "A", "B"),
+                edge("e", "E", "A"), edge("h", "E", "B"),
+                edge("x", "X", "A"), edge("k", "X", "B"), edge("v", "X", "E"),
+                edge("y", "Y", "A"), edge("m", "Y", "B"), edge("n", "Y", "B"),
+            ],
+        )
+    }
+    
+    fn leg_ids<O: Object, M: Morphism<ObjectType = O>>(cone: &Cone<O, M>) -> Vec<String> {
+        cone.legs.iter().map(|leg| leg.id()).collect()
+    }
+    
+    #[test]
+    fn pushout_of_a_span_is_the_corner_of_the_square() {
+        let category = square(true);
+        assert!(category.check_laws().is_lawful());
+        let (f, g) = (edge("f", "C", "A"), edge("g", "C", "B"));
+        
+        let pushout = category.pushout(&f, &g).unwrap().unwrap();
+        assert_eq!(pushout.apex, Node("P"));
+        assert_eq!(leg_ids(&pushout), vec!["p", "q", "d"]);
+        assert_eq!(category.terminal_object().unwrap(), Some(Node("Q")));
+        assert_eq!(category.initial_object().unwrap(), Some(Node("C")));
+        
+        // Without P -> Q neither cocone factors through the other
+        assert!(square(false).pushout(&f, &g).unwrap().is_none());
+        
+        let misplaced = Diagram { objects: vec![Node("A"), Node("B")], arrows: vec![(0, 1, f.clone())] };
+        assert!(matches!(category.colimit(&misplaced), Err(CategoryError::CompositionMismatch(_))));
+    }
+    
+    #[test]
+    fn pullback_and_product_sit_at_the_corner_of_the_cosquare() {
+        let category = cosquare(true);
+        assert!(category.check_laws().is_lawful());
+        let (f, g) = (edge("f", "A", "C"), edge("g", "B", "C"));
+        
+        let pullback = category.pullback(&f, &g).unwrap().unwrap();
+        assert_eq!(pullback.apex, Node("P"));
+        assert_eq!(leg_ids(&pullback), vec!["p", "q", "d"]);
+        
+        let product = category.product(&Node("A"), &Node("B")).unwrap().unwrap();
+        assert_eq!(product.apex, Node("P"));
+        assert_eq!(leg_ids(&product), vec!["p", "q"]);
+        
+        // Without Q -> P neither cone factors through the other
+        let unmediated = cosquare(false);
+        assert!(unmediated.pullback(&f, &g).unwrap().is_none());
+        assert!(unmediated.product(&Node("A"), &Node("B")).unwrap().is_none());
+    }
+    
+    #[test]
+    fn equalizer_of_parallel_arrows_skips_missing_composites() {
+        let category = parallel_arrows();
+        let (f, g) = (edge("f", "A", "B"), edge("g", "A", "B"));
+        let (y, m) = (edge("y", "Y", "A"), edge("m", "Y", "B"));
+        
+        // The missing composites are a law violation, not an arrow that agrees with m
+        assert!(category.compose(&y, &f).is_err());
+        assert!(!category.check_laws().is_lawful());
+        assert!(!category.paths_agree(&[&y, &f], &[&m]));
+        assert!(category.paths_agree(&[&edge("v", "X", "E"), &edge("e", "E", "A")], &[&edge("x", "X", "A")]));
+        
+        let equalizer = category.equalizer(&f, &g).unwrap().unwrap();
+        assert_eq!(equalizer.apex, Node("E"));
+        assert_eq!(leg_ids(&equalizer), vec!["e", "h"]);
+    }
+    
+    #[test]
+    fn rotated_demo_configuration_is_the_common_coarsening() {
+        let category = crate::microscopic::create_microscopic_category();
+        let rotation = category.morphisms().iter().find(|m| !m.is_identity()).unwrap().clone();
+        let (original, rotated) = (rotation.domain().id(), rotation.codomain().id());
+        
+        // Both legs of the span are the rotation, so the rotated configuration closes it
+        let pushout = category.pushout(&rotation, &rotation).unwrap().unwrap();
+        assert_eq!(pushout.apex.id(), rotated);
+        assert_eq!(leg_ids(&pushout)[2], rotation.id());
+        
+        // and the original configuration is their shared refinement
+        let pullback = category.pullback(&rotation, &rotation).unwrap().unwrap();
+        assert_eq!(pullback.apex.id(), original);
+        assert_eq!(leg_ids(&pullback)[2], rotation.id());
+        
+        assert_eq!(category.terminal_object().unwrap().map(|obj| obj.id()), Some(rotated));
+        assert_eq!(category.initial_object().unwrap().map(|obj| obj.id()), Some(original));
+    }
+}
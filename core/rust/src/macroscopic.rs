@@ -1,10 +1,13 @@
 use crate::category::{Category, CategoryError, FinCategory, Morphism, Object};
-use crate::functor::{ConcreteFunctor, Functor};
-use crate::rg_flow::{ParameterSpace, RGFlowError};
-use crate::mesoscopic::{MesoscopicConfiguration, MesoscopicMorphism, MesoscopicParameters};
-use nalgebra::{DMatrix, DVector};
-use std::collections::HashMap;
-use std::fmt::Debug;
+use crate::functor::ConcreteFunctor;
+use crate::hashing::{self, ContentHasher};
+use crate::metric::{self, MetricMorphism, MetricObject};
+use crate::rg_flow::{self, FlowEvent, ParameterSpace, RGFlowError};
+use crate::mesoscopic::{MesoscopicConfiguration, MesoscopicMorphism, MesoscopicParameters, QTensorField};
+use crate::microscopic::QTensor;
+use crate::transformation::{self, ConfigurationMorphism, Transformation};
+use nalgebra::{DMatrix, DVector, Vector3};
+use std::f64::consts::PI;
 use thiserror::Error;
 
 /// Error types related to macroscopic models
@@ -64,10 +67,102 @@ pub struct MacroscopicConfiguration {
     
     /// Boundary conditions
     pub boundary_conditions: Option<String>,
+    
+    /// Floats closer than this are considered equal in `Object::id`
+    pub id_tolerance: f64,
+}
+
+impl MacroscopicConfiguration {
+    /// Create a configuration with the default ID tolerance
+    pub fn new(dimensions: [f64; 3], defects: Vec<Defect>, temperature: f64, boundary_conditions: Option<String>) -> Self {
+        Self { dimensions, defects, temperature, boundary_conditions, id_tolerance: hashing::DEFAULT_ID_TOLERANCE }
+    }
+    
+    /// The same configuration with floats compared up to `tolerance` in `Object::id`.
+    ///
+    /// Only configurations sharing a tolerance have comparable IDs.
+    pub fn with_id_tolerance(mut self, tolerance: f64) -> Self {
+        self.id_tolerance = tolerance;
+        self
+    }
+    
+    /// Identifier derived from the configuration data, with floats compared up to `tolerance`
+    pub fn content_id(&self, tolerance: f64) -> String {
+        let mut hasher = ContentHasher::new(tolerance);
+        
+        hasher.write_f64s(&self.dimensions);
+        hasher.write_usize(self.defects.len());
+        for defect in &self.defects {
+            hasher.write_f64s(&defect.position);
+            hasher.write_f64(defect.charge);
+            hasher.write_option(defect.orientation.as_ref(), |h, orientation| h.write_f64s(orientation));
+        }
+        hasher.write_f64(self.temperature);
+        hasher.write_option(self.boundary_conditions.as_ref(), |h, conditions| h.write_str(conditions));
+        
+        format!("MacroConfig_{:016x}", hasher.finish())
+    }
+    
+    /// Apply a transformation, giving the transformed configuration.
+    ///
+    /// Rotations are about the centre of the system, and defects that rotate,
+    /// translate or move out of it wrap around periodically.
+    pub fn transform(&self, transformation: &Transformation) -> Result<Self, CategoryError> {
+        let mut result = self.clone();
+        let dimensions = Vector3::from(self.dimensions);
+        let wrap = |x: Vector3<f64>| -> [f64; 3] {
+            let mut wrapped = [0.0; 3];
+            for axis in 0..3 {
+                wrapped[axis] = if dimensions[axis] > 0.0 { x[axis].rem_euclid(dimensions[axis]) } else { x[axis] };
+            }
+            wrapped
+        };
+        
+        match transformation {
+            Transformation::Identity => {}
+            Transformation::Rotation { axis, angle } => {
+                let rotation = transformation::rotation_matrix(axis, *angle)?;
+                let center = dimensions / 2.0;
+                for defect in &mut result.defects {
+                    defect.position = wrap(center + rotation * (Vector3::from(defect.position) - center));
+                    defect.orientation = defect.orientation.map(|o| (rotation * Vector3::from(o)).into());
+                }
+            }
+            Transformation::Translation { offset } => {
+                for defect in &mut result.defects {
+                    defect.position = wrap(Vector3::from(defect.position) + offset);
+                }
+            }
+            Transformation::TemperatureChange { delta } => {
+                result.temperature += delta;
+            }
+            Transformation::DefectMotion { displacements } => {
+                if displacements.len() != self.defects.len() {
+                    return Err(CategoryError::InvalidApplication(format!(
+                        "{} displacements given for {} defects", displacements.len(), self.defects.len()
+                    )));
+                }
+                for (defect, displacement) in result.defects.iter_mut().zip(displacements) {
+                    defect.position = wrap(Vector3::from(defect.position) + displacement);
+                }
+            }
+            Transformation::FieldApplication { .. }
+            | Transformation::CoarseGraining { .. }
+            | Transformation::Reconstruction { .. } => {
+                return Err(transformation.unsupported("a macroscopic configuration"));
+            }
+        }
+        
+        Ok(result)
+    }
 }
 
 impl Object for MacroscopicConfiguration {
     fn id(&self) -> String {
+        self.content_id(self.id_tolerance)
+    }
+    
+    fn label(&self) -> String {
         format!("MacroConfig_D{}_T{:.2}_#{}", 
                 self.dimensions.iter().map(|&d| d.to_string()).collect::<Vec<_>>().join("x"),
                 self.temperature,
@@ -79,6 +174,50 @@ impl Object for MacroscopicConfiguration {
     }
 }
 
+impl MetricObject for MacroscopicConfiguration {
+    /// Optimal matching distance between the defect sets plus the temperature difference.
+    ///
+    /// Creating or removing a defect costs half the longest side of the larger system.
+    fn distance(&self, other: &Self) -> f64 {
+        let unmatched_cost = self.dimensions.iter()
+            .chain(other.dimensions.iter())
+            .cloned()
+            .fold(0.0, f64::max) / 2.0;
+        defect_matching_distance(&self.defects, &other.defects, unmatched_cost)
+            + (self.temperature - other.temperature).abs()
+    }
+}
+
+/// Optimal matching (Wasserstein-like) distance between two sets of defects.
+///
+/// Defects are paired one-to-one so as to minimise the total distance moved;
+/// only defects of equal charge can be paired, and every defect left unpaired
+/// costs `unmatched_cost`. Capping each pair at twice that cost keeps this a
+/// metric on defect sets.
+pub fn defect_matching_distance(a: &[Defect], b: &[Defect], unmatched_cost: f64) -> f64 {
+    let (n, m) = (a.len(), b.len());
+    let mut cost = DMatrix::zeros(n + m, n + m);
+    
+    for i in 0..n + m {
+        for j in 0..n + m {
+            cost[(i, j)] = match (a.get(i), b.get(j)) {
+                (Some(p), Some(q)) if p.charge == q.charge => {
+                    let separation = (0..3)
+                        .map(|axis| (p.position[axis] - q.position[axis]).powi(2))
+                        .sum::<f64>()
+                        .sqrt();
+                    separation.min(2.0 * unmatched_cost)
+                }
+                (Some(_), Some(_)) => 2.0 * unmatched_cost,
+                (Some(_), None) | (None, Some(_)) => unmatched_cost,
+                (None, None) => 0.0,
+            };
+        }
+    }
+    
+    metric::min_cost_assignment(&cost).0
+}
+
 /// Parameters for the macroscopic Frank free energy model
 #[derive(Clone, Debug)]
 pub struct MacroscopicParameters {
@@ -95,6 +234,9 @@ pub struct MacroscopicParameters {
     
     /// Defect core energy
     pub core_energy: f64,
+    
+    /// Spatial dimension d of the system
+    pub spatial_dimension: usize,
 }
 
 impl ParameterSpace for MacroscopicParameters {
@@ -102,6 +244,10 @@ impl ParameterSpace for MacroscopicParameters {
         6 // k1, k2, k3, chi_a, temperature, core_energy
     }
     
+    fn spatial_dimension(&self) -> usize {
+        self.spatial_dimension
+    }
+    
     fn as_vector(&self) -> DVector<f64> {
         DVector::from_vec(vec![
             self.k1, self.k2, self.k3, 
@@ -109,7 +255,7 @@ impl ParameterSpace for MacroscopicParameters {
         ])
     }
     
-    fn from_vector(vec: DVector<f64>) -> Result<Self, RGFlowError> {
+    fn from_vector(vec: DVector<f64>, dim: usize) -> Result<Self, RGFlowError> {
         if vec.len() != 6 {
             return Err(RGFlowError::ParameterOutOfRange(
                 format!("Expected 6 parameters, got {}", vec.len())
@@ -123,6 +269,7 @@ impl ParameterSpace for MacroscopicParameters {
             chi_a: vec[3],
             temperature: vec[4],
             core_energy: vec[5],
+            spatial_dimension: dim,
         })
     }
     
@@ -140,11 +287,8 @@ pub struct MacroscopicMorphism {
     /// Codomain of this morphism
     pub codomain: MacroscopicConfiguration,
     
-    /// Type of transformation
-    pub transformation_type: String,
-    
-    /// Parameters of the transformation
-    pub parameters: Option<DVector<f64>>,
+    /// The transformation this morphism performs
+    pub transformation: Transformation,
 }
 
 impl Morphism for MacroscopicMorphism {
@@ -158,11 +302,26 @@ impl Morphism for MacroscopicMorphism {
         &self.codomain
     }
     
-    fn apply<T>(&self, data: &T) -> Result<T, CategoryError> 
-    where T: Clone + Debug {
-        // In a real implementation, this would transform the data
-        // For now, just return a clone
-        Ok(data.clone())
+    fn id(&self) -> String {
+        format!("{}:{}->{}", self.transformation, self.domain.id(), self.codomain.id())
+    }
+    
+    fn is_identity(&self) -> bool {
+        self.transformation == Transformation::Identity && self.domain.id() == self.codomain.id()
+    }
+    
+    fn label(&self) -> String {
+        self.transformation.name().to_string()
+    }
+    
+    fn apply(&self, obj: &Self::ObjectType) -> Result<Self::ObjectType, CategoryError> {
+        obj.transform(&self.transformation)
+    }
+}
+
+impl MetricMorphism for MacroscopicMorphism {
+    fn length(&self) -> f64 {
+        self.domain.distance(&self.codomain)
     }
 }
 
@@ -170,47 +329,46 @@ impl Morphism for MacroscopicMorphism {
 pub fn create_macroscopic_category() -> 
     FinCategory<MacroscopicConfiguration, MacroscopicMorphism> {
     // Create two sample configurations
-    let config1 = MacroscopicConfiguration {
-        dimensions: [10.0, 10.0, 10.0],
-        defects: vec![
+    let config1 = MacroscopicConfiguration::new(
+        [10.0, 10.0, 10.0],
+        vec![
             Defect::new([5.0, 5.0, 5.0], 1.0),
             Defect::new([7.0, 3.0, 5.0], -1.0),
         ],
-        temperature: 300.0,
-        boundary_conditions: None,
-    };
+        300.0,
+        None,
+    );
     
-    let config2 = MacroscopicConfiguration {
-        dimensions: [10.0, 10.0, 10.0],
-        defects: vec![
+    let config2 = MacroscopicConfiguration::new(
+        [10.0, 10.0, 10.0],
+        vec![
             Defect::new([4.0, 4.0, 5.0], 1.0),
             Defect::new([6.0, 6.0, 5.0], -1.0),
         ],
-        temperature: 300.0,
-        boundary_conditions: None,
-    };
+        300.0,
+        None,
+    );
     
     // Create a morphism between them
     let morphism = MacroscopicMorphism {
         domain: config1.clone(),
         codomain: config2.clone(),
-        transformation_type: "DefectMotion".to_string(),
-        parameters: None,
+        transformation: Transformation::DefectMotion {
+            displacements: vec![Vector3::new(-1.0, -1.0, 0.0), Vector3::new(-1.0, 3.0, 0.0)],
+        },
     };
     
     // Create identity morphisms
     let id1 = MacroscopicMorphism {
         domain: config1.clone(),
         codomain: config1.clone(),
-        transformation_type: "Identity".to_string(),
-        parameters: None,
+        transformation: Transformation::Identity,
     };
     
     let id2 = MacroscopicMorphism {
         domain: config2.clone(),
         codomain: config2.clone(),
-        transformation_type: "Identity".to_string(),
-        parameters: None,
+        transformation: Transformation::Identity,
     };
     
     // Create the category
@@ -221,16 +379,28 @@ pub fn create_macroscopic_category() ->
     )
 }
 
-/// Functor that maps from mesoscopic to macroscopic category
-pub fn create_meso_to_macro_functor(
-    meso_cat: FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
-    macro_cat: FinCategory<MacroscopicConfiguration, MacroscopicMorphism>,
-) -> ConcreteFunctor<
-    FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
-    FinCategory<MacroscopicConfiguration, MacroscopicMorphism>
-> {
-    // Define object mapping function: MesoscopicConfiguration -> MacroscopicConfiguration
-    let object_mapping = |meso_obj: &MesoscopicConfiguration| -> MacroscopicConfiguration {
+/// Thresholds for locating defects in a Q-tensor field
+#[derive(Clone, Debug, PartialEq)]
+pub struct DefectDetectionOptions {
+    /// Minimum summed change of the director to neighbouring cells
+    pub gradient_threshold: f64,
+    
+    /// Maximum scalar order parameter at a defect core
+    pub order_threshold: f64,
+}
+
+impl Default for DefectDetectionOptions {
+    fn default() -> Self {
+        Self {
+            gradient_threshold: 1.0,
+            order_threshold: 0.1,
+        }
+    }
+}
+
+impl DefectDetectionOptions {
+    /// Reduce a Q-tensor field to the defects it contains
+    pub fn detect_defects(&self, meso_obj: &MesoscopicConfiguration) -> MacroscopicConfiguration {
         // Extract dimensions
         let (nx, ny, nz) = meso_obj.field.resolution;
         let (dx, dy, dz) = meso_obj.field.spacing;
@@ -241,9 +411,9 @@ pub fn create_meso_to_macro_functor(
         
         // Simplified defect detection using the Q-tensor field
         // In a real implementation, this would use topological charge methods
-        for i in 1..nx-1 {
-            for j in 1..ny-1 {
-                for k in 1..nz-1 {
+        for i in 1..nx.saturating_sub(1) {
+            for j in 1..ny.saturating_sub(1) {
+                for k in 1..nz.saturating_sub(1) {
                     // Check for rapid changes in the director field
                     if let (Some(q_center), Some(q_x), Some(q_y), Some(q_z)) = (
                         meso_obj.field.get(i, j, k),
@@ -264,7 +434,7 @@ pub fn create_meso_to_macro_functor(
                         
                         // If the gradients are large, this could be a defect
                         let gradient_norm = grad_x + grad_y + grad_z;
-                        if gradient_norm > 1.0 && s_center < 0.1 {
+                        if gradient_norm > self.gradient_threshold && s_center < self.order_threshold {
                             // This is a potential defect
                             let position = [
                                 i as f64 * dx,
@@ -272,38 +442,125 @@ pub fn create_meso_to_macro_functor(
                                 k as f64 * dz
                             ];
                             
-                            // In a real implementation, calculate the charge from topological methods
-                            // Here we just use a placeholder
-                            let charge = if rand::random::<f64>() > 0.5 { 1.0 } else { -1.0 };
-                            
-                            defects.push(Defect::new(position, charge));
+                            // Candidates around which the director does not wind are
+                            // smooth distortions rather than defects
+                            let charge = ring_winding(&meso_obj.field, (i, j, k));
+                            if charge != 0.0 {
+                                defects.push(Defect::new(position, charge));
+                            }
                         }
                     }
                 }
             }
         }
         
-        MacroscopicConfiguration {
-            dimensions,
-            defects,
-            temperature: meso_obj.temperature,
-            boundary_conditions: meso_obj.boundary_conditions.clone(),
+        // Boundary conditions are carried over as sorted `name=value` pairs
+        let boundary_conditions = meso_obj.boundary_conditions.as_ref().map(|conditions| {
+            let mut pairs: Vec<String> = conditions.iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect();
+            pairs.sort();
+            pairs.join(", ")
+        });
+        
+        MacroscopicConfiguration::new(dimensions, defects, meso_obj.temperature, boundary_conditions)
+            .with_id_tolerance(meso_obj.id_tolerance)
+    }
+    
+    /// The morphism between the defects of the endpoints of `meso_morph`, carrying its transformation
+    pub fn detect_morphism(&self, meso_morph: &MesoscopicMorphism) -> MacroscopicMorphism {
+        MacroscopicMorphism {
+            domain: self.detect_defects(meso_morph.domain()),
+            codomain: self.detect_defects(meso_morph.codomain()),
+            transformation: meso_morph.transformation.clone(),
         }
-    };
+    }
     
-    // Define morphism mapping function: MesoscopicMorphism -> MacroscopicMorphism
-    let morphism_mapping = |meso_morph: &MesoscopicMorphism| -> Result<MacroscopicMorphism, CategoryError> {
-        // Map the domain and codomain objects
-        let macro_domain = object_mapping(meso_morph.domain());
-        let macro_codomain = object_mapping(meso_morph.codomain());
+    /// The category of the defects detected in each object and morphism of a
+    /// mesoscopic category, the target on which the meso→macro functor is lawful
+    pub fn detect_category(
+        &self,
+        meso_cat: &FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    ) -> FinCategory<MacroscopicConfiguration, MacroscopicMorphism> {
+        let objects = meso_cat.objects().iter().map(|obj| self.detect_defects(obj)).collect();
+        let morphisms = meso_cat.morphisms().iter().map(|morph| self.detect_morphism(morph)).collect();
         
-        // Create the corresponding macroscopic morphism
-        Ok(MacroscopicMorphism {
-            domain: macro_domain,
-            codomain: macro_codomain,
-            transformation_type: meso_morph.transformation_type.clone(),
-            parameters: meso_morph.parameters.clone(),
-        })
+        FinCategory::new(format!("Defects of {}", meso_cat.name()), objects, morphisms)
+    }
+}
+
+/// Strongest winding of the director around a cell.
+///
+/// For each axis the director is projected onto the plane normal to it and its
+/// angle followed around the ring of the eight neighbours of the cell in that
+/// plane. Directors are headless, so each step is wrapped into (-π/2, π/2] and
+/// the winding is a multiple of 1/2. Returns the winding of largest magnitude
+/// over the three planes, or zero if the ring leaves the field.
+fn ring_winding(field: &QTensorField, (i, j, k): (usize, usize, usize)) -> f64 {
+    const RING: [(i64, i64); 8] = [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+    
+    let mut strongest: f64 = 0.0;
+    for axis in 0..3 {
+        let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+        let mut angles = Vec::with_capacity(RING.len());
+        for (du, dv) in RING {
+            let mut site = [i as i64, j as i64, k as i64];
+            site[u] += du;
+            site[v] += dv;
+            if site.iter().any(|&x| x < 0) {
+                return 0.0;
+            }
+            let Some(q) = field.get(site[0] as usize, site[1] as usize, site[2] as usize) else {
+                return 0.0;
+            };
+            let (_, director) = q.to_director();
+            angles.push(director[v].atan2(director[u]));
+        }
+        
+        let mut total = 0.0;
+        for step in 0..angles.len() {
+            let mut delta = (angles[(step + 1) % angles.len()] - angles[step]).rem_euclid(PI);
+            if delta > PI / 2.0 {
+                delta -= PI;
+            }
+            total += delta;
+        }
+        
+        let winding = (total / PI).round() / 2.0;
+        if winding.abs() > strongest.abs() {
+            strongest = winding;
+        }
+    }
+    strongest
+}
+
+/// Functor that maps from mesoscopic to macroscopic category with the default thresholds
+pub fn create_meso_to_macro_functor(
+    meso_cat: FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    macro_cat: FinCategory<MacroscopicConfiguration, MacroscopicMorphism>,
+) -> ConcreteFunctor<
+    FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    FinCategory<MacroscopicConfiguration, MacroscopicMorphism>
+> {
+    create_meso_to_macro_functor_with(meso_cat, macro_cat, DefectDetectionOptions::default())
+}
+
+/// Functor that maps from mesoscopic to macroscopic category with the given thresholds
+pub fn create_meso_to_macro_functor_with(
+    meso_cat: FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    macro_cat: FinCategory<MacroscopicConfiguration, MacroscopicMorphism>,
+    options: DefectDetectionOptions,
+) -> ConcreteFunctor<
+    FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    FinCategory<MacroscopicConfiguration, MacroscopicMorphism>
+> {
+    // Define object mapping function: MesoscopicConfiguration -> MacroscopicConfiguration
+    let object_options = options.clone();
+    let object_mapping = move |meso_obj: &MesoscopicConfiguration| object_options.detect_defects(meso_obj);
+    
+    // Define morphism mapping function: MesoscopicMorphism -> MacroscopicMorphism
+    let morphism_mapping = move |meso_morph: &MesoscopicMorphism| -> Result<MacroscopicMorphism, CategoryError> {
+        Ok(options.detect_morphism(meso_morph))
     };
     
     ConcreteFunctor::new(
@@ -315,6 +572,130 @@ pub fn create_meso_to_macro_functor(
     )
 }
 
+/// Parameters for building a Q-tensor field around a set of defects
+#[derive(Clone, Debug, PartialEq)]
+pub struct DefectFieldOptions {
+    /// Target cell size of the generated field
+    pub spacing: f64,
+    
+    /// Scalar order parameter far from every defect
+    pub scalar_order: f64,
+    
+    /// Distance over which the order parameter vanishes towards a defect core
+    pub core_radius: f64,
+}
+
+impl Default for DefectFieldOptions {
+    fn default() -> Self {
+        Self {
+            spacing: 1.0,
+            scalar_order: 0.6,
+            core_radius: 1.0,
+        }
+    }
+}
+
+impl DefectFieldOptions {
+    /// Build a Q-tensor field containing the given defects as straight lines along z.
+    ///
+    /// The director lies in the xy-plane at an angle that winds by `2π × charge`
+    /// around each defect, and the order parameter drops to zero at each core.
+    pub fn build_field(&self, macro_obj: &MacroscopicConfiguration) -> MesoscopicConfiguration {
+        // Cells divide the system evenly, as close to the requested spacing as possible
+        let cells = |length: f64| ((length / self.spacing).round() as usize).max(1);
+        let resolution = (
+            cells(macro_obj.dimensions[0]),
+            cells(macro_obj.dimensions[1]),
+            cells(macro_obj.dimensions[2]),
+        );
+        let spacing = (
+            macro_obj.dimensions[0] / resolution.0 as f64,
+            macro_obj.dimensions[1] / resolution.1 as f64,
+            macro_obj.dimensions[2] / resolution.2 as f64,
+        );
+        
+        let mut field = QTensorField::new(resolution, spacing);
+        for i in 0..resolution.0 {
+            for j in 0..resolution.1 {
+                let (x, y) = (i as f64 * spacing.0, j as f64 * spacing.1);
+                let mut angle = 0.0;
+                let mut order = self.scalar_order;
+                
+                for defect in &macro_obj.defects {
+                    let (rx, ry) = (x - defect.position[0], y - defect.position[1]);
+                    angle += defect.charge * ry.atan2(rx);
+                    if self.core_radius > 0.0 {
+                        order *= 1.0 - (-(rx * rx + ry * ry) / (self.core_radius * self.core_radius)).exp();
+                    }
+                }
+                
+                let director = Vector3::new(angle.cos(), angle.sin(), 0.0);
+                let q = QTensor::from_director(&director, order).expect("the director is a unit vector");
+                for k in 0..resolution.2 {
+                    field.set(i, j, k, q.clone()).expect("the cell lies inside the field");
+                }
+            }
+        }
+        
+        // Boundary conditions written as `name=value` pairs are split up again
+        let boundary_conditions = macro_obj.boundary_conditions.as_ref().map(|conditions| {
+            conditions.split(", ")
+                .map(|pair| match pair.split_once('=') {
+                    Some((name, value)) => (name.to_string(), value.to_string()),
+                    None => ("conditions".to_string(), pair.to_string()),
+                })
+                .collect()
+        });
+        
+        MesoscopicConfiguration::new(field, macro_obj.temperature, None, boundary_conditions)
+            .with_id_tolerance(macro_obj.id_tolerance)
+    }
+}
+
+/// Functor that maps from macroscopic to mesoscopic category with the default field parameters
+pub fn create_macro_to_meso_functor(
+    macro_cat: FinCategory<MacroscopicConfiguration, MacroscopicMorphism>,
+    meso_cat: FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+) -> ConcreteFunctor<
+    FinCategory<MacroscopicConfiguration, MacroscopicMorphism>,
+    FinCategory<MesoscopicConfiguration, MesoscopicMorphism>
+> {
+    create_macro_to_meso_functor_with(macro_cat, meso_cat, DefectFieldOptions::default())
+}
+
+/// Functor that maps from macroscopic to mesoscopic category with the given field parameters
+pub fn create_macro_to_meso_functor_with(
+    macro_cat: FinCategory<MacroscopicConfiguration, MacroscopicMorphism>,
+    meso_cat: FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    options: DefectFieldOptions,
+) -> ConcreteFunctor<
+    FinCategory<MacroscopicConfiguration, MacroscopicMorphism>,
+    FinCategory<MesoscopicConfiguration, MesoscopicMorphism>
+> {
+    // Define object mapping function: MacroscopicConfiguration -> MesoscopicConfiguration
+    let object_options = options.clone();
+    let object_mapping = move |macro_obj: &MacroscopicConfiguration| object_options.build_field(macro_obj);
+    
+    // Define morphism mapping function: MacroscopicMorphism -> MesoscopicMorphism
+    let morphism_mapping = move |macro_morph: &MacroscopicMorphism| -> Result<MesoscopicMorphism, CategoryError> {
+        let meso_domain = options.build_field(macro_morph.domain());
+        let meso_codomain = options.build_field(macro_morph.codomain());
+        
+        // Defects carry no Q-tensors, so there is no residual to map
+        MesoscopicMorphism::map_transformation(&macro_morph.transformation, meso_domain, meso_codomain, |_| {
+            Err(macro_morph.transformation.unsupported("a macroscopic configuration"))
+        })
+    };
+    
+    ConcreteFunctor::new(
+        "MacroToMeso".to_string(),
+        macro_cat,
+        meso_cat,
+        object_mapping,
+        morphism_mapping,
+    )
+}
+
 /// Converts mesoscopic parameters to macroscopic parameters
 pub fn convert_to_macroscopic_parameters(
     meso_params: &MesoscopicParameters
@@ -344,6 +725,7 @@ pub fn convert_to_macroscopic_parameters(
         chi_a,
         temperature: temp,
         core_energy,
+        spatial_dimension: meso_params.spatial_dimension,
     })
 }
 
@@ -382,32 +764,10 @@ pub fn defect_interaction_energy(
 /// Implement an RG step for macroscopic parameters
 pub fn rg_step_macroscopic(params: &MacroscopicParameters) -> Result<MacroscopicParameters, RGFlowError> {
     // Scale factor for this RG step
-    let scale = 1.5;
-    
-    // Simple RG transformation rules:
-    // 1. Frank elastic constants are dimensionful and scale with length
-    let k1_new = params.k1 * scale;
-    let k2_new = params.k2 * scale;
-    let k3_new = params.k3 * scale;
+    let scale: f64 = 1.5;
     
-    // 2. Susceptibility scales
-    let chi_a_new = params.chi_a * scale.powf(-1.0);
-    
-    // 3. Temperature flows to fixed point
-    let t_star = 300.0; // Critical temperature
-    let t_new = t_star + (params.temperature - t_star) * scale.powf(-1.0);
-    
-    // 4. Core energy scales with length
-    let core_new = params.core_energy * scale;
-    
-    Ok(MacroscopicParameters {
-        k1: k1_new,
-        k2: k2_new,
-        k3: k3_new,
-        chi_a: chi_a_new,
-        temperature: t_new,
-        core_energy: core_new,
-    })
+    // Integrate the beta function over one step
+    rg_flow::rescale_by(beta_function_macroscopic, params, scale)
 }
 
 /// Calculate the beta function for macroscopic parameters
@@ -415,14 +775,339 @@ pub fn beta_function_macroscopic(params: &MacroscopicParameters) -> Result<DVect
     // Calculate beta functions for all parameters
     // β(g) = dg/dl where l is the log of the scale factor
     
-    let beta_k1 = params.k1; // Scales with length
-    let beta_k2 = params.k2; // Scales with length
-    let beta_k3 = params.k3; // Scales with length
+    // Frank constants and the core energy are energies per length^(d - 2)
+    let y_k = params.spatial_dimension as f64 - 2.0;
+    
+    let beta_k1 = y_k * params.k1; // Scales with length^(d - 2)
+    let beta_k2 = y_k * params.k2; // Scales with length^(d - 2)
+    let beta_k3 = y_k * params.k3; // Scales with length^(d - 2)
     let beta_chi_a = -params.chi_a; // Scales with inverse length
     let beta_t = -(params.temperature - 300.0); // Flow to fixed point
-    let beta_core = params.core_energy; // Scales with length
+    let beta_core = y_k * params.core_energy; // Scales with length^(d - 2)
     
     Ok(DVector::from_vec(vec![
         beta_k1, beta_k2, beta_k3, beta_chi_a, beta_t, beta_core
     ]))
 }
+
+/// Number of angles in the quadrature over the momentum shell
+const SHELL_QUADRATURE_POINTS: usize = 64;
+
+/// K_d Λ^(d-2) with Λ = 1: the area of the unit sphere in d dimensions over (2π)^d
+fn shell_factor(d: usize) -> f64 {
+    // Γ(d/2) from Γ(1) = 1 and Γ(1/2) = √π
+    let (mut gamma, mut x) = if d % 2 == 1 { (PI.sqrt(), 0.5) } else { (1.0, 1.0) };
+    while x < d as f64 / 2.0 {
+        gamma *= x;
+        x += 1.0;
+    }
+    
+    let sphere = 2.0 * PI.powf(d as f64 / 2.0) / gamma;
+    sphere / (2.0 * PI).powi(d as i32)
+}
+
+/// Mean of 1/(k_perp sin²ψ + k_par cos²ψ) over the momentum shell, with ψ the
+/// angle between the wavevector and the director.
+///
+/// This is the thermal amplitude of a director mode with stiffness k_perp
+/// across the director and k_par along it, per unit k_B T.
+fn mode_amplitude(k_perp: f64, k_par: f64, d: usize) -> f64 {
+    // The shell measure is sin^(d-2)ψ dψ; the integrand is symmetric about π/2
+    let h = 0.5 * PI / SHELL_QUADRATURE_POINTS as f64;
+    let (mut sum, mut norm) = (0.0, 0.0);
+    for i in 0..SHELL_QUADRATURE_POINTS {
+        let psi = (i as f64 + 0.5) * h;
+        let (sin, cos) = psi.sin_cos();
+        let weight = sin.powi(d as i32 - 2);
+        sum += weight / (k_perp * sin * sin + k_par * cos * cos);
+        norm += weight;
+    }
+    sum / norm
+}
+
+/// Beta function of the macroscopic parameters with the Frank constants
+/// softened by thermal director fluctuations, to one loop.
+///
+/// Integrating out a shell of director modes tilts the local director by a
+/// random angle with variance a = K_d k_B T ⟨1/K⟩ per axis, where ⟨1/K⟩ averages
+/// the inverse stiffness of the splay-bend (K1, K3) and twist-bend (K2, K3)
+/// modes over the shell and k_B T is the temperature. The tilt shortens every
+/// slow deformation, taking a·K_i from each constant, and turns it relative to
+/// the director, which mixes splay and twist into bend and back:
+///
+/// dK1/dℓ = (d - 2) K1 - a (2 K1 - K3)
+/// dK2/dℓ = (d - 2) K2 - a (2 K2 - K3)
+/// dK3/dℓ = (d - 2) K3 - a (3 K3 - K1 - K2)
+///
+/// With equal constants this is the one-loop flow of the director as a
+/// three-component unit vector, dK/dℓ = (d - 2) K - K_d k_B T. Near equal
+/// constants the mixing makes K1/K2 relax at rate a and K3 relative to the
+/// other two at rate 3a, so fluctuations drive the constants towards the
+/// one-constant approximation. The temperature is a physical parameter and
+/// does not flow; the other couplings flow as in [`beta_function_macroscopic`].
+///
+/// In d = 2, and in d = 3 once k_B T is comparable to K, fluctuations drive a
+/// constant to zero at finite ℓ: the director has lost its stiffness and order
+/// persists only up to e^ℓ core sizes. Past that point the fluctuation
+/// correction is meaningless and only the rescaling is kept, so the flow stays
+/// finite; integrate with [`stiffness_loss_events`] to stop it there.
+pub fn beta_function_frank_fluctuations(params: &MacroscopicParameters) -> Result<DVector<f64>, RGFlowError> {
+    let (k1, k2, k3) = (params.k1, params.k2, params.k3);
+    let d = params.spatial_dimension;
+    let y_k = d as f64 - 2.0;
+    
+    // Tilt variance per axis from the shell, averaged over the two transverse modes
+    let tilt = if k1 > 0.0 && k2 > 0.0 && k3 > 0.0 {
+        params.temperature * shell_factor(d)
+            * 0.5 * (mode_amplitude(k1, k3, d) + mode_amplitude(k2, k3, d))
+    } else {
+        0.0
+    };
+    
+    let mut beta = beta_function_macroscopic(params)?;
+    beta[0] = y_k * k1 - tilt * (2.0 * k1 - k3);
+    beta[1] = y_k * k2 - tilt * (2.0 * k2 - k3);
+    beta[2] = y_k * k3 - tilt * (3.0 * k3 - k1 - k2);
+    beta[4] = 0.0;
+    
+    Ok(beta)
+}
+
+/// Events ending a flow of [`beta_function_frank_fluctuations`] where one of
+/// K1, K2, K3 vanishes
+pub fn stiffness_loss_events() -> Vec<FlowEvent> {
+    (0..3).map(|parameter| FlowEvent::CrossesZero { parameter }).collect()
+}
+
+/// RG step for macroscopic parameters with thermally renormalized Frank constants
+pub fn rg_step_frank_fluctuations(params: &MacroscopicParameters) -> Result<MacroscopicParameters, RGFlowError> {
+    // Scale factor for this RG step
+    let scale: f64 = 1.5;
+    
+    // Integrate the beta function over one step
+    rg_flow::rescale_by(beta_function_frank_fluctuations, params, scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functor::IdentityFunctor;
+    use crate::rg_flow::{ConcreteRGFlow, FlowTermination, IntegrationOptions, RGFlow};
+    
+    #[test]
+    fn frank_flow_at_equal_constants() {
+        assert!((shell_factor(2) - 1.0 / (2.0 * PI)).abs() < 1e-15);
+        assert!((shell_factor(3) - 1.0 / (2.0 * PI * PI)).abs() < 1e-15);
+        
+        for d in [2, 3] {
+            let params = MacroscopicParameters {
+                k1: 2.0,
+                k2: 2.0,
+                k3: 2.0,
+                chi_a: 0.1,
+                temperature: 0.3,
+                core_energy: 1.0,
+                spatial_dimension: d,
+            };
+            let beta = beta_function_frank_fluctuations(&params).unwrap();
+            
+            // dK/dℓ = (d - 2) K - K_d T for every constant
+            let expected = (d as f64 - 2.0) * 2.0 - shell_factor(d) * 0.3;
+            for constant in 0..3 {
+                assert!((beta[constant] - expected).abs() < 1e-12, "d = {}: {} against {}", d, beta[constant], expected);
+            }
+        }
+    }
+    
+    #[test]
+    fn frank_anisotropy_relaxes_at_the_tilt_rates() {
+        let (k, temperature) = (2.0, 0.3);
+        for d in [2, 3] {
+            let params = MacroscopicParameters {
+                k1: k * (1.0 + 2e-4),
+                k2: k * (1.0 - 1e-4),
+                k3: k * (1.0 + 3e-4),
+                chi_a: 0.1,
+                temperature,
+                core_energy: 1.0,
+                spatial_dimension: d,
+            };
+            let (k1, k2, k3) = (params.k1, params.k2, params.k3);
+            let beta = beta_function_frank_fluctuations(&params).unwrap();
+            
+            // Near equal constants both modes have amplitude 1/K
+            let tilt = temperature * shell_factor(d) / k;
+            
+            // d ln(K1/K2)/dℓ = -a ln(K1/K2)
+            let splay_twist = beta[0] / k1 - beta[1] / k2;
+            let expected = -tilt * (k1 / k2).ln();
+            assert!((splay_twist - expected).abs() < 1e-2 * expected.abs(), "d = {}: {} against {}", d, splay_twist, expected);
+            
+            // d ln(K3/K̄)/dℓ = -3a ln(K3/K̄) for the mean K̄ of K1 and K2
+            let mean = 0.5 * (k1 + k2);
+            let bend = beta[2] / k3 - 0.5 * (beta[0] + beta[1]) / mean;
+            let expected = -3.0 * tilt * (k3 / mean).ln();
+            assert!((bend - expected).abs() < 1e-2 * expected.abs(), "d = {}: {} against {}", d, bend, expected);
+        }
+    }
+    
+    #[test]
+    fn film_flow_stops_where_a_frank_constant_vanishes() {
+        let thermal_flow = |d| ConcreteRGFlow::new(
+            "MacroscopicThermalRG".to_string(),
+            create_macroscopic_category(),
+            IdentityFunctor::<FinCategory<MacroscopicConfiguration, MacroscopicMorphism>>::new(),
+            rg_step_frank_fluctuations,
+            beta_function_frank_fluctuations,
+            d,
+        );
+        let options = IntegrationOptions { events: stiffness_loss_events(), ..IntegrationOptions::default() };
+        
+        // Equal constants soften at the constant rate T/2π and vanish together at ℓ = 2πK/T
+        let equal = MacroscopicParameters {
+            k1: 1.0,
+            k2: 1.0,
+            k3: 1.0,
+            chi_a: 0.1,
+            temperature: 0.5,
+            core_energy: 1.0,
+            spatial_dimension: 2,
+        };
+        let trajectory = thermal_flow(2).integrate(&equal, 100.0, &options).unwrap();
+        assert!(matches!(trajectory.termination, FlowTermination::Event { .. }));
+        assert!((trajectory.scales.last().unwrap() - 4.0 * PI).abs() < 1e-6);
+        
+        // The tilt rates grow as 1/K, so unequal constants lose their anisotropy and vanish together
+        let unequal = MacroscopicParameters { k1: 1.5, k2: 0.8, k3: 2.0, ..equal.clone() };
+        let trajectory = thermal_flow(2).integrate(&unequal, 100.0, &options).unwrap();
+        assert!(matches!(trajectory.termination, FlowTermination::Event { .. }));
+        let end = trajectory.points.last().unwrap();
+        assert!(end.k1.abs() < 1e-6 && end.k2.abs() < 1e-6 && end.k3.abs() < 1e-6);
+        let before = &trajectory.points[trajectory.points.len() - 2];
+        assert!((before.k2 / before.k1 - 1.0).abs() < 1e-2 && (before.k3 / before.k1 - 1.0).abs() < 1e-2);
+        
+        // In bulk the rescaling outgrows the same fluctuations
+        let bulk = MacroscopicParameters { spatial_dimension: 3, ..equal };
+        let trajectory = thermal_flow(3).integrate(&bulk, 20.0, &options).unwrap();
+        assert_eq!(trajectory.termination, FlowTermination::Completed);
+    }
+    
+    #[test]
+    fn frank_constants_scale_with_length_to_the_d_minus_two() {
+        let film = MacroscopicParameters {
+            k1: 1.0,
+            k2: 2.0,
+            k3: 3.0,
+            chi_a: 0.5,
+            temperature: 300.0,
+            core_energy: 4.0,
+            spatial_dimension: 2,
+        };
+        
+        // Marginal in a film, growing linearly with length in bulk
+        let beta = beta_function_macroscopic(&film).unwrap();
+        assert_eq!(beta.as_slice(), &[0.0, 0.0, 0.0, -0.5, 0.0, 0.0]);
+        let bulk = MacroscopicParameters { spatial_dimension: 3, ..film.clone() };
+        let beta = beta_function_macroscopic(&bulk).unwrap();
+        assert_eq!(beta.as_slice(), &[1.0, 2.0, 3.0, -0.5, 0.0, 4.0]);
+        
+        let film_step = rg_step_macroscopic(&film).unwrap();
+        assert_eq!((film_step.k1, film_step.k2, film_step.k3, film_step.core_energy), (1.0, 2.0, 3.0, 4.0));
+        let bulk_step = rg_step_macroscopic(&bulk).unwrap();
+        assert!((bulk_step.k3 / 3.0 - 1.5).abs() < 1e-6 && (bulk_step.core_energy / 4.0 - 1.5).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn defect_matching_pays_for_unmatched_and_mismatched_charges() {
+        let plus = |x: f64| Defect::new([x, 0.0, 0.0], 0.5);
+        let minus = |x: f64| Defect::new([x, 0.0, 0.0], -0.5);
+        let cost = 5.0;
+        
+        // The +1/2 moves by 1 and the -1/2 is created from nothing
+        assert!((defect_matching_distance(&[plus(0.0)], &[plus(1.0), minus(0.0)], cost) - 6.0).abs() < 1e-12);
+        // Defects of opposite charge cannot be paired, however close
+        assert!((defect_matching_distance(&[plus(0.0)], &[minus(0.0)], cost) - 2.0 * cost).abs() < 1e-12);
+        // Far-apart pairs cost no more than removing one defect and creating the other
+        assert!((defect_matching_distance(&[plus(0.0)], &[plus(40.0)], cost) - 2.0 * cost).abs() < 1e-12);
+        assert_eq!(defect_matching_distance(&[], &[], cost), 0.0);
+        
+        let sets = [
+            vec![],
+            vec![plus(0.0)],
+            vec![plus(3.0), minus(1.0)],
+            vec![minus(4.0), minus(0.5), plus(8.0)],
+            vec![plus(1.0), plus(2.0)],
+        ];
+        for a in &sets {
+            for b in &sets {
+                let ab = defect_matching_distance(a, b, cost);
+                assert!((ab - defect_matching_distance(b, a, cost)).abs() < 1e-12);
+                for c in &sets {
+                    let detour = defect_matching_distance(a, c, cost) + defect_matching_distance(c, b, cost);
+                    assert!(ab <= detour + 1e-12, "{:?} -> {:?} via {:?}: {} > {}", a, b, c, ab, detour);
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn demo_functors_are_lawful() {
+        use crate::functor::{ComposedFunctor, Functor};
+        use crate::mesoscopic::create_micro_to_meso_functor;
+        use crate::mesoscopic::fixtures::{demo_categories, disclination_categories};
+        
+        let (micro_cat, meso_cat) = demo_categories();
+        let macro_cat = DefectDetectionOptions::default().detect_category(&meso_cat);
+        
+        let micro_to_meso = create_micro_to_meso_functor(micro_cat.clone(), meso_cat.clone());
+        let meso_to_macro = create_meso_to_macro_functor(meso_cat.clone(), macro_cat.clone());
+        let micro_to_macro = ComposedFunctor::new(micro_to_meso.clone(), meso_to_macro.clone());
+        
+        assert!(micro_to_meso.verify_functor_laws(&micro_cat, &meso_cat).is_lawful());
+        assert!(meso_to_macro.verify_functor_laws(&meso_cat, &macro_cat).is_lawful());
+        assert!(micro_to_macro.verify_functor_laws(&micro_cat, &macro_cat).is_lawful());
+        assert!(create_macroscopic_category().check_actions().is_lawful());
+        
+        // The disclinations of a lattice survive both coarsenings
+        let (micro_cat, meso_cat) = disclination_categories();
+        let macro_cat = DefectDetectionOptions::default().detect_category(&meso_cat);
+        let micro_to_macro = ComposedFunctor::new(
+            create_micro_to_meso_functor(micro_cat.clone(), meso_cat.clone()),
+            create_meso_to_macro_functor(meso_cat, macro_cat.clone()),
+        );
+        assert!(micro_to_macro.verify_functor_laws(&micro_cat, &macro_cat).is_lawful());
+        for config in macro_cat.objects() {
+            let charges: Vec<f64> = config.defects.iter().map(|defect| defect.charge).collect();
+            assert_eq!(charges, vec![1.0, -1.0]);
+        }
+    }
+    
+    #[test]
+    fn defect_charge_follows_director_winding() {
+        for winding in [1.0, -1.0] {
+            // A disclination line along z through the cells (2, 2, k)
+            let mut field = QTensorField::new((5, 5, 3), (1.0, 1.0, 1.0));
+            for i in 0..5 {
+                for j in 0..5 {
+                    for k in 0..3 {
+                        let angle = winding * (j as f64 - 2.0).atan2(i as f64 - 2.0);
+                        let order = if (i, j) == (2, 2) { 0.0 } else { 0.5 };
+                        let director = Vector3::new(angle.cos(), angle.sin(), 0.0);
+                        field.set(i, j, k, QTensor::from_director(&director, order).unwrap()).unwrap();
+                    }
+                }
+            }
+            
+            assert_eq!(ring_winding(&field, (2, 2, 1)), winding);
+            assert_eq!(ring_winding(&field, (0, 2, 1)), 0.0);
+            
+            let meso = MesoscopicConfiguration::new(field, 300.0, None, None);
+            let options = DefectDetectionOptions::default();
+            let detected = options.detect_defects(&meso);
+            assert_eq!(detected.defects.len(), 1);
+            assert_eq!(detected.defects[0].position, [2.0, 2.0, 1.0]);
+            assert_eq!(detected.defects[0].charge, winding);
+            assert_eq!(detected.id(), options.detect_defects(&meso).id());
+        }
+    }
+}
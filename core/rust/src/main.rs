@@ -1,26 +1,28 @@
 use catlc::{
-    microscopic::{self, MicroscopicConfiguration, MicroscopicParameters},
-    mesoscopic::{self, MesoscopicParameters},
-    macroscopic::{self, MacroscopicParameters},
-    rg_flow::{RGFlow, ConcreteRGFlow},
-    category::{Category, FinCategory},
-    functor::{Functor, ConcreteFunctor},
+    microscopic::{self, MicroscopicParameters},
+    mesoscopic::{self, CoarseGrainingOptions, MesoscopicParameters, RefinementOptions},
+    macroscopic::{self, DefectDetectionOptions, MacroscopicParameters},
+    kosterlitz_thouless::{KTParameters, KosterlitzThoulessFlow, UnbindingOptions},
+    rg_flow::{RGFlow, ConcreteRGFlow, ParameterSpace, FixedPointCatalogue, FixedPointSearchOptions, FlowTermination, IntegrationOptions},
+    category::{Category, Morphism, Object},
+    functor::{Functor, ComposedFunctor},
     visualization_data::{
         microscopic_to_director_field, 
         mesoscopic_to_director_field,
         macroscopic_to_defect_data,
         generate_curved_surface_data,
         save_to_json,
-        generate_rg_flow_data
+        generate_rg_flow_data,
+        generate_phase_diagram_data
     },
+    phase_diagram::{PhasePlane, PlaneAxis, PhaseDiagramOptions, Basin, map_phase_diagram},
     manifold::CurvedSpace,
+    export::{ExportOptions, category_to_data, concrete_functor_to_dot, functor_to_dot},
 };
-use nalgebra::{Vector3, DVector};
-use log::{info, error};
+use nalgebra::Vector3;
+use log::{info, warn, error};
 use std::error::Error;
-use std::path::Path;
 use std::fs;
-use std::io::Write;
 
 fn main() -> Result<(), Box<dyn Error>> {
     env_logger::init();
@@ -123,6 +125,7 @@ fn run_microscopic_simulation() -> Result<(), Box<dyn Error>> {
         l3: 1.0,
         h: 0.0,
         temperature: 300.0,
+        spatial_dimension: 3,
     };
     
     // Calculate free energy for a configuration
@@ -162,6 +165,7 @@ fn run_mesoscopic_simulation() -> Result<(), Box<dyn Error>> {
         h: 0.0,
         temperature: 300.0,
         xi: 1.0,
+        spatial_dimension: 3,
     };
     
     // Execute RG steps
@@ -222,6 +226,7 @@ fn run_macroscopic_simulation() -> Result<(), Box<dyn Error>> {
         chi_a: 1.0,
         temperature: 300.0,
         core_energy: 5.0,
+        spatial_dimension: 3,
     };
     
     // Calculate interaction energy
@@ -237,8 +242,9 @@ fn run_rg_flow_analysis() -> Result<(), Box<dyn Error>> {
     
     // Create categories for different scales
     let micro_cat = microscopic::create_microscopic_category();
-    let meso_cat = mesoscopic::create_mesoscopic_category();
-    let macro_cat = macroscopic::create_macroscopic_category();
+    // The coarse-graining of the microscopic demo and its defects, so the functors land on their objects
+    let meso_cat = CoarseGrainingOptions::default().coarse_grain_category(&micro_cat, "MesoscopicCategory")?;
+    let macro_cat = DefectDetectionOptions::default().detect_category(&meso_cat);
     
     // Create functors between categories
     info!("Creating functors between categories");
@@ -252,6 +258,61 @@ fn run_rg_flow_analysis() -> Result<(), Box<dyn Error>> {
         macro_cat.clone()
     );
     
+    // The full pipeline from lattice to continuum as a single functor
+    let micro_to_macro = ComposedFunctor::new(micro_to_meso.clone(), meso_to_macro.clone());
+    
+    // The coarse-graining maps are only useful if they respect composition
+    let functor_laws = [
+        (micro_to_meso.name(), micro_to_meso.verify_functor_laws(&micro_cat, &meso_cat)),
+        (meso_to_macro.name(), meso_to_macro.verify_functor_laws(&meso_cat, &macro_cat)),
+        ("MicroToMacro", micro_to_macro.verify_functor_laws(&micro_cat, &macro_cat)),
+    ];
+    for (functor_name, laws) in &functor_laws {
+        if laws.is_lawful() {
+            info!("{} satisfies the functor laws ({} checks)", functor_name, laws.checked);
+        } else {
+            warn!("{} violates the functor laws: {}", functor_name, laws);
+        }
+    }
+    
+    // Distinct lattice transformations that look the same after coarse-graining
+    let micro_morphisms = micro_cat.morphisms();
+    for (i, j) in micro_to_meso.analyze(&micro_cat, &meso_cat).identified {
+        info!(
+            "{} identifies {} with {}",
+            micro_to_meso.name(),
+            micro_morphisms[i].label(),
+            micro_morphisms[j].label()
+        );
+    }
+    
+    // Detail each lattice configuration loses to coarse-graining, as the length of the refinement counit
+    let refinement = mesoscopic::create_refinement_adjunction(
+        micro_cat.clone(),
+        meso_cat.clone(),
+        CoarseGrainingOptions::default(),
+        RefinementOptions::default(),
+    );
+    for obj in micro_cat.objects() {
+        match refinement.information_loss(obj) {
+            Ok(loss) => info!("Coarse-graining {} loses detail of size {:.4}", obj.label(), loss),
+            Err(err) => warn!("Cannot reconstruct {} after coarse-graining: {}", obj.label(), err),
+        }
+    }
+    
+    // Export the scale hierarchy for inspection
+    info!("Exporting category diagrams");
+    let export_options = ExportOptions { identities: false, compositions: true };
+    fs::write("output/micro_to_meso.dot", concrete_functor_to_dot(&micro_to_meso, &export_options))?;
+    fs::write("output/meso_to_macro.dot", concrete_functor_to_dot(&meso_to_macro, &export_options))?;
+    fs::write(
+        "output/micro_to_macro.dot",
+        functor_to_dot(&micro_to_macro, "MicroToMacro", &micro_cat, &macro_cat, &export_options),
+    )?;
+    save_to_json(&category_to_data(&micro_cat, "Microscopic", &export_options), "output/micro_category.json")?;
+    save_to_json(&category_to_data(&meso_cat, "Mesoscopic", &export_options), "output/meso_category.json")?;
+    save_to_json(&category_to_data(&macro_cat, "Macroscopic", &export_options), "output/macro_category.json")?;
+    
     // Define RG flows
     let meso_rg = ConcreteRGFlow::new(
         "MesoscopicRG".to_string(),
@@ -259,6 +320,7 @@ fn run_rg_flow_analysis() -> Result<(), Box<dyn Error>> {
         micro_to_meso.clone(),
         mesoscopic::rg_step_mesoscopic,
         mesoscopic::beta_function_mesoscopic,
+        3,
     );
     
     let macro_rg = ConcreteRGFlow::new(
@@ -267,6 +329,7 @@ fn run_rg_flow_analysis() -> Result<(), Box<dyn Error>> {
         meso_to_macro.clone(),
         macroscopic::rg_step_macroscopic,
         macroscopic::beta_function_macroscopic,
+        3,
     );
     
     // Initial mesoscopic parameters
@@ -279,6 +342,7 @@ fn run_rg_flow_analysis() -> Result<(), Box<dyn Error>> {
         h: 0.0,
         temperature: 290.0,
         xi: 1.0,
+        spatial_dimension: 3,
     };
     
     // Run RG flow
@@ -292,6 +356,22 @@ fn run_rg_flow_analysis() -> Result<(), Box<dyn Error>> {
         meso_trajectory.push(current.clone());
     }
     
+    // The same ten steps as a continuous flow; each discrete step rescales lengths by 1.5
+    let step_length = 1.5f64.ln();
+    let continuous = meso_rg.integrate(
+        &meso_params,
+        10.0 * step_length,
+        &IntegrationOptions { sample_interval: Some(step_length), ..IntegrationOptions::default() },
+    )?;
+    if let (Some(discrete_end), Some(continuous_end)) = (meso_trajectory.last(), continuous.points.last()) {
+        info!(
+            "Discrete and continuous mesoscopic flows end {:.3} apart ({:?} after {} steps)",
+            discrete_end.distance(continuous_end),
+            continuous.termination,
+            continuous.steps
+        );
+    }
+    
     // Initial macroscopic parameters
     let macro_params = MacroscopicParameters {
         k1: 1.0,
@@ -300,6 +380,7 @@ fn run_rg_flow_analysis() -> Result<(), Box<dyn Error>> {
         chi_a: 1.0,
         temperature: 290.0,
         core_energy: 5.0,
+        spatial_dimension: 3,
     };
     
     // Run RG flow
@@ -313,6 +394,122 @@ fn run_rg_flow_analysis() -> Result<(), Box<dyn Error>> {
         macro_trajectory.push(current.clone());
     }
     
+    // Frank constants softened by director fluctuations, with 5CB-like constants in
+    // units of k_B T / a at a = 10 nm
+    info!("Running macroscopic RG flow with thermal director fluctuations");
+    let thermal_macro_rg = ConcreteRGFlow::new(
+        "MacroscopicThermalRG".to_string(),
+        macro_cat.clone(),
+        meso_to_macro.clone(),
+        macroscopic::rg_step_frank_fluctuations,
+        macroscopic::beta_function_frank_fluctuations,
+        3,
+    );
+    let bulk = MacroscopicParameters { k1: 15.0, k2: 9.5, k3: 20.0, temperature: 1.0, ..macro_params.clone() };
+    let thermal_options = IntegrationOptions { events: macroscopic::stiffness_loss_events(), ..IntegrationOptions::default() };
+    let softened = thermal_macro_rg.integrate(&bulk, 10.0, &thermal_options)?;
+    if let Some(end) = softened.points.last() {
+        info!(
+            "Frank constant ratios K2/K1, K3/K1 flow from ({:.4}, {:.4}) to ({:.4}, {:.4}) over ℓ = 10 ({:?})",
+            bulk.k2 / bulk.k1, bulk.k3 / bulk.k1, end.k2 / end.k1, end.k3 / end.k1, softened.termination
+        );
+    }
+    
+    // In a film the same fluctuations destroy the stiffness at a finite scale
+    let thermal_film_rg = ConcreteRGFlow::new(
+        "MacroscopicThermalFilmRG".to_string(),
+        macro_cat.clone(),
+        meso_to_macro.clone(),
+        macroscopic::rg_step_frank_fluctuations,
+        macroscopic::beta_function_frank_fluctuations,
+        2,
+    );
+    let softened = thermal_film_rg.integrate(&MacroscopicParameters { spatial_dimension: 2, ..bulk.clone() }, 1000.0, &thermal_options)?;
+    match (softened.termination, softened.scales.last()) {
+        (FlowTermination::Event { index }, Some(scale)) => info!(
+            "Film Frank constant K{} vanishes at ℓ = {:.2}: orientational order is lost beyond e^ℓ core sizes",
+            index + 1, scale
+        ),
+        (termination, _) => warn!("Film Frank constants did not vanish within ℓ = 1000 ({:?})", termination),
+    }
+    
+    // Disclination unbinding in a 2D film with the same Frank constants and core energy
+    info!("Locating the defect-unbinding transition of a 2D film");
+    let film = MacroscopicParameters { spatial_dimension: 2, ..macro_params.clone() };
+    let kt_flow = KosterlitzThoulessFlow::default();
+    let jump = kt_flow.stiffness_jump(
+        |temperature| KTParameters::from_macroscopic(&MacroscopicParameters { temperature, ..film.clone() }),
+        0.01,
+        10.0,
+        &UnbindingOptions::default(),
+    )?;
+    match jump {
+        Some(jump) => info!(
+            "Film unbinds at T = {:.6}: stiffness jumps by {:.4} (universal {:.4}, deviation {:.1e})",
+            jump.temperature, jump.stiffness, jump.universal, jump.deviation()
+        ),
+        None => warn!("No defect-unbinding transition between T = 0.01 and T = 10"),
+    }
+    
+    // Locate every fixed point in a box around the initial couplings
+    info!("Searching for fixed points");
+    let search_options = FixedPointSearchOptions::default();
+    // The elastic constants are marginal, so the box is flat along them; otherwise
+    // every seed would land on its own point of a line of fixed points. The flow
+    // is singular at a = -1, so the box stays above it.
+    let meso_lower = MesoscopicParameters { a: -0.9, b: 0.0, c: 0.0, l1: 1.0, l2: 1.0, h: -1.0, temperature: 280.0, xi: 0.0, spatial_dimension: 3 };
+    let meso_upper = MesoscopicParameters { a: 1.0, b: 3.0, c: 2.0, l1: 1.0, l2: 1.0, h: 1.0, temperature: 340.0, xi: 2.0, spatial_dimension: 3 };
+    let meso_catalogue = meso_rg.find_fixed_points(&meso_lower, &meso_upper, &search_options)?;
+    let macro_catalogue = macro_rg.find_fixed_points(
+        &MacroscopicParameters { k1: 0.0, k2: 0.0, k3: 0.0, chi_a: 0.0, temperature: 280.0, core_energy: 0.0, spatial_dimension: 3 },
+        &MacroscopicParameters { k1: 3.0, k2: 3.0, k3: 3.0, chi_a: 2.0, temperature: 340.0, core_energy: 10.0, spatial_dimension: 3 },
+        &search_options,
+    )?;
+    report_fixed_points("Mesoscopic", &meso_catalogue);
+    report_fixed_points("Macroscopic", &macro_catalogue);
+    
+    // The same mesoscopic flow for a 2D film
+    let film_rg = ConcreteRGFlow::new(
+        "MesoscopicFilmRG".to_string(),
+        meso_cat.clone(),
+        micro_to_meso.clone(),
+        mesoscopic::rg_step_mesoscopic,
+        mesoscopic::beta_function_mesoscopic,
+        2,
+    );
+    let film_catalogue = film_rg.find_fixed_points(
+        &MesoscopicParameters { spatial_dimension: 2, ..meso_lower },
+        &MesoscopicParameters { spatial_dimension: 2, ..meso_upper },
+        &search_options,
+    )?;
+    report_fixed_points("Mesoscopic film", &film_catalogue);
+    
+    // Basins of attraction in the (temperature, xi) plane through the Gaussian fixed point.
+    // Every fixed point is unstable along a, so a plane through a runs away everywhere
+    // off the critical line, and through an interacting fixed point the solver error in
+    // a grows as e^{2ℓ} before temperature and xi have settled. The Gaussian point is
+    // exact, and temperature and xi are irrelevant there.
+    if !meso_catalogue.fixed_points.is_empty() {
+        info!("Mapping mesoscopic phase diagram");
+        let gaussian = MesoscopicParameters { a: 0.0, b: 0.0, c: 0.0, h: 0.0, temperature: 300.0, xi: 0.0, ..meso_params.clone() };
+        let plane = PhasePlane::new(
+            gaussian,
+            PlaneAxis::new(6, 280.0, 320.0, 21),
+            PlaneAxis::new(7, 0.0, 2.0, 21),
+        );
+        let diagram = map_phase_diagram(&meso_rg, &plane, &meso_catalogue.fixed_points, &PhaseDiagramOptions::default())?;
+        info!(
+            "Phase diagram: {} points reach a fixed point, {} run away, {} unresolved",
+            (0..meso_catalogue.fixed_points.len()).map(|k| diagram.count(Basin::FixedPoint(k))).sum::<usize>(),
+            diagram.count(Basin::Runaway),
+            diagram.count(Basin::Unresolved)
+        );
+        save_to_json(
+            &generate_phase_diagram_data(&diagram, "temperature".to_string(), "xi".to_string()),
+            "output/meso_phase_diagram.json",
+        )?;
+    }
+    
     // Generate visualization data
     let meso_param_names = vec![
         "a".to_string(), "b".to_string(), "c".to_string(),
@@ -328,18 +525,13 @@ fn run_rg_flow_analysis() -> Result<(), Box<dyn Error>> {
     let meso_rg_data = generate_rg_flow_data(
         meso_param_names,
         vec![meso_trajectory],
-        vec![
-            (vec![0.0, 2.0, 1.0, 2.0, 2.0, 0.0, 300.0, 0.0], "stable".to_string()),
-            (vec![0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 330.0, 0.0], "unstable".to_string()),
-        ]
+        fixed_point_markers(&meso_catalogue)
     );
     
     let macro_rg_data = generate_rg_flow_data(
         macro_param_names,
         vec![macro_trajectory],
-        vec![
-            (vec![2.0, 2.0, 2.0, 0.0, 300.0, 10.0], "stable".to_string())
-        ]
+        fixed_point_markers(&macro_catalogue)
     );
     
     // Save visualizations
@@ -347,14 +539,34 @@ fn run_rg_flow_analysis() -> Result<(), Box<dyn Error>> {
     save_to_json(&meso_rg_data, "output/meso_rg_flow.json")?;
     save_to_json(&macro_rg_data, "output/macro_rg_flow.json")?;
     
-    // Try to find fixed points
-    info!("Searching for fixed points");
-    let meso_fixed = meso_rg.find_fixed_point(&meso_params, 50, 1e-3)?;
-    println!("Mesoscopic fixed point: {:?}", meso_fixed);
-    
     Ok(())
 }
 
+/// Print each fixed point in a catalogue with its linearized stability
+fn report_fixed_points<P: ParameterSpace>(scale: &str, catalogue: &FixedPointCatalogue<P>) {
+    info!(
+        "{} search: {} seeds, {} failed, {} left the search box",
+        scale, catalogue.seeds, catalogue.failures, catalogue.outside
+    );
+    for (fixed, hits) in catalogue.fixed_points.iter().zip(&catalogue.hits) {
+        println!("{} fixed point ({}, {} seeds): {:?}", scale, fixed.classification, hits, fixed.parameters);
+        println!("  scaling exponents: {:?}", fixed.critical_exponents);
+        if !fixed.frozen_couplings.is_empty() {
+            println!("  frozen couplings: {:?}", fixed.frozen_couplings);
+        }
+        if let Some(nu) = fixed.correlation_length_exponent {
+            println!("  correlation length exponent: {}", nu);
+        }
+    }
+}
+
+/// Fixed points in the form expected by `generate_rg_flow_data`
+fn fixed_point_markers<P: ParameterSpace>(catalogue: &FixedPointCatalogue<P>) -> Vec<(Vec<f64>, String)> {
+    catalogue.fixed_points.iter()
+        .map(|fixed| (fixed.parameters.as_vector().iter().cloned().collect(), fixed.classification.clone()))
+        .collect()
+}
+
 fn run_curved_surface_simulation(surface_type: &str) -> Result<(), Box<dyn Error>> {
     // Create output directory
     fs::create_dir_all("output")?;
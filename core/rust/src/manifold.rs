@@ -114,6 +114,18 @@ pub struct CurvedSpacePoint {
     intrinsic_coordinates: DVector<f64>,
 }
 
+impl CurvedSpacePoint {
+    /// The curved space this point lies on
+    pub fn space_type(&self) -> &CurvedSpace {
+        &self.space_type
+    }
+    
+    /// Intrinsic coordinates on the manifold
+    pub fn intrinsic_coordinates(&self) -> &DVector<f64> {
+        &self.intrinsic_coordinates
+    }
+}
+
 impl ManifoldPoint for CurvedSpacePoint {
     fn coordinates(&self) -> DVector<f64> {
         self.coordinates.clone()
@@ -228,7 +240,7 @@ impl Manifold for CurvedSpace {
                 
                 // Choose an arbitrary vector not collinear with radial
                 let mut v = Vector3::new(1.0, 0.0, 0.0);
-                if radial.dot(&v.into()) > 0.9 {
+                if radial.dot(&v) > 0.9 {
                     v = Vector3::new(0.0, 1.0, 0.0);
                 }
                 
@@ -256,9 +268,6 @@ impl Manifold for CurvedSpace {
             CurvedSpace::HyperbolicSpace { .. } => {
                 // For hyperbolic space in the Poincaré model
                 // The tangent space can be calculated based on the metric
-                let coords = point.coordinates();
-                let r2 = coords.norm_squared();
-                
                 // Create an orthogonal basis (simplified)
                 let mut basis_vectors = Vec::new();
                 for i in 0..2 {
@@ -314,7 +323,7 @@ impl Manifold for CurvedSpace {
         ))
     }
     
-    fn christoffel_symbols(&self, point: &Self::Point) -> Result<Vec<DMatrix<f64>>, ManifoldError> {
+    fn christoffel_symbols(&self, _point: &Self::Point) -> Result<Vec<DMatrix<f64>>, ManifoldError> {
         // This would compute Christoffel symbols based on the metric tensor
         // Simplified placeholder
         match self {
@@ -332,7 +341,7 @@ impl Manifold for CurvedSpace {
         }
     }
     
-    fn riemann_tensor(&self, point: &Self::Point) -> Result<Vec<DMatrix<f64>>, ManifoldError> {
+    fn riemann_tensor(&self, _point: &Self::Point) -> Result<Vec<DMatrix<f64>>, ManifoldError> {
         // This would compute the Riemann curvature tensor
         // Simplified placeholder
         Err(ManifoldError::ComputationFailed(
@@ -1,10 +1,13 @@
 use crate::category::{Category, CategoryError, FinCategory, Morphism, Object};
-use crate::functor::{ConcreteFunctor, Functor};
-use crate::rg_flow::{ParameterSpace, RGFlowError};
-use crate::microscopic::{MicroscopicConfiguration, MicroscopicMorphism, MicroscopicParameters, QTensor};
-use nalgebra::{DMatrix, DVector};
+use crate::adjunction::Adjunction;
+use crate::functor::{ComposedFunctor, ConcreteFunctor, ConcreteNaturalTransformation, Functor, IdentityFunctor};
+use crate::hashing::{self, ContentHasher};
+use crate::metric::{self, MetricMorphism, MetricObject};
+use crate::rg_flow::{self, ParameterSpace, RGFlowError};
+use crate::microscopic::{self, MicroscopicConfiguration, MicroscopicMorphism, MicroscopicParameters, QTensor};
+use crate::transformation::{self, ConfigurationMorphism, LatticeDimensions, LatticeRotation, Transformation};
+use nalgebra::{DMatrix, DVector, Vector3};
 use std::collections::HashMap;
-use std::fmt::Debug;
 use thiserror::Error;
 
 /// Error types related to mesoscopic models
@@ -87,6 +90,17 @@ impl QTensorField {
         Ok([grad_x, grad_y, grad_z])
     }
     
+    /// L2 distance to another field on the same grid, integrating over cell volumes
+    pub fn l2_distance(&self, other: &QTensorField) -> Option<f64> {
+        if self.resolution != other.resolution || self.spacing != other.spacing
+            || self.values.len() != other.values.len() {
+            return None;
+        }
+        
+        let (dx, dy, dz) = self.spacing;
+        Some(metric::tensor_l2_distance(&self.values, &other.values, dx * dy * dz))
+    }
+    
     /// Get the Laplacian of the Q-tensor field at a specific grid point
     pub fn laplacian(&self, i: usize, j: usize, k: usize) -> Result<DMatrix<f64>, MesoscopicError> {
         let (nx, ny, nz) = self.resolution;
@@ -132,10 +146,143 @@ pub struct MesoscopicConfiguration {
     
     /// Boundary conditions
     pub boundary_conditions: Option<HashMap<String, String>>,
+    
+    /// Dimensions of the lattice this field was coarse-grained from, if any
+    pub lattice: Option<LatticeDimensions>,
+    
+    /// Floats closer than this are considered equal in `Object::id`
+    pub id_tolerance: f64,
+}
+
+impl MesoscopicConfiguration {
+    /// Create a configuration with the default ID tolerance
+    pub fn new(
+        field: QTensorField,
+        temperature: f64,
+        external_field: Option<DVector<f64>>,
+        boundary_conditions: Option<HashMap<String, String>>,
+    ) -> Self {
+        Self {
+            field,
+            temperature,
+            external_field,
+            boundary_conditions,
+            lattice: None,
+            id_tolerance: hashing::DEFAULT_ID_TOLERANCE,
+        }
+    }
+    
+    /// The same configuration, remembering that it was coarse-grained from a lattice of `dimensions`
+    pub fn with_lattice(mut self, dimensions: LatticeDimensions) -> Self {
+        self.lattice = Some(dimensions);
+        self
+    }
+    
+    /// The same configuration with floats compared up to `tolerance` in `Object::id`.
+    ///
+    /// Only configurations sharing a tolerance have comparable IDs.
+    pub fn with_id_tolerance(mut self, tolerance: f64) -> Self {
+        self.id_tolerance = tolerance;
+        self
+    }
+    
+    /// Identifier derived from the configuration data, with floats compared up to `tolerance`
+    pub fn content_id(&self, tolerance: f64) -> String {
+        let mut hasher = ContentHasher::new(tolerance);
+        let (nx, ny, nz) = self.field.resolution;
+        let (dx, dy, dz) = self.field.spacing;
+        
+        for n in [nx, ny, nz] {
+            hasher.write_usize(n);
+        }
+        hasher.write_f64s(&[dx, dy, dz]);
+        hasher.write_usize(self.field.values.len());
+        for q in &self.field.values {
+            hasher.write_f64s(q.components.iter());
+        }
+        hasher.write_f64(self.temperature);
+        hasher.write_option(self.external_field.as_ref(), |h, field| {
+            h.write_usize(field.len());
+            h.write_f64s(field.iter());
+        });
+        hasher.write_option(self.boundary_conditions.as_ref(), |h, conditions| {
+            // Map iteration order is not stable, so hash the entries sorted
+            let mut entries: Vec<_> = conditions.iter().collect();
+            entries.sort();
+            h.write_usize(entries.len());
+            for (key, value) in entries {
+                h.write_str(key);
+                h.write_str(value);
+            }
+        });
+        hasher.write_option(self.lattice.as_ref(), |h, &(nx, ny, nz)| {
+            for n in [nx, ny, nz] {
+                h.write_usize(n);
+            }
+        });
+        
+        format!("MesoConfig_{:016x}", hasher.finish())
+    }
+    
+    /// Apply a transformation, giving the transformed configuration
+    pub fn transform(&self, transformation: &Transformation) -> Result<Self, CategoryError> {
+        let mut result = self.clone();
+        
+        match transformation {
+            Transformation::Identity => {}
+            Transformation::Rotation { axis, angle } => {
+                let rotation = transformation::rotation_matrix(axis, *angle)?;
+                let lattice_rotation = LatticeRotation::from_matrix(&rotation)?;
+                let (values, resolution) = lattice_rotation.apply(&self.field.values, self.field.resolution, &rotation)?;
+                let (dx, dy, dz) = self.field.spacing;
+                let spacing = lattice_rotation.axes.map(|(old, _)| [dx, dy, dz][old]);
+                result.field = QTensorField {
+                    resolution,
+                    values,
+                    spacing: (spacing[0], spacing[1], spacing[2]),
+                };
+                result.lattice = self.lattice.map(|lattice| lattice_rotation.dimensions(lattice));
+            }
+            Transformation::Translation { offset } => {
+                let shift = transformation::lattice_shift(offset, self.field.spacing)?;
+                result.field.values = transformation::shift_lattice(&self.field.values, self.field.resolution, shift);
+            }
+            Transformation::TemperatureChange { delta } => {
+                result.temperature += delta;
+            }
+            Transformation::FieldApplication { field } => {
+                result.external_field = Some(DVector::from_iterator(3, field.iter().cloned()));
+            }
+            Transformation::DefectMotion { .. } => {
+                return Err(transformation.unsupported("a mesoscopic configuration"));
+            }
+            Transformation::Reconstruction { residual } => {
+                result.field.values = transformation::add_residual(&self.field.values, residual)?;
+            }
+            Transformation::CoarseGraining { factor } => {
+                let (values, resolution) = transformation::block_average(&self.field.values, self.field.resolution, *factor)?;
+                let (dx, dy, dz) = self.field.spacing;
+                let scale = *factor as f64;
+                result.field = QTensorField {
+                    resolution,
+                    values,
+                    spacing: (dx * scale, dy * scale, dz * scale),
+                };
+                // The coarser cells no longer match blocks of the original lattice
+                result.lattice = None;
+            }
+        }
+        
+        Ok(result)
+    }
 }
 
 impl Object for MesoscopicConfiguration {
     fn id(&self) -> String {
+        self.content_id(self.id_tolerance)
+    }
+    
+    fn label(&self) -> String {
         let (nx, ny, nz) = self.field.resolution;
         format!("MesoConfig_{}x{}x{}_T{:.2}", nx, ny, nz, self.temperature)
     }
@@ -146,6 +293,23 @@ impl Object for MesoscopicConfiguration {
     }
 }
 
+impl MetricObject for MesoscopicConfiguration {
+    /// L2 distance between the Q-tensor fields plus the differences in
+    /// temperature and external field, infinite for different grids
+    fn distance(&self, other: &Self) -> f64 {
+        let Some(field) = self.field.l2_distance(&other.field) else {
+            return f64::INFINITY;
+        };
+        
+        field
+            + (self.temperature - other.temperature).abs()
+            + metric::field_distance(
+                self.external_field.as_ref().map(|h| h.as_slice()),
+                other.external_field.as_ref().map(|h| h.as_slice()),
+            )
+    }
+}
+
 /// Parameters for the mesoscopic Landau-de Gennes model
 #[derive(Clone, Debug)]
 pub struct MesoscopicParameters {
@@ -166,6 +330,9 @@ pub struct MesoscopicParameters {
     
     /// Correlation length
     pub xi: f64,
+    
+    /// Spatial dimension d of the system
+    pub spatial_dimension: usize,
 }
 
 impl ParameterSpace for MesoscopicParameters {
@@ -173,6 +340,10 @@ impl ParameterSpace for MesoscopicParameters {
         8 // a, b, c, l1, l2, h, temperature, xi
     }
     
+    fn spatial_dimension(&self) -> usize {
+        self.spatial_dimension
+    }
+    
     fn as_vector(&self) -> DVector<f64> {
         DVector::from_vec(vec![
             self.a, self.b, self.c, 
@@ -181,7 +352,7 @@ impl ParameterSpace for MesoscopicParameters {
         ])
     }
     
-    fn from_vector(vec: DVector<f64>) -> Result<Self, RGFlowError> {
+    fn from_vector(vec: DVector<f64>, dim: usize) -> Result<Self, RGFlowError> {
         if vec.len() != 8 {
             return Err(RGFlowError::ParameterOutOfRange(
                 format!("Expected 8 parameters, got {}", vec.len())
@@ -197,6 +368,7 @@ impl ParameterSpace for MesoscopicParameters {
             h: vec[5],
             temperature: vec[6],
             xi: vec[7],
+            spatial_dimension: dim,
         })
     }
     
@@ -214,11 +386,8 @@ pub struct MesoscopicMorphism {
     /// Codomain of this morphism
     pub codomain: MesoscopicConfiguration,
     
-    /// Type of transformation
-    pub transformation_type: String,
-    
-    /// Parameters for the transformation
-    pub parameters: Option<DVector<f64>>,
+    /// The transformation this morphism performs
+    pub transformation: Transformation,
 }
 
 impl Morphism for MesoscopicMorphism {
@@ -232,11 +401,36 @@ impl Morphism for MesoscopicMorphism {
         &self.codomain
     }
     
-    fn apply<T>(&self, data: &T) -> Result<T, CategoryError> 
-    where T: Clone + Debug {
-        // In a real implementation, this would transform the data
-        // For now, just return a clone
-        Ok(data.clone())
+    fn id(&self) -> String {
+        format!("{}:{}->{}", self.transformation, self.domain.id(), self.codomain.id())
+    }
+    
+    fn is_identity(&self) -> bool {
+        self.transformation == Transformation::Identity && self.domain.id() == self.codomain.id()
+    }
+    
+    fn label(&self) -> String {
+        self.transformation.name().to_string()
+    }
+    
+    fn apply(&self, obj: &Self::ObjectType) -> Result<Self::ObjectType, CategoryError> {
+        obj.transform(&self.transformation)
+    }
+}
+
+impl ConfigurationMorphism for MesoscopicMorphism {
+    fn with_transformation(domain: Self::ObjectType, codomain: Self::ObjectType, transformation: Transformation) -> Self {
+        Self { domain, codomain, transformation }
+    }
+    
+    fn q_tensors(obj: &Self::ObjectType) -> &[QTensor] {
+        &obj.field.values
+    }
+}
+
+impl MetricMorphism for MesoscopicMorphism {
+    fn length(&self) -> f64 {
+        self.domain.distance(&self.codomain)
     }
 }
 
@@ -247,41 +441,28 @@ pub fn create_mesoscopic_category() ->
     let field1 = QTensorField::new((10, 10, 10), (1.0, 1.0, 1.0));
     let field2 = QTensorField::new((10, 10, 10), (1.0, 1.0, 1.0));
     
-    let config1 = MesoscopicConfiguration {
-        field: field1,
-        temperature: 300.0,
-        external_field: None,
-        boundary_conditions: None,
-    };
+    let config1 = MesoscopicConfiguration::new(field1, 300.0, None, None);
     
-    let config2 = MesoscopicConfiguration {
-        field: field2,
-        temperature: 310.0,
-        external_field: None,
-        boundary_conditions: None,
-    };
+    let config2 = MesoscopicConfiguration::new(field2, 310.0, None, None);
     
     // Create a morphism between them
     let morphism = MesoscopicMorphism {
         domain: config1.clone(),
         codomain: config2.clone(),
-        transformation_type: "TemperatureIncrease".to_string(),
-        parameters: None,
+        transformation: Transformation::TemperatureChange { delta: 10.0 },
     };
     
     // Create identity morphisms
     let id1 = MesoscopicMorphism {
         domain: config1.clone(),
         codomain: config1.clone(),
-        transformation_type: "Identity".to_string(),
-        parameters: None,
+        transformation: Transformation::Identity,
     };
     
     let id2 = MesoscopicMorphism {
         domain: config2.clone(),
         codomain: config2.clone(),
-        transformation_type: "Identity".to_string(),
-        parameters: None,
+        transformation: Transformation::Identity,
     };
     
     // Create the category
@@ -292,79 +473,167 @@ pub fn create_mesoscopic_category() ->
     )
 }
 
-/// Functor that maps from microscopic to mesoscopic category
-pub fn create_micro_to_meso_functor(
-    micro_cat: FinCategory<MicroscopicConfiguration, MicroscopicMorphism>,
-    meso_cat: FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
-) -> ConcreteFunctor<
-    FinCategory<MicroscopicConfiguration, MicroscopicMorphism>,
-    FinCategory<MesoscopicConfiguration, MesoscopicMorphism>
-> {
-    // Define object mapping function: MicroscopicConfiguration -> MesoscopicConfiguration
-    let object_mapping = |micro_obj: &MicroscopicConfiguration| -> MesoscopicConfiguration {
-        let (nx, ny, nz) = micro_obj.dimensions;
-        let resolution = (nx/2, ny/2, nz/2); // Coarse-graining by factor of 2
-        let spacing = (2.0, 2.0, 2.0); // Double the spacing
+/// Weighting of lattice sites within a coarse-graining block
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoarseGrainingKernel {
+    /// Equal weight for every site in the block
+    Box,
+    
+    /// Gaussian weight of width `sigma` lattice sites about the block centre
+    Gaussian { sigma: f64 },
+}
+
+impl CoarseGrainingKernel {
+    /// Weight of a site at `offset` lattice sites from the block centre
+    fn weight(&self, offset: [f64; 3]) -> f64 {
+        match self {
+            CoarseGrainingKernel::Box => 1.0,
+            CoarseGrainingKernel::Gaussian { sigma } => {
+                let r2: f64 = offset.iter().map(|x| x * x).sum();
+                (-r2 / (2.0 * sigma * sigma)).exp()
+            }
+        }
+    }
+}
+
+/// Parameters of the coarse-graining from lattice configurations to Q-tensor fields
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoarseGrainingOptions {
+    /// Lattice sites per mesoscopic cell along each axis
+    pub block_size: usize,
+    
+    /// Weighting of sites within a block
+    pub kernel: CoarseGrainingKernel,
+}
+
+impl Default for CoarseGrainingOptions {
+    fn default() -> Self {
+        Self {
+            block_size: 2,
+            kernel: CoarseGrainingKernel::Box,
+        }
+    }
+}
+
+impl CoarseGrainingOptions {
+    /// Average a lattice configuration over blocks of sites.
+    ///
+    /// Blocks start at the first site along each axis, and a last block that
+    /// overhangs the lattice averages the sites it covers, so that every site
+    /// contributes. The cell spacing of the resulting field is the block size in
+    /// lattice units, and the field remembers the lattice for refinement.
+    pub fn coarse_grain(&self, micro_obj: &MicroscopicConfiguration) -> MesoscopicConfiguration {
+        MesoscopicConfiguration::new(
+            self.average_blocks(&micro_obj.q_tensors, micro_obj.dimensions),
+            micro_obj.temperature,
+            micro_obj.external_field.map(|v| DVector::from_iterator(3, v.iter().cloned())),
+            None,
+        )
+        .with_lattice(micro_obj.dimensions)
+        .with_id_tolerance(micro_obj.id_tolerance)
+    }
+    
+    /// Average Q-tensors on a lattice of `dimensions` over blocks of sites.
+    ///
+    /// The average is linear, so it also carries residuals between configurations.
+    fn average_blocks(&self, values: &[QTensor], dimensions: LatticeDimensions) -> QTensorField {
+        let block = self.block_size.max(1);
+        let (nx, ny, nz) = dimensions;
+        let resolution = (nx.div_ceil(block), ny.div_ceil(block), nz.div_ceil(block));
+        let spacing = (block as f64, block as f64, block as f64);
         
-        // Create a new Q-tensor field at reduced resolution
-        let mut field = QTensorField::new(resolution, spacing);
+        // Kernel weights depend only on the position within the block
+        let centre = (block as f64 - 1.0) / 2.0;
+        let mut weights = Vec::with_capacity(block * block * block);
+        for di in 0..block {
+            for dj in 0..block {
+                for dk in 0..block {
+                    let offset = [di as f64 - centre, dj as f64 - centre, dk as f64 - centre];
+                    weights.push(((di, dj, dk), self.kernel.weight(offset)));
+                }
+            }
+        }
         
-        // Perform block averaging to coarse-grain the microscopic configuration
+        let mut field = QTensorField::new(resolution, spacing);
         for i in 0..resolution.0 {
             for j in 0..resolution.1 {
                 for k in 0..resolution.2 {
-                    // Average Q-tensors in a 2x2x2 block
                     let mut avg_q = DMatrix::zeros(3, 3);
-                    let mut count = 0;
+                    let mut total_weight = 0.0;
                     
-                    for di in 0..2 {
-                        for dj in 0..2 {
-                            for dk in 0..2 {
-                                let micro_i = 2*i + di;
-                                let micro_j = 2*j + dj;
-                                let micro_k = 2*k + dk;
-                                
-                                if micro_i < nx && micro_j < ny && micro_k < nz {
-                                    let idx = micro_i*ny*nz + micro_j*nz + micro_k;
-                                    if idx < micro_obj.q_tensors.len() {
-                                        avg_q += &micro_obj.q_tensors[idx].components;
-                                        count += 1;
-                                    }
-                                }
-                            }
+                    for &((di, dj, dk), weight) in &weights {
+                        let (x, y, z) = (block * i + di, block * j + dj, block * k + dk);
+                        if x >= nx || y >= ny || z >= nz {
+                            continue;
+                        }
+                        if let Some(q) = values.get(x * ny * nz + y * nz + z) {
+                            avg_q += &q.components * weight;
+                            total_weight += weight;
                         }
                     }
                     
-                    if count > 0 {
-                        avg_q /= count as f64;
+                    if total_weight > 0.0 {
+                        avg_q /= total_weight;
                         let _ = field.set(i, j, k, QTensor::new(avg_q));
                     }
                 }
             }
         }
         
-        MesoscopicConfiguration {
-            field,
-            temperature: micro_obj.temperature,
-            external_field: micro_obj.external_field.clone().map(|v| DVector::from_iterator(3, v.iter().cloned())),
-            boundary_conditions: None,
-        }
-    };
+        field
+    }
     
-    // Define morphism mapping function: MicroscopicMorphism -> MesoscopicMorphism
-    let morphism_mapping = |micro_morph: &MicroscopicMorphism| -> Result<MesoscopicMorphism, CategoryError> {
-        // Map the domain and codomain objects
-        let meso_domain = object_mapping(micro_morph.domain());
-        let meso_codomain = object_mapping(micro_morph.codomain());
-        
-        // Create the corresponding mesoscopic morphism
-        Ok(MesoscopicMorphism {
-            domain: meso_domain,
-            codomain: meso_codomain,
-            transformation_type: micro_morph.transformation_type.clone(),
-            parameters: micro_morph.parameters.clone(),
+    /// The morphism between the coarse-grained endpoints of `micro_morph`, carrying its transformation
+    pub fn coarse_grain_morphism(&self, micro_morph: &MicroscopicMorphism) -> Result<MesoscopicMorphism, CategoryError> {
+        let meso_domain = self.coarse_grain(micro_morph.domain());
+        let meso_codomain = self.coarse_grain(micro_morph.codomain());
+        let dimensions = micro_morph.domain().dimensions;
+        MesoscopicMorphism::map_transformation(&micro_morph.transformation, meso_domain, meso_codomain, |residual| {
+            Ok(residual_components(&self.average_blocks(&residual_tensors(residual), dimensions).values))
         })
-    };
+    }
+    
+    /// The category of the coarse-grainings of each object and morphism of a microscopic category
+    pub fn coarse_grain_category(
+        &self,
+        micro_cat: &FinCategory<MicroscopicConfiguration, MicroscopicMorphism>,
+        name: &str,
+    ) -> Result<FinCategory<MesoscopicConfiguration, MesoscopicMorphism>, CategoryError> {
+        let objects = micro_cat.objects().iter().map(|obj| self.coarse_grain(obj)).collect();
+        let morphisms = micro_cat.morphisms().iter()
+            .map(|morph| self.coarse_grain_morphism(morph))
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(FinCategory::new(name.to_string(), objects, morphisms))
+    }
+}
+
+/// Functor that maps from microscopic to mesoscopic category by averaging over 2x2x2 blocks
+pub fn create_micro_to_meso_functor(
+    micro_cat: FinCategory<MicroscopicConfiguration, MicroscopicMorphism>,
+    meso_cat: FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+) -> ConcreteFunctor<
+    FinCategory<MicroscopicConfiguration, MicroscopicMorphism>,
+    FinCategory<MesoscopicConfiguration, MesoscopicMorphism>
+> {
+    create_micro_to_meso_functor_with(micro_cat, meso_cat, CoarseGrainingOptions::default())
+}
+
+/// Functor that maps from microscopic to mesoscopic category with the given coarse-graining
+pub fn create_micro_to_meso_functor_with(
+    micro_cat: FinCategory<MicroscopicConfiguration, MicroscopicMorphism>,
+    meso_cat: FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    options: CoarseGrainingOptions,
+) -> ConcreteFunctor<
+    FinCategory<MicroscopicConfiguration, MicroscopicMorphism>,
+    FinCategory<MesoscopicConfiguration, MesoscopicMorphism>
+> {
+    // Define object mapping function: MicroscopicConfiguration -> MesoscopicConfiguration
+    let object_options = options.clone();
+    let object_mapping = move |micro_obj: &MicroscopicConfiguration| object_options.coarse_grain(micro_obj);
+    
+    // Define morphism mapping function: MicroscopicMorphism -> MesoscopicMorphism
+    let morphism_mapping = move |micro_morph: &MicroscopicMorphism| options.coarse_grain_morphism(micro_morph);
     
     ConcreteFunctor::new(
         "MicroToMeso".to_string(),
@@ -375,6 +644,261 @@ pub fn create_micro_to_meso_functor(
     )
 }
 
+/// Interpolation used when refining a Q-tensor field onto a finer lattice
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Interpolation {
+    /// Every site takes the value of the cell containing it
+    Constant,
+    
+    /// Trilinear interpolation between neighbouring cell centres
+    Trilinear,
+}
+
+/// Parameters of the refinement from Q-tensor fields to lattice configurations
+#[derive(Clone, Debug, PartialEq)]
+pub struct RefinementOptions {
+    /// Lattice sites per mesoscopic cell along each axis
+    pub factor: usize,
+    
+    /// How values between cell centres are filled in
+    pub interpolation: Interpolation,
+}
+
+impl Default for RefinementOptions {
+    fn default() -> Self {
+        Self {
+            factor: 2,
+            interpolation: Interpolation::Constant,
+        }
+    }
+}
+
+impl RefinementOptions {
+    /// Interpolate a Q-tensor field onto a lattice with `factor` sites per cell along each axis.
+    ///
+    /// A field coarse-grained from a lattice is refined back onto that lattice,
+    /// cut to its size where that is smaller, so the round trip keeps the lattice
+    /// dimensions. With constant interpolation, box coarse-graining by the same
+    /// factor recovers the original field exactly.
+    pub fn refine(&self, meso_obj: &MesoscopicConfiguration) -> MicroscopicConfiguration {
+        let dimensions = self.lattice_dimensions(meso_obj);
+        MicroscopicConfiguration::new(
+            dimensions,
+            self.sample(&meso_obj.field, dimensions),
+            meso_obj.temperature,
+            meso_obj.external_field.as_ref()
+                .filter(|field| field.len() == 3)
+                .map(|field| Vector3::new(field[0], field[1], field[2])),
+        )
+        .with_id_tolerance(meso_obj.id_tolerance)
+    }
+    
+    /// The morphism between the refined endpoints of `meso_morph`, carrying its transformation
+    pub fn refine_morphism(&self, meso_morph: &MesoscopicMorphism) -> Result<MicroscopicMorphism, CategoryError> {
+        let micro_domain = self.refine(meso_morph.domain());
+        let micro_codomain = self.refine(meso_morph.codomain());
+        let (resolution, spacing) = (meso_morph.domain().field.resolution, meso_morph.domain().field.spacing);
+        let dimensions = micro_domain.dimensions;
+        MicroscopicMorphism::map_transformation(&meso_morph.transformation, micro_domain, micro_codomain, |residual| {
+            let cells = QTensorField { resolution, spacing, values: residual_tensors(residual) };
+            Ok(residual_components(&self.sample(&cells, dimensions)))
+        })
+    }
+    
+    /// Dimensions of the lattice a field is refined onto
+    fn lattice_dimensions(&self, meso_obj: &MesoscopicConfiguration) -> LatticeDimensions {
+        let factor = self.factor.max(1);
+        let (nx, ny, nz) = meso_obj.field.resolution;
+        let full = (nx * factor, ny * factor, nz * factor);
+        match meso_obj.lattice {
+            Some((lx, ly, lz)) => (full.0.min(lx), full.1.min(ly), full.2.min(lz)),
+            None => full,
+        }
+    }
+    
+    /// Interpolate a field at the sites of a lattice of `dimensions`.
+    ///
+    /// The interpolation is linear, so it also carries residuals between configurations.
+    fn sample(&self, field: &QTensorField, dimensions: LatticeDimensions) -> Vec<QTensor> {
+        let factor = self.factor.max(1);
+        let mut q_tensors = Vec::with_capacity(dimensions.0 * dimensions.1 * dimensions.2);
+        for i in 0..dimensions.0 {
+            for j in 0..dimensions.1 {
+                for k in 0..dimensions.2 {
+                    let components = match self.interpolation {
+                        Interpolation::Constant => field.get(i / factor, j / factor, k / factor)
+                            .map(|q| q.components.clone())
+                            .unwrap_or_else(|| DMatrix::zeros(3, 3)),
+                        Interpolation::Trilinear => interpolate_trilinear(field, [i, j, k], factor),
+                    };
+                    q_tensors.push(QTensor::new(components));
+                }
+            }
+        }
+        q_tensors
+    }
+}
+
+/// A residual as Q-tensors, to be carried along by the linear coarse-graining and refinement
+fn residual_tensors(residual: &[DMatrix<f64>]) -> Vec<QTensor> {
+    residual.iter().map(|r| QTensor::new(r.clone())).collect()
+}
+
+/// The residual held by Q-tensors built with [`residual_tensors`]
+fn residual_components(values: &[QTensor]) -> Vec<DMatrix<f64>> {
+    values.iter().map(|q| q.components.clone()).collect()
+}
+
+/// Trilinear interpolation of a field at the centre of a lattice site with `factor` sites per cell
+fn interpolate_trilinear(field: &QTensorField, site: [usize; 3], factor: usize) -> DMatrix<f64> {
+    let resolution = [field.resolution.0, field.resolution.1, field.resolution.2];
+    let mut lower = [0; 3];
+    let mut upper = [0; 3];
+    let mut fraction = [0.0; 3];
+    
+    for axis in 0..3 {
+        // Position of the site centre in cells, measured from the first cell centre
+        let last = resolution[axis].saturating_sub(1);
+        let x = ((site[axis] as f64 + 0.5) / factor as f64 - 0.5).clamp(0.0, last as f64);
+        lower[axis] = x.floor() as usize;
+        upper[axis] = (lower[axis] + 1).min(last);
+        fraction[axis] = x - lower[axis] as f64;
+    }
+    
+    let mut result = DMatrix::zeros(3, 3);
+    for corner in 0..8 {
+        let mut weight = 1.0;
+        let mut cell = [0; 3];
+        for axis in 0..3 {
+            if (corner >> axis) & 1 == 1 {
+                cell[axis] = upper[axis];
+                weight *= fraction[axis];
+            } else {
+                cell[axis] = lower[axis];
+                weight *= 1.0 - fraction[axis];
+            }
+        }
+        if let Some(q) = field.get(cell[0], cell[1], cell[2]) {
+            result += &q.components * weight;
+        }
+    }
+    
+    result
+}
+
+/// Functor that maps from mesoscopic to microscopic category by refining onto a lattice twice as fine
+pub fn create_meso_to_micro_functor(
+    meso_cat: FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    micro_cat: FinCategory<MicroscopicConfiguration, MicroscopicMorphism>,
+) -> ConcreteFunctor<
+    FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    FinCategory<MicroscopicConfiguration, MicroscopicMorphism>
+> {
+    create_meso_to_micro_functor_with(meso_cat, micro_cat, RefinementOptions::default())
+}
+
+/// Functor that maps from mesoscopic to microscopic category with the given refinement
+pub fn create_meso_to_micro_functor_with(
+    meso_cat: FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    micro_cat: FinCategory<MicroscopicConfiguration, MicroscopicMorphism>,
+    options: RefinementOptions,
+) -> ConcreteFunctor<
+    FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    FinCategory<MicroscopicConfiguration, MicroscopicMorphism>
+> {
+    // Define object mapping function: MesoscopicConfiguration -> MicroscopicConfiguration
+    let object_options = options.clone();
+    let object_mapping = move |meso_obj: &MesoscopicConfiguration| object_options.refine(meso_obj);
+    
+    // Define morphism mapping function: MesoscopicMorphism -> MicroscopicMorphism
+    let morphism_mapping = move |meso_morph: &MesoscopicMorphism| options.refine_morphism(meso_morph);
+    
+    ConcreteFunctor::new(
+        "MesoToMicro".to_string(),
+        meso_cat,
+        micro_cat,
+        object_mapping,
+        morphism_mapping,
+    )
+}
+
+type MicroCategory = FinCategory<MicroscopicConfiguration, MicroscopicMorphism>;
+type MesoCategory = FinCategory<MesoscopicConfiguration, MesoscopicMorphism>;
+type RefinementFunctor = ConcreteFunctor<MesoCategory, MicroCategory>;
+type CoarseGrainingFunctor = ConcreteFunctor<MicroCategory, MesoCategory>;
+
+/// Refinement left adjoint to coarse-graining, with unit and counit made of reconstructions
+pub type RefinementAdjunction = Adjunction<
+    RefinementFunctor,
+    CoarseGrainingFunctor,
+    ConcreteNaturalTransformation<
+        MesoCategory,
+        MesoCategory,
+        IdentityFunctor<MesoCategory>,
+        ComposedFunctor<RefinementFunctor, CoarseGrainingFunctor>,
+    >,
+    ConcreteNaturalTransformation<
+        MicroCategory,
+        MicroCategory,
+        ComposedFunctor<CoarseGrainingFunctor, RefinementFunctor>,
+        IdentityFunctor<MicroCategory>,
+    >,
+>;
+
+/// Adjunction between refinement and coarse-graining.
+///
+/// The unit takes a field to the coarse-graining of its refinement, and the
+/// counit restores the detail a lattice configuration loses when coarse-grained
+/// and refined again, so `information_loss` measures the detail lost by
+/// coarse-graining. Refinement returns to the lattice a field was coarse-grained
+/// from, so a component is only undefined where the round trip changes the cell
+/// spacing.
+///
+/// The functors carry a component to the other scale by coarse-graining or
+/// refining its residual, rather than by refitting it between the images, so
+/// the triangle identities compare the components with what the actions do to
+/// them. With a box kernel and constant interpolation at the block size,
+/// coarse-graining undoes refinement and the unit is the identity.
+///
+/// `Adjunction::verify` only holds on categories closed under the round trip.
+/// A lattice configuration that loses detail has a counit component starting
+/// at its refined coarse-graining, which is then not an object of the
+/// microscopic category, so naturality and the triangle identities fail there
+/// even though `information_loss` is still defined.
+pub fn create_refinement_adjunction(
+    micro_cat: FinCategory<MicroscopicConfiguration, MicroscopicMorphism>,
+    meso_cat: FinCategory<MesoscopicConfiguration, MesoscopicMorphism>,
+    coarse_graining: CoarseGrainingOptions,
+    refinement: RefinementOptions,
+) -> RefinementAdjunction {
+    let refine = create_meso_to_micro_functor_with(meso_cat.clone(), micro_cat.clone(), refinement);
+    let coarse = create_micro_to_meso_functor_with(micro_cat, meso_cat, coarse_graining);
+    
+    let round_trip = ComposedFunctor::new(refine.clone(), coarse.clone());
+    let unit_round_trip = round_trip.clone();
+    let unit = ConcreteNaturalTransformation::new(
+        "RefinementUnit".to_string(),
+        IdentityFunctor::new(),
+        round_trip,
+        move |meso_obj: &MesoscopicConfiguration| {
+            MesoscopicMorphism::reconstruction(meso_obj.clone(), unit_round_trip.map_object(meso_obj))
+        },
+    );
+    
+    let lossy = ComposedFunctor::new(coarse, refine);
+    let counit_lossy = lossy.clone();
+    let counit = ConcreteNaturalTransformation::new(
+        "RefinementCounit".to_string(),
+        lossy,
+        IdentityFunctor::new(),
+        move |micro_obj: &MicroscopicConfiguration| {
+            MicroscopicMorphism::reconstruction(counit_lossy.map_object(micro_obj), micro_obj.clone())
+        },
+    );
+    
+    Adjunction::new(unit, counit)
+}
+
 /// Performs coarse-graining of microscopic parameters to mesoscopic parameters
 pub fn coarse_grain_parameters(
     micro_params: &MicroscopicParameters,
@@ -384,7 +908,8 @@ pub fn coarse_grain_parameters(
     // based on block-spin averaging and integrating out high-frequency modes
     
     // Determine the scale factor from block size
-    let scale_factor = (block_size as f64).powf(1.0/3.0);
+    let d = micro_params.spatial_dimension;
+    let scale_factor = (block_size as f64).powf(1.0 / d as f64);
     
     // Temperature-dependent parameter gets rescaled
     let a_meso = micro_params.a / scale_factor;
@@ -417,6 +942,7 @@ pub fn coarse_grain_parameters(
         h: h_meso,
         temperature: temp_meso,
         xi: xi_meso,
+        spatial_dimension: d,
     })
 }
 
@@ -474,42 +1000,10 @@ pub fn rg_step_mesoscopic(params: &MesoscopicParameters) -> Result<MesoscopicPar
     // based on the classic Wilson renormalization approach
     
     // Scale factor for this RG step
-    let scale = 1.5;
-    
-    // Simple RG transformation rules:
-    // 1. a parameter flows according to its scaling dimension
-    let a_new = params.a * scale.powf(1.0);
+    let scale: f64 = 1.5;
     
-    // 2. b parameter flows
-    let b_new = params.b * scale.powf(0.5);
-    
-    // 3. c parameter is marginally relevant
-    let c_new = params.c * (1.0 + 0.1 * params.c.ln());
-    
-    // 4. Elastic constants scale with length
-    let l1_new = params.l1 * scale;
-    let l2_new = params.l2 * scale;
-    
-    // 5. External field coupling
-    let h_new = params.h * scale.powf(-0.5);
-    
-    // 6. Temperature flows to fixed point
-    let t_star = 300.0; // Critical temperature
-    let t_new = t_star + (params.temperature - t_star) * scale.powf(-1.0);
-    
-    // 7. Correlation length shrinks under RG
-    let xi_new = params.xi / scale;
-    
-    Ok(MesoscopicParameters {
-        a: a_new,
-        b: b_new,
-        c: c_new,
-        l1: l1_new,
-        l2: l2_new,
-        h: h_new,
-        temperature: t_new,
-        xi: xi_new,
-    })
+    // Integrate the beta function over one step
+    rg_flow::rescale_by(beta_function_mesoscopic, params, scale)
 }
 
 /// Calculate the beta function for mesoscopic parameters
@@ -517,12 +1011,11 @@ pub fn beta_function_mesoscopic(params: &MesoscopicParameters) -> Result<DVector
     // Calculate beta functions for all parameters
     // β(g) = dg/dl where l is the log of the scale factor
     
-    let beta_a = params.a; // Linear scaling
-    let beta_b = 0.5 * params.b; // Scaling dimension 1/2
-    let beta_c = 0.1 * params.c.powi(2); // Marginally relevant
-    let beta_l1 = params.l1; // Scales with length
-    let beta_l2 = params.l2; // Scales with length
-    let beta_h = -0.5 * params.h; // Scaling dimension -1/2
+    let epsilon = 4.0 - params.spatial_dimension as f64;
+    let [beta_a, beta_b, beta_c, beta_h] =
+        microscopic::landau_de_gennes_beta(params.a, params.b, params.c, params.h, epsilon);
+    let beta_l1 = 0.0; // l1 is marginal, as the gradient term fixes the normalization of Q
+    let beta_l2 = 0.0; // l2 is marginal
     let beta_t = -(params.temperature - 300.0); // Flow to fixed point
     let beta_xi = -params.xi; // Correlation length shrinks
     
@@ -550,9 +1043,6 @@ pub fn calculate_defect_tensor(field: &QTensorField) -> Result<Vec<DMatrix<f64>>
                 let gradients = field.gradient(i, j, k)?;
                 let [grad_x, grad_y, grad_z] = gradients;
                 
-                // Get the Q-tensor at this point
-                let q = field.get(i, j, k).unwrap().components.clone();
-                
                 // Simplified calculation of the defect tensor
                 // In a real implementation, this would involve a proper geometric calculation
                 let mut defect_tensor = DMatrix::zeros(3, 3);
@@ -573,4 +1063,279 @@ pub fn calculate_defect_tensor(field: &QTensorField) -> Result<Vec<DMatrix<f64>>
     }
     
     Ok(defect_tensors)
-}
\ No newline at end of file
+}
+
+/// Lattices for exercising the refinement adjunction in tests
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::*;
+    use crate::macroscopic::{Defect, DefectFieldOptions, MacroscopicConfiguration};
+    
+    /// A lattice with a +1 and a -1 disclination line, refined from the field
+    /// built around them and so constant on each 2×2×2 block
+    pub fn disclination_lattice() -> MicroscopicConfiguration {
+        let defects = MacroscopicConfiguration::new(
+            [10.0, 10.0, 3.0],
+            vec![
+                Defect::new([5.0, 5.0, 1.0], 1.0),
+                Defect::new([7.0, 3.0, 1.0], -1.0),
+            ],
+            300.0,
+            None,
+        );
+        RefinementOptions::default().refine(&DefectFieldOptions::default().build_field(&defects))
+    }
+    
+    /// Each configuration, the same heated by 10, and the heating between them
+    pub fn heated(configs: &[MicroscopicConfiguration]) -> MicroCategory {
+        let heating = Transformation::TemperatureChange { delta: 10.0 };
+        let mut objects = Vec::new();
+        let mut morphisms = Vec::new();
+        for config in configs {
+            let warm = config.transform(&heating).unwrap();
+            morphisms.push(MicroscopicMorphism::with_transformation(config.clone(), warm.clone(), heating.clone()));
+            morphisms.push(MicroscopicMorphism::with_transformation(config.clone(), config.clone(), Transformation::Identity));
+            morphisms.push(MicroscopicMorphism::with_transformation(warm.clone(), warm.clone(), Transformation::Identity));
+            objects.extend([config.clone(), warm]);
+        }
+        FinCategory::new("Heated".to_string(), objects, morphisms)
+    }
+    
+    /// The microscopic demo category and its coarse-graining, on which the demo functors land
+    pub fn demo_categories() -> (MicroCategory, MesoCategory) {
+        let micro_cat = microscopic::create_microscopic_category();
+        let meso_cat = CoarseGrainingOptions::default().coarse_grain_category(&micro_cat, "CoarseDemo").unwrap();
+        (micro_cat, meso_cat)
+    }
+    
+    /// The heated disclination lattice and its coarse-graining, closed under the round trip
+    pub fn disclination_categories() -> (MicroCategory, MesoCategory) {
+        let micro_cat = heated(&[disclination_lattice()]);
+        let meso_cat = CoarseGrainingOptions::default().coarse_grain_category(&micro_cat, "CoarseHeated").unwrap();
+        (micro_cat, meso_cat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::fixtures::{demo_categories, disclination_categories, disclination_lattice, heated};
+    use crate::category::{FinCategoryBuilder, LawViolation};
+    use crate::functor::NaturalTransformation;
+    use crate::microscopic::generate_microscopic_configuration;
+    
+    fn refinement_adjunction() -> RefinementAdjunction {
+        let (micro_cat, meso_cat) = demo_categories();
+        create_refinement_adjunction(
+            micro_cat,
+            meso_cat,
+            CoarseGrainingOptions::default(),
+            RefinementOptions::default(),
+        )
+    }
+    
+    /// A category of heatings with an extra `TemperatureChange(0)` at each object,
+    /// which acts as the identity without being it
+    fn with_idle_heating<M: ConfigurationMorphism + Clone + 'static>(
+        category: &FinCategory<M::ObjectType, M>,
+        delta: fn(&M) -> f64,
+    ) -> FinCategory<M::ObjectType, M> {
+        let idle = category.objects().iter()
+            .map(|obj| M::with_transformation(obj.clone(), obj.clone(), Transformation::TemperatureChange { delta: 0.0 }));
+        FinCategoryBuilder::new(category.name().to_string())
+            .objects(category.objects().to_vec())
+            .morphisms(category.morphisms().to_vec())
+            .morphisms(idle)
+            .composition_fn(move |f: &M, g: &M| {
+                if f.is_identity() {
+                    return Some(g.clone());
+                }
+                if g.is_identity() {
+                    return Some(f.clone());
+                }
+                let heating = Transformation::TemperatureChange { delta: delta(f) + delta(g) };
+                Some(M::with_transformation(f.domain().clone(), g.codomain().clone(), heating))
+            })
+            .build()
+            .unwrap()
+    }
+    
+    #[test]
+    fn refinement_adjunction_holds_on_demo_categories() {
+        let adjunction = refinement_adjunction();
+        let (micro_cat, meso_cat) = demo_categories();
+        
+        let report = adjunction.verify(&meso_cat, &micro_cat);
+        assert!(report.is_lawful(), "{}", report);
+        for obj in micro_cat.objects() {
+            assert!(adjunction.information_loss(obj).unwrap() < 1e-12);
+        }
+    }
+    
+    #[test]
+    fn refinement_adjunction_holds_only_on_lossless_lattices() {
+        let (micro_cat, meso_cat) = disclination_categories();
+        let adjunction = create_refinement_adjunction(
+            micro_cat.clone(),
+            meso_cat.clone(),
+            CoarseGrainingOptions::default(),
+            RefinementOptions::default(),
+        );
+        let report = adjunction.verify(&meso_cat, &micro_cat);
+        assert!(report.is_lawful(), "{}", report);
+        for obj in micro_cat.objects() {
+            assert!(adjunction.information_loss(obj).unwrap() < 1e-12);
+        }
+        
+        // A twisted lattice varies within blocks, so its round trip is not in the category
+        let twisted = generate_microscopic_configuration(4, 4, 4, "twisted", 300.0);
+        let micro_cat = heated(&[disclination_lattice(), twisted.clone()]);
+        let meso_cat = CoarseGrainingOptions::default().coarse_grain_category(&micro_cat, "CoarseHeated").unwrap();
+        let adjunction = create_refinement_adjunction(
+            micro_cat.clone(),
+            meso_cat.clone(),
+            CoarseGrainingOptions::default(),
+            RefinementOptions::default(),
+        );
+        assert!(adjunction.information_loss(&twisted).unwrap() > 0.0);
+        
+        let report = adjunction.verify(&meso_cat, &micro_cat);
+        assert!(report.violations.contains(&LawViolation::ComponentMismatch { object: twisted.id() }));
+        assert!(report.violations.contains(&LawViolation::LeftTriangle { object: meso_cat.objects()[2].id() }));
+        // Only the twisted lattice and what comes from it break the laws
+        let lossless: Vec<String> = micro_cat.objects()[..2].iter().map(|obj| obj.id()).collect();
+        assert!(!report.violations.iter().any(|v| matches!(v,
+            LawViolation::ComponentMismatch { object } | LawViolation::RightTriangle { object } if lossless.contains(object)
+        )));
+    }
+    
+    #[test]
+    fn refinement_returns_to_lattices_with_partial_blocks() {
+        let adjunction = refinement_adjunction();
+        let lattice = generate_microscopic_configuration(5, 5, 5, "twisted", 300.0);
+        let coarse = adjunction.right().map_object(&lattice);
+        let refined = adjunction.left().map_object(&coarse);
+        assert_eq!(coarse.field.resolution, (3, 3, 3));
+        assert_eq!(refined.dimensions, (5, 5, 5));
+        
+        let counit = adjunction.counit().component_at(&lattice).unwrap();
+        assert_eq!(counit.label(), "Reconstruction");
+        assert_eq!(counit.apply(&refined).unwrap().id(), lattice.id());
+        assert!(adjunction.information_loss(&lattice).unwrap() > 0.0);
+        
+        // Coarse-graining the restored detail undoes what the round trip did to the field
+        let coarse_counit = adjunction.right().map_morphism(&counit).unwrap();
+        assert_eq!(coarse_counit.apply(coarse_counit.domain()).unwrap().id(), coarse.id());
+    }
+    
+    #[test]
+    fn trilinear_refinement_reproduces_linear_fields() {
+        // Q = (x + 2y - z) M in units of cells, with M symmetric and traceless
+        let m = DMatrix::from_row_slice(3, 3, &[1.0, 0.5, 0.0, 0.5, -0.5, 0.2, 0.0, 0.2, -0.5]);
+        let linear = |x: f64, y: f64, z: f64| &m * (x + 2.0 * y - z);
+        let mut field = QTensorField::new((3, 3, 3), (2.0, 2.0, 2.0));
+        for i in 0..3 {
+            for j in 0..3 {
+                for k in 0..3 {
+                    field.set(i, j, k, QTensor::new(linear(i as f64, j as f64, k as f64))).unwrap();
+                }
+            }
+        }
+        let meso = MesoscopicConfiguration::new(field, 300.0, None, None);
+        
+        let options = RefinementOptions { factor: 2, interpolation: Interpolation::Trilinear };
+        let lattice = options.refine(&meso);
+        assert_eq!(lattice.dimensions, (6, 6, 6));
+        
+        // Site s has its centre at (s + 1/2)/2 - 1/2 cells, held at the outer cell centres
+        let cell = |s: usize| ((s as f64 + 0.5) / 2.0 - 0.5).clamp(0.0, 2.0);
+        for i in 0..6 {
+            for j in 0..6 {
+                for k in 0..6 {
+                    let q = &lattice.q_tensors[i * 36 + j * 6 + k].components;
+                    assert!((q - linear(cell(i), cell(j), cell(k))).norm() < 1e-12, "site ({}, {}, {})", i, j, k);
+                }
+            }
+        }
+        
+        // Box coarse-graining returns the interior cell, where no site was clamped
+        let coarse = CoarseGrainingOptions::default().coarse_grain(&lattice);
+        let centre = &coarse.field.get(1, 1, 1).unwrap().components;
+        assert!((centre - &meso.field.get(1, 1, 1).unwrap().components).norm() < 1e-12);
+    }
+    
+    #[test]
+    fn broken_counit_violates_triangle_identities() {
+        let adjunction = refinement_adjunction();
+        let heated_cat = heated(&[generate_microscopic_configuration(5, 5, 5, "uniform", 300.0)]);
+        let coarse_cat = CoarseGrainingOptions::default().coarse_grain_category(&heated_cat, "CoarseHeated").unwrap();
+        let micro_cat = with_idle_heating(&heated_cat, |m: &MicroscopicMorphism| {
+            match m.transformation {
+                Transformation::TemperatureChange { delta } => delta,
+                _ => 0.0,
+            }
+        });
+        let meso_cat = with_idle_heating(&coarse_cat, |m: &MesoscopicMorphism| {
+            match m.transformation {
+                Transformation::TemperatureChange { delta } => delta,
+                _ => 0.0,
+            }
+        });
+        assert!(adjunction.verify(&meso_cat, &micro_cat).is_lawful());
+        
+        // Idle heating in place of the identity is natural but no counit
+        let lossy = adjunction.counit().domain_functor().clone();
+        let counit = ConcreteNaturalTransformation::new(
+            "IdleCounit".to_string(),
+            lossy.clone(),
+            IdentityFunctor::new(),
+            move |micro_obj: &MicroscopicConfiguration| {
+                let idle = Transformation::TemperatureChange { delta: 0.0 };
+                Ok(MicroscopicMorphism::with_transformation(lossy.map_object(micro_obj), micro_obj.clone(), idle))
+            },
+        );
+        let broken = Adjunction::new(adjunction.unit().clone(), counit);
+        
+        let report = broken.verify(&meso_cat, &micro_cat);
+        assert!(report.violations.iter().any(|v| matches!(v, LawViolation::LeftTriangle { .. })));
+        assert!(report.violations.iter().any(|v| matches!(v, LawViolation::RightTriangle { .. })));
+        assert!(!report.violations.iter().any(|v| matches!(v, LawViolation::NaturalitySquare { .. })));
+    }
+    
+    #[test]
+    fn film_flow_has_its_own_fixed_point_and_exponents() {
+        let film = |a, c| MesoscopicParameters {
+            a, b: 0.0, c, l1: 1.0, l2: 1.0, h: 0.0, temperature: 300.0, xi: 0.0, spatial_dimension: 2,
+        };
+        
+        // At b = 0 and ε = 2, β_c = 0 puts c* = 2(1 + a*)²/13 and then β_a = 0 puts a* = -7/20
+        let interacting = film(-0.35, 2.0 * 0.65f64.powi(2) / 13.0);
+        assert!(beta_function_mesoscopic(&interacting).unwrap().norm() < 1e-12);
+        // In bulk c has dimension 1 instead of 2, which leaves β_c = -c* there
+        let bulk = MesoscopicParameters { spatial_dimension: 3, ..interacting.clone() };
+        let beta = beta_function_mesoscopic(&bulk).unwrap();
+        assert!(beta[0].abs() < 1e-12 && (beta[2] + interacting.c).abs() < 1e-12);
+        
+        // About the Gaussian point Q is dimensionless in a film, so a, b, c and h all have dimension 2
+        let exponents = |params: &MesoscopicParameters| {
+            let d = params.spatial_dimension;
+            let jacobian = rg_flow::numerical_jacobian(beta_function_mesoscopic, params, d).unwrap();
+            let fixed = rg_flow::linearized_stability(params, jacobian, d).unwrap();
+            assert_eq!(fixed.frozen_couplings, vec![3, 4]);
+            fixed.critical_exponents
+        };
+        let gaussian = film(0.0, 0.0);
+        for (found, expected) in exponents(&gaussian).iter().zip([2.0, 2.0, 2.0, 2.0, -1.0, -1.0]) {
+            assert!((found - expected).abs() < 1e-6, "{} != {}", found, expected);
+        }
+        let bulk = MesoscopicParameters { spatial_dimension: 3, ..gaussian.clone() };
+        for (found, expected) in exponents(&bulk).iter().zip([2.5, 2.0, 1.5, 1.0, -1.0, -1.0]) {
+            assert!((found - expected).abs() < 1e-6, "{} != {}", found, expected);
+        }
+        
+        // A step of the film flow stays in two dimensions
+        let step = rg_step_mesoscopic(&film(0.1, 0.0)).unwrap();
+        assert_eq!(step.spatial_dimension, 2);
+        assert!((step.a / 0.1 - 2.25).abs() < 1e-6);
+    }
+}
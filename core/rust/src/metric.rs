@@ -0,0 +1,338 @@
+use crate::category::{Category, LawReport, LawViolation, Morphism, Object};
+use crate::functor::Functor;
+use crate::microscopic::QTensor;
+use nalgebra::DMatrix;
+
+/// An object at a distance from other objects of the same kind.
+///
+/// Distances must be non-negative, vanish between equal objects and satisfy the
+/// triangle inequality. They may be infinite for objects that cannot be compared,
+/// as in a Lawvere metric space.
+pub trait MetricObject: Object {
+    /// Distance to another object
+    fn distance(&self, other: &Self) -> f64;
+}
+
+/// A morphism with a length, making each category a Lawvere metric space.
+///
+/// Identities must have length zero and composition must not be longer than
+/// its parts: `length(g ∘ f) <= length(f) + length(g)`.
+pub trait MetricMorphism: Morphism {
+    /// Length of this morphism
+    fn length(&self) -> f64;
+}
+
+/// Distance from `a` to `b` in the Lawvere metric of a category: the length of
+/// the shortest morphism `a -> b`, or infinity if there is none
+pub fn lawvere_distance<C>(category: &C, a: &C::Ob, b: &C::Ob) -> f64
+where
+    C: Category,
+    C::Mor: MetricMorphism,
+{
+    category.morphisms().iter()
+        .filter(|f| f.domain().id() == a.id() && f.codomain().id() == b.id())
+        .map(|f| f.length())
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Check that morphism lengths make a category enriched in Lawvere metric spaces
+pub fn check_enrichment<C>(category: &C, tolerance: f64) -> LawReport
+where
+    C: Category,
+    C::Mor: MetricMorphism,
+{
+    let mut report = LawReport::default();
+    let morphisms = category.morphisms();
+    
+    for (i, f) in morphisms.iter().enumerate() {
+        if f.is_identity() {
+            report.checked += 1;
+            if f.length() > tolerance {
+                report.violations.push(LawViolation::IdentityLength { morphism: i });
+            }
+        }
+        
+        for (j, g) in morphisms.iter().enumerate() {
+            if f.codomain().id() != g.domain().id() {
+                continue;
+            }
+            // Undefined composites are reported by the associativity check
+            let Ok(gf) = category.compose(f, g) else {
+                continue;
+            };
+            report.checked += 1;
+            
+            if gf.length() > f.length() + g.length() + tolerance {
+                report.violations.push(LawViolation::TriangleInequality { f: i, g: j });
+            }
+        }
+    }
+    
+    report
+}
+
+/// L2 distance between two lattices of Q-tensors, each site standing for a
+/// cell of volume `cell_volume`. Lattices with different numbers of sites are
+/// infinitely far apart.
+pub fn tensor_l2_distance(a: &[QTensor], b: &[QTensor], cell_volume: f64) -> f64 {
+    if a.len() != b.len() {
+        return f64::INFINITY;
+    }
+    
+    let sum: f64 = a.iter()
+        .zip(b)
+        .map(|(p, q)| (&p.components - &q.components).norm_squared())
+        .sum();
+    (sum * cell_volume).sqrt()
+}
+
+/// Euclidean distance between two external fields, an absent field or
+/// missing component counting as zero
+pub fn field_distance(a: Option<&[f64]>, b: Option<&[f64]>) -> f64 {
+    let (a, b) = (a.unwrap_or(&[]), b.unwrap_or(&[]));
+    (0..a.len().max(b.len()))
+        .map(|i| (a.get(i).copied().unwrap_or(0.0) - b.get(i).copied().unwrap_or(0.0)).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Estimate of how much a functor stretches distances between objects
+#[derive(Clone, Debug, PartialEq)]
+pub struct LipschitzEstimate {
+    /// Largest ratio d(F a, F b) / d(a, b) over pairs of distinct source objects
+    pub constant: f64,
+    
+    /// Indices of the source objects attaining the largest ratio
+    pub worst_pair: Option<(usize, usize)>,
+}
+
+impl LipschitzEstimate {
+    /// Whether the functor is Lipschitz with the given constant on the sampled objects
+    pub fn is_lipschitz(&self, bound: f64) -> bool {
+        self.constant <= bound
+    }
+}
+
+/// Estimate the Lipschitz constant of a functor on the objects of its source category.
+///
+/// Pairs at infinite distance are skipped. Pairs at distance zero whose images
+/// are apart make the estimate infinite.
+pub fn lipschitz_estimate<F>(functor: &F, source: &F::Source) -> LipschitzEstimate
+where
+    F: Functor,
+    <F::Source as Category>::Ob: MetricObject,
+    <F::Target as Category>::Ob: MetricObject,
+{
+    let objects = source.objects();
+    let images: Vec<_> = objects.iter().map(|obj| functor.map_object(obj)).collect();
+    let mut estimate = LipschitzEstimate { constant: 0.0, worst_pair: None };
+    
+    for i in 0..objects.len() {
+        for j in 0..objects.len() {
+            if i == j {
+                continue;
+            }
+            let distance = objects[i].distance(&objects[j]);
+            let image_distance = images[i].distance(&images[j]);
+            if distance.is_infinite() {
+                continue;
+            }
+            
+            let ratio = if distance > 0.0 {
+                image_distance / distance
+            } else if image_distance > 0.0 {
+                f64::INFINITY
+            } else {
+                0.0
+            };
+            if ratio > estimate.constant {
+                estimate = LipschitzEstimate { constant: ratio, worst_pair: Some((i, j)) };
+            }
+        }
+    }
+    
+    estimate
+}
+
+/// Solve the assignment problem for a square cost matrix with the Hungarian method.
+///
+/// Returns the minimal total cost and, for each row, the column assigned to it.
+pub fn min_cost_assignment(cost: &DMatrix<f64>) -> (f64, Vec<usize>) {
+    let n = cost.nrows();
+    if n == 0 {
+        return (0.0, Vec::new());
+    }
+    
+    // Row and column potentials, with row `p[j]` assigned to column `j`; index 0 is a sentinel
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; n + 1];
+    let mut p = vec![0usize; n + 1];
+    let mut way = vec![0usize; n + 1];
+    
+    for row in 1..=n {
+        p[0] = row;
+        let mut j0 = 0;
+        let mut min_slack = vec![f64::INFINITY; n + 1];
+        let mut used = vec![false; n + 1];
+        
+        // Grow an alternating tree until it reaches a free column
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = f64::INFINITY;
+            let mut j1 = 0;
+            
+            for j in 1..=n {
+                if used[j] {
+                    continue;
+                }
+                let slack = cost[(i0 - 1, j - 1)] - u[i0] - v[j];
+                if slack < min_slack[j] {
+                    min_slack[j] = slack;
+                    way[j] = j0;
+                }
+                if min_slack[j] < delta {
+                    delta = min_slack[j];
+                    j1 = j;
+                }
+            }
+            
+            for j in 0..=n {
+                if used[j] {
+                    u[p[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_slack[j] -= delta;
+                }
+            }
+            
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+        
+        // Flip the augmenting path
+        while j0 != 0 {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+        }
+    }
+    
+    let mut assignment = vec![0; n];
+    for j in 1..=n {
+        assignment[p[j] - 1] = j - 1;
+    }
+    let total = assignment.iter().enumerate().map(|(i, &j)| cost[(i, j)]).sum();
+    
+    (total, assignment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::fixtures::{edge, identity, Edge, Node};
+    use crate::category::FinCategory;
+    use crate::functor::ConcreteFunctor;
+    use std::collections::HashMap;
+    
+    impl MetricMorphism for Edge {
+        fn length(&self) -> f64 {
+            match self.name.as_str() {
+                "f" | "g" => 1.0,
+                "gf" => 3.0,
+                _ => 0.0,
+            }
+        }
+    }
+    
+    /// Nodes named by their position on a line
+    impl MetricObject for Node {
+        fn distance(&self, other: &Self) -> f64 {
+            let position = |node: &Node| node.0.parse::<f64>().unwrap();
+            (position(self) - position(other)).abs()
+        }
+    }
+    
+    /// The discrete category on nodes at the given positions
+    fn points(positions: &[&'static str]) -> FinCategory<Node, Edge> {
+        FinCategory::new(
+            "Points".to_string(),
+            positions.iter().map(|&p| Node(p)).collect(),
+            positions.iter().map(|&p| identity(p)).collect(),
+        )
+    }
+    
+    /// The functor between discrete categories sending the i-th node of `source` to the i-th of `target`
+    fn relabel(source: &[&'static str], target: &[&'static str]) -> ConcreteFunctor<FinCategory<Node, Edge>, FinCategory<Node, Edge>> {
+        let table: HashMap<&'static str, &'static str> = source.iter().copied().zip(target.iter().copied()).collect();
+        let objects = table.clone();
+        ConcreteFunctor::new(
+            "Relabel".to_string(),
+            points(source),
+            points(target),
+            move |obj| Node(objects[obj.0]),
+            move |morph| Ok(identity(table[morph.domain.0])),
+        )
+    }
+    
+    #[test]
+    fn lipschitz_estimate_finds_the_largest_stretch() {
+        // Doubling every distance except 1 -> 3, which is tripled
+        let source = ["0", "1", "3"];
+        let stretch = relabel(&source, &["0", "2", "8"]);
+        let estimate = lipschitz_estimate(&stretch, &points(&source));
+        assert_eq!(estimate.constant, 3.0);
+        assert_eq!(estimate.worst_pair, Some((1, 2)));
+        assert!(estimate.is_lipschitz(3.0) && !estimate.is_lipschitz(2.9));
+        
+        // Collapsing everything is Lipschitz with constant zero
+        let collapse = relabel(&source, &["5", "5", "5"]);
+        assert_eq!(lipschitz_estimate(&collapse, &points(&source)).constant, 0.0);
+        
+        // Two distinct objects at distance zero pulled apart cannot be Lipschitz
+        let tear = relabel(&["1", "1.0"], &["2", "2.5"]);
+        let estimate = lipschitz_estimate(&tear, &points(&["1", "1.0"]));
+        assert_eq!(estimate.constant, f64::INFINITY);
+        assert_eq!(estimate.worst_pair, Some((0, 1)));
+        assert!(!estimate.is_lipschitz(1e12));
+    }
+    
+    #[test]
+    fn enrichment_check_finds_composites_longer_than_their_parts() {
+        let chain = FinCategory::new(
+            "Chain".to_string(),
+            vec![Node("A"), Node("B"), Node("C")],
+            vec![
+                identity("A"), identity("B"), identity("C"),
+                edge("f", "A", "B"), edge("g", "B", "C"), edge("gf", "A", "C"),
+            ],
+        );
+        assert_eq!(lawvere_distance(&chain, &Node("A"), &Node("C")), 3.0);
+        assert_eq!(lawvere_distance(&chain, &Node("C"), &Node("A")), f64::INFINITY);
+        
+        let report = check_enrichment(&chain, 1e-12);
+        assert_eq!(report.violations, vec![LawViolation::TriangleInequality { f: 3, g: 4 }]);
+    }
+    
+    #[test]
+    fn hungarian_method_beats_greedy_matching() {
+        // Greedily taking the cheapest entry (0, 0) forces the expensive (1, 1)
+        let cost = DMatrix::from_row_slice(3, 3, &[
+            1.0, 2.0, 9.0,
+            2.0, 9.0, 9.0,
+            9.0, 9.0, 1.0,
+        ]);
+        let (total, assignment) = min_cost_assignment(&cost);
+        assert_eq!(assignment, vec![1, 0, 2]);
+        assert_eq!(total, 5.0);
+        
+        let unit = QTensor::new(DMatrix::identity(3, 3));
+        let zero = QTensor::new(DMatrix::zeros(3, 3));
+        let distance = tensor_l2_distance(&[unit.clone(), zero.clone()], &[zero.clone(), zero.clone()], 4.0);
+        assert!((distance - 2.0 * 3f64.sqrt()).abs() < 1e-12);
+        assert_eq!(tensor_l2_distance(&[unit], &[zero.clone(), zero], 1.0), f64::INFINITY);
+        assert_eq!(field_distance(Some(&[3.0, 4.0]), None), 5.0);
+    }
+}
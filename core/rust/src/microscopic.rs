@@ -1,6 +1,8 @@
-use crate::category::{Category, CategoryError, FinCategory, Morphism, Object};
-use crate::functor::{Functor, ConcreteFunctor};
-use crate::rg_flow::{ParameterSpace, RGFlowError};
+use crate::category::{CategoryError, FinCategory, Morphism, Object};
+use crate::hashing::{self, ContentHasher};
+use crate::metric::{self, MetricMorphism, MetricObject};
+use crate::rg_flow::{self, ParameterSpace, RGFlowError};
+use crate::transformation::{self, ConfigurationMorphism, LatticeRotation, Transformation};
 use nalgebra::{DMatrix, DVector, Matrix3, Vector3};
 use rand::Rng;
 use std::f64::consts::PI;
@@ -49,18 +51,18 @@ impl QTensor {
         let outer_product = n * n.transpose();
         let components = scalar_order * (outer_product - identity / 3.0);
         
-        Ok(Self { components })
+        Ok(Self { components: DMatrix::from_column_slice(3, 3, components.as_slice()) })
     }
     
     /// Convert this Q-tensor to a director and scalar order parameter
     pub fn to_director(&self) -> (f64, Vector3<f64>) {
         // Compute the eigendecomposition of the Q-tensor
-        let eigen = self.components.symmetric_eigen();
+        let eigen = self.components.clone().symmetric_eigen();
         let eigenvalues = eigen.eigenvalues;
         let eigenvectors = eigen.eigenvectors;
         
         // The director is the eigenvector corresponding to the largest eigenvalue
-        let max_idx = eigenvalues.argmax();
+        let max_idx = eigenvalues.argmax().0;
         let director = Vector3::new(
             eigenvectors[(0, max_idx)],
             eigenvectors[(1, max_idx)],
@@ -88,10 +90,96 @@ pub struct MicroscopicConfiguration {
     
     /// External field (if any)
     pub external_field: Option<Vector3<f64>>,
+    
+    /// Floats closer than this are considered equal in `Object::id`
+    pub id_tolerance: f64,
+}
+
+impl MicroscopicConfiguration {
+    /// Create a configuration with the default ID tolerance
+    pub fn new(
+        dimensions: (usize, usize, usize),
+        q_tensors: Vec<QTensor>,
+        temperature: f64,
+        external_field: Option<Vector3<f64>>,
+    ) -> Self {
+        Self { dimensions, q_tensors, temperature, external_field, id_tolerance: hashing::DEFAULT_ID_TOLERANCE }
+    }
+    
+    /// The same configuration with floats compared up to `tolerance` in `Object::id`.
+    ///
+    /// Only configurations sharing a tolerance have comparable IDs.
+    pub fn with_id_tolerance(mut self, tolerance: f64) -> Self {
+        self.id_tolerance = tolerance;
+        self
+    }
+    
+    /// Identifier derived from the configuration data, with floats compared up to `tolerance`
+    pub fn content_id(&self, tolerance: f64) -> String {
+        let mut hasher = ContentHasher::new(tolerance);
+        let (nx, ny, nz) = self.dimensions;
+        
+        for n in [nx, ny, nz] {
+            hasher.write_usize(n);
+        }
+        hasher.write_usize(self.q_tensors.len());
+        for q in &self.q_tensors {
+            hasher.write_f64s(q.components.iter());
+        }
+        hasher.write_f64(self.temperature);
+        hasher.write_option(self.external_field.as_ref(), |h, field| h.write_f64s(field.iter()));
+        
+        format!("MicroConfig_{:016x}", hasher.finish())
+    }
+    
+    /// Apply a transformation, giving the transformed configuration.
+    ///
+    /// The lattice spacing is taken as the unit of length.
+    pub fn transform(&self, transformation: &Transformation) -> Result<Self, CategoryError> {
+        let mut result = self.clone();
+        
+        match transformation {
+            Transformation::Identity => {}
+            Transformation::Rotation { axis, angle } => {
+                let rotation = transformation::rotation_matrix(axis, *angle)?;
+                let (q_tensors, dimensions) = LatticeRotation::from_matrix(&rotation)?
+                    .apply(&self.q_tensors, self.dimensions, &rotation)?;
+                result.q_tensors = q_tensors;
+                result.dimensions = dimensions;
+            }
+            Transformation::Translation { offset } => {
+                let shift = transformation::lattice_shift(offset, (1.0, 1.0, 1.0))?;
+                result.q_tensors = transformation::shift_lattice(&self.q_tensors, self.dimensions, shift);
+            }
+            Transformation::TemperatureChange { delta } => {
+                result.temperature += delta;
+            }
+            Transformation::FieldApplication { field } => {
+                result.external_field = Some(*field);
+            }
+            Transformation::DefectMotion { .. } => {
+                return Err(transformation.unsupported("a microscopic configuration"));
+            }
+            Transformation::Reconstruction { residual } => {
+                result.q_tensors = transformation::add_residual(&self.q_tensors, residual)?;
+            }
+            Transformation::CoarseGraining { factor } => {
+                let (q_tensors, dimensions) = transformation::block_average(&self.q_tensors, self.dimensions, *factor)?;
+                result.q_tensors = q_tensors;
+                result.dimensions = dimensions;
+            }
+        }
+        
+        Ok(result)
+    }
 }
 
 impl Object for MicroscopicConfiguration {
     fn id(&self) -> String {
+        self.content_id(self.id_tolerance)
+    }
+    
+    fn label(&self) -> String {
         let (nx, ny, nz) = self.dimensions;
         format!("MicroConfig_{}x{}x{}_T{:.2}", nx, ny, nz, self.temperature)
     }
@@ -102,6 +190,23 @@ impl Object for MicroscopicConfiguration {
     }
 }
 
+impl MetricObject for MicroscopicConfiguration {
+    /// L2 distance between the Q-tensor lattices, each site a unit cell, plus the
+    /// differences in temperature and external field. Infinite for different lattices.
+    fn distance(&self, other: &Self) -> f64 {
+        if self.dimensions != other.dimensions {
+            return f64::INFINITY;
+        }
+        
+        metric::tensor_l2_distance(&self.q_tensors, &other.q_tensors, 1.0)
+            + (self.temperature - other.temperature).abs()
+            + metric::field_distance(
+                self.external_field.as_ref().map(|h| h.as_slice()),
+                other.external_field.as_ref().map(|h| h.as_slice()),
+            )
+    }
+}
+
 /// Parameters for the microscopic Maier-Saupe model
 #[derive(Clone, Debug)]
 pub struct MicroscopicParameters {
@@ -120,6 +225,9 @@ pub struct MicroscopicParameters {
     
     /// Temperature
     pub temperature: f64,
+    
+    /// Spatial dimension d of the system
+    pub spatial_dimension: usize,
 }
 
 impl ParameterSpace for MicroscopicParameters {
@@ -127,6 +235,10 @@ impl ParameterSpace for MicroscopicParameters {
         7 // a, b, c, l1, l2, l3, h
     }
     
+    fn spatial_dimension(&self) -> usize {
+        self.spatial_dimension
+    }
+    
     fn as_vector(&self) -> DVector<f64> {
         DVector::from_vec(vec![
             self.a, self.b, self.c, 
@@ -134,7 +246,7 @@ impl ParameterSpace for MicroscopicParameters {
         ])
     }
     
-    fn from_vector(vec: DVector<f64>) -> Result<Self, RGFlowError> {
+    fn from_vector(vec: DVector<f64>, dim: usize) -> Result<Self, RGFlowError> {
         if vec.len() != 7 {
             return Err(RGFlowError::ParameterOutOfRange(
                 format!("Expected 7 parameters, got {}", vec.len())
@@ -150,6 +262,7 @@ impl ParameterSpace for MicroscopicParameters {
             l3: vec[5],
             h: vec[6],
             temperature: 300.0, // Default value, not stored in vector
+            spatial_dimension: dim,
         })
     }
     
@@ -167,11 +280,8 @@ pub struct MicroscopicMorphism {
     /// Codomain of this morphism
     pub codomain: MicroscopicConfiguration,
     
-    /// Type of transformation
-    pub transformation_type: String,
-    
-    /// Parameters of the transformation
-    pub parameters: Option<DVector<f64>>,
+    /// The transformation this morphism performs
+    pub transformation: Transformation,
 }
 
 impl Morphism for MicroscopicMorphism {
@@ -185,11 +295,36 @@ impl Morphism for MicroscopicMorphism {
         &self.codomain
     }
     
-    fn apply<T>(&self, data: &T) -> Result<T, CategoryError> 
-    where T: Clone + Debug {
-        // In a real implementation, this would transform the data
-        // For now, just return a clone
-        Ok(data.clone())
+    fn id(&self) -> String {
+        format!("{}:{}->{}", self.transformation, self.domain.id(), self.codomain.id())
+    }
+    
+    fn is_identity(&self) -> bool {
+        self.transformation == Transformation::Identity && self.domain.id() == self.codomain.id()
+    }
+    
+    fn label(&self) -> String {
+        self.transformation.name().to_string()
+    }
+    
+    fn apply(&self, obj: &Self::ObjectType) -> Result<Self::ObjectType, CategoryError> {
+        obj.transform(&self.transformation)
+    }
+}
+
+impl ConfigurationMorphism for MicroscopicMorphism {
+    fn with_transformation(domain: Self::ObjectType, codomain: Self::ObjectType, transformation: Transformation) -> Self {
+        Self { domain, codomain, transformation }
+    }
+    
+    fn q_tensors(obj: &Self::ObjectType) -> &[QTensor] {
+        &obj.q_tensors
+    }
+}
+
+impl MetricMorphism for MicroscopicMorphism {
+    fn length(&self) -> f64 {
+        self.domain.distance(&self.codomain)
     }
 }
 
@@ -272,12 +407,7 @@ pub fn generate_microscopic_configuration(
         },
     };
     
-    MicroscopicConfiguration {
-        dimensions: (nx, ny, nz),
-        q_tensors,
-        temperature,
-        external_field: None,
-    }
+    MicroscopicConfiguration::new((nx, ny, nz), q_tensors, temperature, None)
 }
 
 /// Calculate the bulk free energy for a microscopic configuration
@@ -362,31 +492,29 @@ pub fn calculate_free_energy(config: &MicroscopicConfiguration, params: &Microsc
 /// Create a category for microscopic configurations
 pub fn create_microscopic_category() -> 
     FinCategory<MicroscopicConfiguration, MicroscopicMorphism> {
-    // Create sample objects
+    // Create sample objects, the second one rotated into the xz-plane
+    let rotation = Transformation::Rotation { axis: Vector3::new(0.0, 1.0, 0.0), angle: PI / 2.0 };
     let config1 = generate_microscopic_configuration(5, 5, 5, "uniform", 300.0);
-    let config2 = generate_microscopic_configuration(5, 5, 5, "twisted", 300.0);
+    let config2 = config1.transform(&rotation).unwrap();
     
     // Create a morphism between them
     let morphism = MicroscopicMorphism {
         domain: config1.clone(),
         codomain: config2.clone(),
-        transformation_type: "Twist".to_string(),
-        parameters: None,
+        transformation: rotation,
     };
     
     // Create identity morphisms
     let id1 = MicroscopicMorphism {
         domain: config1.clone(),
         codomain: config1.clone(),
-        transformation_type: "Identity".to_string(),
-        parameters: None,
+        transformation: Transformation::Identity,
     };
     
     let id2 = MicroscopicMorphism {
         domain: config2.clone(),
         codomain: config2.clone(),
-        transformation_type: "Identity".to_string(),
-        parameters: None,
+        transformation: Transformation::Identity,
     };
     
     // Create the category
@@ -397,18 +525,94 @@ pub fn create_microscopic_category() ->
     )
 }
 
+/// Number of independent components of the Q-tensor
+const Q_COMPONENTS: f64 = 5.0;
+
+/// Canonical scaling dimensions of the Landau-de Gennes couplings in d = 4 - ε dimensions.
+///
+/// The Q-tensor is rescaled to keep the gradient term fixed, which gives it
+/// dimension (d - 2)/2, so the coupling of a term with k powers of Q has
+/// dimension d - k(d - 2)/2.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScalingDimensions {
+    /// Quadratic coupling a, 2
+    pub a: f64,
+    
+    /// Cubic coupling b, 1 + ε/2
+    pub b: f64,
+    
+    /// Quartic coupling c, ε
+    pub c: f64,
+    
+    /// External field coupling h, 3 - ε/2
+    pub h: f64,
+}
+
+impl ScalingDimensions {
+    /// Scaling dimensions at `epsilon` = 4 - d, which need not be an integer
+    pub fn new(epsilon: f64) -> Self {
+        let d = 4.0 - epsilon;
+        let field = (d - 2.0) / 2.0;
+        Self {
+            a: d - 2.0 * field,
+            b: d - 3.0 * field,
+            c: d - 4.0 * field,
+            h: d - field,
+        }
+    }
+}
+
+/// Beta functions of the Landau-de Gennes couplings (a, b, c, h) to one loop in ε = 4 - d.
+///
+/// The bulk free energy is a/2 tr(Q²) - b/3 tr(Q³) + c/4 [tr(Q²)]², with couplings
+/// in units where the gradient term is ½(∇Q)² and the momentum-shell factor K_d Λ^d
+/// is one. The field coupling gets no correction at this order.
+///
+/// Integrating out a shell of width dℓ adds ½ Tr ln(1 + a + U'') dℓ to the bulk
+/// free energy, where U'' is the Hessian of the interaction U = -b/3 tr(Q³) +
+/// c/4 [tr(Q²)]² in the components q_i of Q = Σ q_i T_i, with tr(T_i T_j) = δ_ij.
+/// Expanding the logarithm to fourth order in G U'', with G = 1/(1 + a), and
+/// matching powers of Q gives every coefficient below. The numbers rest on two
+/// identities for traceless symmetric 3×3 matrices: Σ_jk d_ijk d_ljk = 7/12 δ_il
+/// for d_ijk = tr(T_i T_j T_k), and tr(Q⁴) = ½ [tr(Q²)]² by Cayley-Hamilton, so
+/// no second quartic coupling is generated. At b = 0 this is the one-loop flow of
+/// the O(5) model. The momentum dependence of the cubic bubble, which gives Q an
+/// anomalous dimension of order b², is left out.
+///
+/// G diverges at a = -1, where the shell modes go soft, and the expansion about
+/// the disordered phase only holds for a > -1; fixed-point searches should keep
+/// their boxes inside that half-space.
+pub fn landau_de_gennes_beta(a: f64, b: f64, c: f64, h: f64, epsilon: f64) -> [f64; 4] {
+    let y = ScalingDimensions::new(epsilon);
+    let n = Q_COMPONENTS;
+    let g = 1.0 / (1.0 + a);
+    
+    // Tadpole from the quartic term and bubble from two cubic vertices, ½ (2b)² · 7/12
+    let beta_a = y.a * a + (n + 2.0) * c * g - 7.0 / 6.0 * b * b * g.powi(2);
+    
+    // Quartic-cubic bubble and cubic triangle
+    let beta_b = y.b * b - 6.0 * b * c * g.powi(2) - 0.5 * b.powi(3) * g.powi(3);
+    
+    // Quartic bubble, then loops with two and four cubic vertices
+    let beta_c = y.c * c - (n + 8.0) * c * c * g.powi(2)
+        + 22.0 / 3.0 * b * b * c * g.powi(3)
+        - 25.0 / 36.0 * b.powi(4) * g.powi(4);
+    
+    let beta_h = y.h * h;
+    
+    [beta_a, beta_b, beta_c, beta_h]
+}
+
 /// Calculate the RG beta function for microscopic parameters
 pub fn beta_function_microscopic(params: &MicroscopicParameters) -> Result<DVector<f64>, RGFlowError> {
     // Calculate beta functions for all parameters
     // β(g) = dg/dl where l is the log of the scale factor
     
-    let beta_a = 2.0 * params.a; // a has scaling dimension 2
-    let beta_b = 1.5 * params.b; // b has scaling dimension 3/2
-    let beta_c = params.c; // c has scaling dimension 1
+    let [beta_a, beta_b, beta_c, beta_h] =
+        landau_de_gennes_beta(params.a, params.b, params.c, params.h, 4.0 - params.spatial_dimension as f64);
     let beta_l1 = 0.0; // l1 is marginal
     let beta_l2 = 0.0; // l2 is marginal
     let beta_l3 = 0.0; // l3 is marginal
-    let beta_h = 1.5 * params.h; // h has scaling dimension 3/2
     
     Ok(DVector::from_vec(vec![
         beta_a, beta_b, beta_c, beta_l1, beta_l2, beta_l3, beta_h
@@ -418,30 +622,90 @@ pub fn beta_function_microscopic(params: &MicroscopicParameters) -> Result<DVect
 /// Implement an RG step for microscopic parameters
 pub fn rg_step_microscopic(params: &MicroscopicParameters) -> Result<MicroscopicParameters, RGFlowError> {
     // Scale factor for this RG step
-    let scale = 1.5;
-    
-    // Update parameters based on their scaling dimensions
-    let a_new = params.a * scale.powf(2.0);
-    let b_new = params.b * scale.powf(1.5);
-    let c_new = params.c * scale.powf(1.0);
-    // Elastic constants stay unchanged in this simplified model
-    let l1_new = params.l1;
-    let l2_new = params.l2;
-    let l3_new = params.l3;
-    let h_new = params.h * scale.powf(1.5);
+    let scale: f64 = 1.5;
+    
+    // Couplings follow the beta function over one step
+    let mut rescaled = rg_flow::rescale_by(beta_function_microscopic, params, scale)?;
     
     // Temperature flows toward the critical temperature
     let t_critical = 330.0;
-    let temp_new = t_critical + (params.temperature - t_critical) * scale.powf(-1.0);
-    
-    Ok(MicroscopicParameters {
-        a: a_new,
-        b: b_new,
-        c: c_new,
-        l1: l1_new,
-        l2: l2_new,
-        l3: l3_new,
-        h: h_new,
-        temperature: temp_new,
-    })
+    rescaled.temperature = t_critical + (params.temperature - t_critical) * scale.powf(-1.0);
+    
+    Ok(rescaled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Fixed point of the b = 0 flow, by Newton steps on the diagonal of the Jacobian
+    fn nematic_fixed_point(epsilon: f64) -> (f64, f64) {
+        let (mut a, mut c) = (0.0, epsilon);
+        for _ in 0..100 {
+            let [beta_a, _, beta_c, _] = landau_de_gennes_beta(a, 0.0, c, 0.0, epsilon);
+            let g = 1.0 / (1.0 + a);
+            a -= beta_a / (2.0 - (Q_COMPONENTS + 2.0) * c * g * g);
+            c -= beta_c / (epsilon - 2.0 * (Q_COMPONENTS + 8.0) * c * g * g);
+        }
+        (a, c)
+    }
+    
+    #[test]
+    fn fixed_point_matches_epsilon_expansion() {
+        for epsilon in [1e-2, 1e-3] {
+            let (a, c) = nematic_fixed_point(epsilon);
+            let [beta_a, _, beta_c, _] = landau_de_gennes_beta(a, 0.0, c, 0.0, epsilon);
+            assert!(beta_a.abs() < 1e-12 && beta_c.abs() < 1e-12);
+            
+            // c* = ε/(n + 8) and a* = -(n + 2)/2 c* with n = 5, up to O(ε²)
+            assert!((c - epsilon / 13.0).abs() < epsilon * epsilon);
+            assert!((a + 7.0 / 13.0 * epsilon / 2.0).abs() < epsilon * epsilon);
+        }
+    }
+    
+    #[test]
+    fn scaling_dimensions_follow_the_spatial_dimension() {
+        // In a film Q is dimensionless, so every coupling has dimension d = 2
+        assert_eq!(ScalingDimensions::new(2.0), ScalingDimensions { a: 2.0, b: 2.0, c: 2.0, h: 2.0 });
+        assert_eq!(ScalingDimensions::new(1.0), ScalingDimensions { a: 2.0, b: 1.5, c: 1.0, h: 2.5 });
+        assert_eq!(ScalingDimensions::new(0.0), ScalingDimensions { a: 2.0, b: 1.0, c: 0.0, h: 3.0 });
+        
+        // Without c and b the loops vanish and only the rescaling is left
+        let film = MicroscopicParameters {
+            a: 0.5, b: 0.0, c: 0.0, l1: 1.0, l2: 1.0, l3: 1.0, h: 0.1, temperature: 300.0, spatial_dimension: 2,
+        };
+        let beta = beta_function_microscopic(&film).unwrap();
+        assert_eq!(beta.as_slice(), &[1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.2]);
+        let bulk = MicroscopicParameters { spatial_dimension: 3, ..film.clone() };
+        assert_eq!(beta_function_microscopic(&bulk).unwrap()[6], 0.25);
+        
+        // A step rescales lengths by 3/2, integrating the flow to about 1e-7
+        let step = rg_step_microscopic(&film).unwrap();
+        assert_eq!(step.spatial_dimension, 2);
+        assert!((step.a / 0.5 - 2.25).abs() < 1e-6 && (step.h / 0.1 - 2.25).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn translations_move_whole_sites_only() {
+        let mut config = generate_microscopic_configuration(3, 1, 1, "uniform", 300.0);
+        config.q_tensors[0] = QTensor::from_director(&Vector3::new(1.0, 0.0, 0.0), 0.5).unwrap();
+        
+        let shifted = config.transform(&Transformation::Translation { offset: Vector3::new(1.0, 0.0, 0.0) }).unwrap();
+        assert_eq!(shifted.q_tensors[1], config.q_tensors[0]);
+        assert_eq!(shifted.q_tensors[0], config.q_tensors[2]);
+        
+        let partial = config.transform(&Transformation::Translation { offset: Vector3::new(0.4, 0.0, 0.0) });
+        assert!(matches!(partial, Err(CategoryError::InvalidApplication(_))));
+    }
+    
+    #[test]
+    fn ids_follow_fields_up_to_tolerance() {
+        let config = generate_microscopic_configuration(2, 2, 2, "uniform", 300.0);
+        let mut warmer = config.clone();
+        warmer.temperature += 1e-6;
+        assert_ne!(config.id(), warmer.id());
+        
+        let coarse = |c: &MicroscopicConfiguration| c.clone().with_id_tolerance(1e-3).id();
+        assert_eq!(coarse(&config), coarse(&warmer));
+    }
 }
@@ -0,0 +1,305 @@
+use crate::rg_flow::{FlowTermination, IntegrationOptions, ParameterSpace, RGFixedPoint, RGFlow, RGFlowError};
+use nalgebra::DVector;
+
+/// One of the two varied coordinates of a [`PhasePlane`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlaneAxis {
+    /// Index of the coupling in [`ParameterSpace::as_vector`]
+    pub parameter: usize,
+    
+    /// Smallest value on the grid
+    pub min: f64,
+    
+    /// Largest value on the grid
+    pub max: f64,
+    
+    /// Number of grid lines, including both ends
+    pub points: usize,
+}
+
+impl PlaneAxis {
+    /// Create an axis varying `parameter` over `points` evenly spaced values
+    pub fn new(parameter: usize, min: f64, max: f64, points: usize) -> Self {
+        Self { parameter, min, max, points }
+    }
+    
+    /// Coordinate of the `index`-th grid line
+    pub fn value(&self, index: usize) -> f64 {
+        if self.points <= 1 {
+            self.min
+        } else {
+            self.min + (self.max - self.min) * index as f64 / (self.points - 1) as f64
+        }
+    }
+    
+    /// Coordinates of all grid lines
+    pub fn values(&self) -> Vec<f64> {
+        (0..self.points).map(|index| self.value(index)).collect()
+    }
+}
+
+/// A 2D slice of parameter space: two couplings varied on a grid, the rest held at `base`
+#[derive(Clone, Debug)]
+pub struct PhasePlane<P: ParameterSpace> {
+    /// Values of the couplings that are not varied
+    pub base: P,
+    
+    /// Horizontal axis
+    pub x: PlaneAxis,
+    
+    /// Vertical axis
+    pub y: PlaneAxis,
+}
+
+impl<P: ParameterSpace> PhasePlane<P> {
+    /// Create a plane through `base` spanned by two axes
+    pub fn new(base: P, x: PlaneAxis, y: PlaneAxis) -> Self {
+        Self { base, x, y }
+    }
+    
+    /// The point of parameter space at column `i` and row `j` of the grid
+    pub fn point(&self, i: usize, j: usize, dim: usize) -> Result<P, RGFlowError> {
+        let mut v = self.base.as_vector();
+        v[self.x.parameter] = self.x.value(i);
+        v[self.y.parameter] = self.y.value(j);
+        P::from_vector(v, dim)
+    }
+    
+    /// Orthogonal projection of a point of parameter space onto the plane
+    pub fn project(&self, v: &DVector<f64>) -> [f64; 2] {
+        [v[self.x.parameter], v[self.y.parameter]]
+    }
+}
+
+/// Where the flow from a grid point ends up
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Basin {
+    /// Reached the fixed point at this index
+    FixedPoint(usize),
+    
+    /// Left the region of interest or blew up
+    Runaway,
+    
+    /// Neither reached a fixed point nor ran away within the flow length
+    Unresolved,
+}
+
+impl Basin {
+    /// Label used when exporting phase diagrams
+    pub fn label(&self) -> String {
+        match self {
+            Basin::FixedPoint(index) => format!("fixed_point_{}", index),
+            Basin::Runaway => "runaway".to_string(),
+            Basin::Unresolved => "unresolved".to_string(),
+        }
+    }
+}
+
+/// Options for [`map_phase_diagram`]
+#[derive(Clone, Debug)]
+pub struct PhaseDiagramOptions {
+    /// Longest flow in ℓ from each grid point
+    pub length: f64,
+    
+    /// How often in ℓ to check whether the flow has settled or run away
+    pub check_interval: f64,
+    
+    /// A flow is captured once it is this close to a fixed point
+    pub capture_radius: f64,
+    
+    /// A flow has run away once it is this far from the base of the plane
+    pub runaway_distance: f64,
+    
+    /// Keep the projected trajectory of every this many grid points along each axis; 0 keeps none
+    pub streamline_stride: usize,
+    
+    /// Options for the continuous flow. Any event that fires counts as a runaway.
+    pub integration: IntegrationOptions,
+}
+
+impl Default for PhaseDiagramOptions {
+    fn default() -> Self {
+        Self {
+            length: 50.0,
+            check_interval: 0.5,
+            capture_radius: 1e-3,
+            runaway_distance: 1e3,
+            streamline_stride: 4,
+            integration: IntegrationOptions::default(),
+        }
+    }
+}
+
+/// Basins of attraction and flow on a [`PhasePlane`]
+#[derive(Clone, Debug)]
+pub struct PhaseDiagram {
+    /// Horizontal axis
+    pub x: PlaneAxis,
+    
+    /// Vertical axis
+    pub y: PlaneAxis,
+    
+    /// Basin of each grid point, indexed by row and then column
+    pub basins: Vec<Vec<Basin>>,
+    
+    /// β projected onto the plane at each grid point, indexed like `basins`
+    pub flow: Vec<Vec<[f64; 2]>>,
+    
+    /// Projected trajectories from a subset of the grid points
+    pub streamlines: Vec<Vec<[f64; 2]>>,
+    
+    /// Projections of the fixed points the basins refer to
+    pub fixed_points: Vec<[f64; 2]>,
+    
+    /// Classification of each fixed point
+    pub fixed_point_types: Vec<String>,
+}
+
+impl PhaseDiagram {
+    /// Number of grid points in `basin`
+    pub fn count(&self, basin: Basin) -> usize {
+        self.basins.iter().flatten().filter(|b| **b == basin).count()
+    }
+}
+
+/// Flow every point of `plane` until it reaches one of `fixed_points` or runs away.
+///
+/// The flow is not confined to the plane; couplings held fixed at the grid
+/// points evolve too, and trajectories are projected back onto it.
+pub fn map_phase_diagram<P, R>(
+    flow: &R,
+    plane: &PhasePlane<P>,
+    fixed_points: &[RGFixedPoint<P>],
+    options: &PhaseDiagramOptions,
+) -> Result<PhaseDiagram, RGFlowError>
+where
+    P: ParameterSpace,
+    R: RGFlow<P> + ?Sized,
+{
+    let n = plane.base.dimension();
+    for axis in [&plane.x, &plane.y] {
+        if axis.parameter >= n {
+            return Err(RGFlowError::ParameterOutOfRange(
+                format!("Axis on parameter {} of a {}-dimensional flow", axis.parameter, n)
+            ));
+        }
+    }
+    for (name, value) in [
+        ("length", options.length),
+        ("check_interval", options.check_interval),
+        ("capture_radius", options.capture_radius),
+    ] {
+        // A zero check interval would never advance the flow
+        if value.is_nan() || value <= 0.0 {
+            return Err(RGFlowError::ParameterOutOfRange(
+                format!("Phase diagram option {} must be positive, got {}", name, value)
+            ));
+        }
+    }
+    
+    let dim = flow.spatial_dimension();
+    let mut diagram = PhaseDiagram {
+        x: plane.x.clone(),
+        y: plane.y.clone(),
+        basins: Vec::with_capacity(plane.y.points),
+        flow: Vec::with_capacity(plane.y.points),
+        streamlines: Vec::new(),
+        fixed_points: fixed_points.iter().map(|fixed| plane.project(&fixed.parameters.as_vector())).collect(),
+        fixed_point_types: fixed_points.iter().map(|fixed| fixed.classification.clone()).collect(),
+    };
+    
+    for j in 0..plane.y.points {
+        let mut basin_row = Vec::with_capacity(plane.x.points);
+        let mut flow_row = Vec::with_capacity(plane.x.points);
+        
+        for i in 0..plane.x.points {
+            let start = plane.point(i, j, dim)?;
+            flow_row.push(plane.project(&flow.beta_function(&start)?));
+            
+            let keep_streamline = options.streamline_stride > 0
+                && i % options.streamline_stride == 0
+                && j % options.streamline_stride == 0;
+            let mut streamline = vec![plane.project(&start.as_vector())];
+            let mut state = start;
+            let mut elapsed = 0.0;
+            
+            // Flow in short stretches, checking for capture or runaway in between
+            let basin = loop {
+                let captured = fixed_points.iter()
+                    .map(|fixed| fixed.parameters.distance(&state))
+                    .enumerate()
+                    .filter(|(_, distance)| *distance < options.capture_radius)
+                    .min_by(|a, b| a.1.total_cmp(&b.1));
+                if let Some((index, _)) = captured {
+                    break Basin::FixedPoint(index);
+                }
+                let distance = state.distance(&plane.base);
+                if distance.is_nan() || distance > options.runaway_distance {
+                    break Basin::Runaway;
+                }
+                if elapsed >= options.length {
+                    break Basin::Unresolved;
+                }
+                
+                let stretch = options.check_interval.min(options.length - elapsed);
+                let trajectory = flow.integrate(&state, stretch, &options.integration)?;
+                if keep_streamline {
+                    streamline.extend(trajectory.points.iter().skip(1).map(|p| plane.project(&p.as_vector())));
+                }
+                match trajectory.termination {
+                    FlowTermination::Completed => {}
+                    FlowTermination::Event { .. }
+                    | FlowTermination::StepSizeTooSmall
+                    | FlowTermination::NonFinite => break Basin::Runaway,
+                    FlowTermination::MaxSteps => break Basin::Unresolved,
+                }
+                
+                match trajectory.points.into_iter().last() {
+                    Some(last) => state = last,
+                    None => break Basin::Unresolved,
+                }
+                elapsed += stretch;
+            };
+            
+            basin_row.push(basin);
+            if keep_streamline {
+                diagram.streamlines.push(streamline);
+            }
+        }
+        
+        diagram.basins.push(basin_row);
+        diagram.flow.push(flow_row);
+    }
+    
+    Ok(diagram)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rg_flow::fixtures::{saddle_flow, Couplings};
+    
+    #[test]
+    fn basins_split_at_the_saddle() {
+        // g₁ < 0 blows up, g₁ = 0 stays on the saddle and g₁ > 0 flows to g₁ = 1
+        let flow = saddle_flow();
+        let fixed_points = [Couplings::new(&[0.0, 0.0, 0.5]), Couplings::new(&[1.0, 0.0, 0.5])]
+            .iter()
+            .map(|point| flow.analyze_fixed_point(point))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let plane = PhasePlane::new(
+            Couplings::new(&[0.0, 0.0, 0.5]),
+            PlaneAxis::new(0, -0.5, 1.5, 5),
+            PlaneAxis::new(1, -1.0, 1.0, 3),
+        );
+        
+        let diagram = map_phase_diagram(&flow, &plane, &fixed_points, &PhaseDiagramOptions::default()).unwrap();
+        let expected = [Basin::Runaway, Basin::FixedPoint(0), Basin::FixedPoint(1), Basin::FixedPoint(1), Basin::FixedPoint(1)];
+        for row in &diagram.basins {
+            assert_eq!(row, &expected);
+        }
+        assert_eq!(diagram.fixed_points, vec![[0.0, 0.0], [1.0, 0.0]]);
+        assert_eq!(diagram.fixed_point_types, vec!["saddle".to_string(), "stable".to_string()]);
+    }
+}
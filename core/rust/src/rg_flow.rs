@@ -1,6 +1,9 @@
 use crate::category::{Category, CategoryError};
-use crate::functor::{Functor, NaturalTransformation};
-use nalgebra::DVector;
+use crate::functor::Functor;
+use nalgebra::{Complex, DMatrix, DVector};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use thiserror::Error;
@@ -22,6 +25,148 @@ pub enum RGFlowError {
     
     #[error("Dimension mismatch: expected {expected}, got {actual}")]
     DimensionMismatch { expected: usize, actual: usize },
+    
+    #[error("Fixed point solver failed after {} iterations with |β| = {:e}: {reason}", .diagnostics.iterations, .diagnostics.residual_norm)]
+    SolverFailed { reason: SolverFailure, diagnostics: SolverDiagnostics },
+}
+
+/// Why the fixed point solver gave up
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum SolverFailure {
+    #[error("iteration limit reached")]
+    MaxIterations,
+    
+    #[error("steps became negligible before the beta function vanished")]
+    Stalled,
+    
+    #[error("no damped step reduced the beta function")]
+    DampingExhausted,
+    
+    #[error("beta function or Jacobian is not finite")]
+    NonFinite,
+}
+
+/// Options for [`RGFlow::solve_fixed_point`]
+#[derive(Clone, Debug)]
+pub struct FixedPointSolverOptions {
+    /// Maximum number of accepted or rejected steps
+    pub max_iterations: usize,
+    
+    /// Converged once |β| falls below this
+    pub tolerance: f64,
+    
+    /// Stalled once a step is shorter than this
+    pub step_tolerance: f64,
+    
+    /// Initial Levenberg-Marquardt damping; zero starts with plain Newton steps
+    pub damping: f64,
+    
+    /// Factor by which the damping grows on a rejected step and shrinks on an accepted one
+    pub damping_factor: f64,
+    
+    /// Give up once the damping exceeds this
+    pub max_damping: f64,
+}
+
+impl Default for FixedPointSolverOptions {
+    fn default() -> Self {
+        Self {
+            max_iterations: 100,
+            tolerance: 1e-10,
+            step_tolerance: 1e-14,
+            damping: 1e-3,
+            damping_factor: 10.0,
+            max_damping: 1e12,
+        }
+    }
+}
+
+/// How the fixed point solver progressed
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SolverDiagnostics {
+    /// Number of steps attempted
+    pub iterations: usize,
+    
+    /// Number of those steps that were rejected
+    pub rejected_steps: usize,
+    
+    /// |β| at the final point
+    pub residual_norm: f64,
+    
+    /// Length of the last accepted step
+    pub step_norm: f64,
+    
+    /// Damping at the end of the run
+    pub damping: f64,
+    
+    /// |β| after each accepted step, starting with the initial guess
+    pub residual_history: Vec<f64>,
+}
+
+/// A zero of the beta function found by [`RGFlow::solve_fixed_point`]
+#[derive(Clone, Debug)]
+pub struct FixedPointSolution<P: ParameterSpace> {
+    /// The parameter values at which β vanishes
+    pub parameters: P,
+    
+    /// How the solver got there
+    pub diagnostics: SolverDiagnostics,
+}
+
+/// How [`RGFlow::find_fixed_points`] places its initial guesses in the search box
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SeedStrategy {
+    /// A regular grid with this many points along every axis of nonzero width
+    Grid { points_per_axis: usize },
+    
+    /// A Latin hypercube design, reproducible from `seed`
+    LatinHypercube { samples: usize, seed: u64 },
+    
+    /// Uniformly random points, reproducible from `seed`
+    Random { samples: usize, seed: u64 },
+}
+
+/// Options for [`RGFlow::find_fixed_points`]
+#[derive(Clone, Debug)]
+pub struct FixedPointSearchOptions {
+    /// Initial guesses for the solver
+    pub seeds: SeedStrategy,
+    
+    /// Options for each solver run
+    pub solver: FixedPointSolverOptions,
+    
+    /// Solutions closer than this are the same fixed point. Degenerate zeros of β
+    /// are only located to about the square root of the solver tolerance.
+    pub cluster_tolerance: f64,
+}
+
+impl Default for FixedPointSearchOptions {
+    fn default() -> Self {
+        Self {
+            seeds: SeedStrategy::LatinHypercube { samples: 64, seed: 0 },
+            solver: FixedPointSolverOptions::default(),
+            cluster_tolerance: 1e-3,
+        }
+    }
+}
+
+/// The distinct fixed points found by [`RGFlow::find_fixed_points`]
+#[derive(Clone, Debug)]
+pub struct FixedPointCatalogue<P: ParameterSpace> {
+    /// Each distinct fixed point in the search box, analyzed
+    pub fixed_points: Vec<RGFixedPoint<P>>,
+    
+    /// How many seeds converged to each of `fixed_points`
+    pub hits: Vec<usize>,
+    
+    /// Number of seeds tried
+    pub seeds: usize,
+    
+    /// Seeds for which the solver failed
+    pub failures: usize,
+    
+    /// Seeds that converged to a fixed point outside the search box
+    pub outside: usize,
 }
 
 /// Trait for parameter spaces that can be used in RG flows
@@ -53,10 +198,11 @@ pub struct RGFixedPoint<P: ParameterSpace> {
     /// The parameter values at the fixed point
     pub parameters: P,
     
-    /// Critical exponents at the fixed point
+    /// Critical exponents at the fixed point: the real parts of the eigenvalues of
+    /// the stability matrix, most relevant first
     pub critical_exponents: Vec<f64>,
     
-    /// Classification of the fixed point (stable, unstable, saddle)
+    /// Classification of the fixed point (stable, unstable, saddle, marginal)
     pub classification: String,
     
     /// Universality class
@@ -64,13 +210,171 @@ pub struct RGFixedPoint<P: ParameterSpace> {
     
     /// Spatial dimension
     pub dimension: usize,
+    
+    /// Stability matrix ∂β_i/∂g_j at the fixed point
+    pub stability_matrix: DMatrix<f64>,
+    
+    /// Eigendirections of the stability matrix, most relevant first, leaving
+    /// out the frozen couplings
+    pub directions: Vec<ScalingDirection>,
+    
+    /// Couplings whose beta function does not depend on any coupling near the
+    /// fixed point, such as exactly marginal ones. Every value of them is a fixed
+    /// point, so they are left out of the classification and critical exponents.
+    pub frozen_couplings: Vec<usize>,
+    
+    /// Correlation-length exponent ν = 1/y of the most relevant direction, if any
+    pub correlation_length_exponent: Option<f64>,
+}
+
+/// How a small perturbation along a scaling direction behaves under the flow
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Relevance {
+    /// Grows away from the fixed point
+    Relevant,
+    
+    /// Decays back to the fixed point
+    Irrelevant,
+    
+    /// Neither grows nor decays at linear order
+    Marginal,
+}
+
+/// An eigendirection of the stability matrix at a fixed point
+#[derive(Clone, Debug)]
+pub struct ScalingDirection {
+    /// Eigenvalue y; a perturbation along this direction scales as e^{y l}
+    pub eigenvalue: Complex<f64>,
+    
+    /// Unit eigenvector in parameter space, or `None` for complex eigenvalues
+    pub eigenvector: Option<DVector<f64>>,
+    
+    /// Whether the direction is relevant, irrelevant or marginal
+    pub relevance: Relevance,
+}
+
+/// Eigenvalues closer to the imaginary axis than this are treated as marginal.
+/// A marginal coupling is only solved to about the square root of the solver
+/// tolerance, which leaves a small spurious eigenvalue of that order.
+const MARGINAL_TOLERANCE: f64 = 1e-4;
+
+/// Linearize the flow about `fixed_point` using its stability matrix.
+///
+/// β is taken as dg/dl for the log of the length scale l, so positive
+/// eigenvalues are relevant and a fixed point with only negative ones is stable.
+/// A coupling with a zero row in the stability matrix does not flow and only
+/// adds a zero eigenvalue, so it is reported as frozen and the directions are
+/// found among the other couplings.
+pub fn linearized_stability<P: ParameterSpace>(
+    fixed_point: &P,
+    stability_matrix: DMatrix<f64>,
+    dimension: usize,
+) -> Result<RGFixedPoint<P>, RGFlowError> {
+    let n = fixed_point.dimension();
+    if stability_matrix.nrows() != n || stability_matrix.ncols() != n {
+        return Err(RGFlowError::DimensionMismatch { expected: n, actual: stability_matrix.nrows() });
+    }
+    if stability_matrix.iter().any(|v| !v.is_finite()) {
+        return Err(RGFlowError::ParameterOutOfRange("stability matrix is not finite".to_string()));
+    }
+    
+    let frozen_couplings: Vec<usize> = (0..n)
+        .filter(|&i| stability_matrix.row(i).iter().all(|&v| v == 0.0))
+        .collect();
+    let flowing: Vec<usize> = (0..n).filter(|i| !frozen_couplings.contains(i)).collect();
+    let reduced = DMatrix::from_fn(flowing.len(), flowing.len(), |i, j| stability_matrix[(flowing[i], flowing[j])]);
+    
+    // Frozen rows vanish, so the other components of an eigenvector with a
+    // nonzero eigenvalue form an eigenvector of the reduced matrix
+    let embed = |v: DVector<f64>| {
+        let mut full = DVector::zeros(n);
+        for (i, &coupling) in flowing.iter().enumerate() {
+            full[coupling] = v[i];
+        }
+        full
+    };
+    
+    let mut eigenvalues: Vec<Complex<f64>> = if flowing.is_empty() {
+        Vec::new()
+    } else {
+        reduced.complex_eigenvalues().iter().cloned().collect()
+    };
+    eigenvalues.sort_by(|a, b| b.re.total_cmp(&a.re).then(b.im.total_cmp(&a.im)));
+    
+    let mut directions: Vec<ScalingDirection> = Vec::with_capacity(flowing.len());
+    for (i, &eigenvalue) in eigenvalues.iter().enumerate() {
+        let relevance = if eigenvalue.re > MARGINAL_TOLERANCE {
+            Relevance::Relevant
+        } else if eigenvalue.re < -MARGINAL_TOLERANCE {
+            Relevance::Irrelevant
+        } else {
+            Relevance::Marginal
+        };
+        
+        // A repeated real eigenvalue takes successive vectors from the same null space
+        let eigenvector = if eigenvalue.im.abs() > MARGINAL_TOLERANCE {
+            None
+        } else {
+            let repeated: Vec<&Complex<f64>> = eigenvalues[..i].iter()
+                .filter(|e| (*e - eigenvalue).norm_sqr().sqrt() <= MARGINAL_TOLERANCE)
+                .collect();
+            let shift = repeated.first().map_or(eigenvalue.re, |e| e.re);
+            null_vector(&reduced, shift, repeated.len()).map(embed)
+        };
+        
+        directions.push(ScalingDirection { eigenvalue, eigenvector, relevance });
+    }
+    
+    let count = |relevance| directions.iter().filter(|d| d.relevance == relevance).count();
+    // Nothing flows at all near a fixed point with only frozen couplings
+    let classification = if directions.is_empty() || count(Relevance::Marginal) > 0 {
+        "marginal"
+    } else if count(Relevance::Relevant) == 0 {
+        "stable"
+    } else if count(Relevance::Irrelevant) == 0 {
+        "unstable"
+    } else {
+        "saddle"
+    };
+    
+    let correlation_length_exponent = directions.first()
+        .filter(|d| d.relevance == Relevance::Relevant)
+        .map(|d| 1.0 / d.eigenvalue.re);
+    
+    Ok(RGFixedPoint {
+        parameters: fixed_point.clone(),
+        critical_exponents: directions.iter().map(|d| d.eigenvalue.re).collect(),
+        classification: classification.to_string(),
+        universality_class: None,
+        dimension,
+        stability_matrix,
+        directions,
+        frozen_couplings,
+        correlation_length_exponent,
+    })
+}
+
+/// The right singular vector of `matrix - eigenvalue I` with the `rank`-th
+/// smallest singular value, normalized
+fn null_vector(matrix: &DMatrix<f64>, eigenvalue: f64, rank: usize) -> Option<DVector<f64>> {
+    let n = matrix.nrows();
+    let shifted = matrix - DMatrix::identity(n, n) * eigenvalue;
+    let svd = shifted.svd(false, true);
+    let v_t = svd.v_t?;
+    
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| svd.singular_values[a].total_cmp(&svd.singular_values[b]));
+    let row = *order.get(rank)?;
+    
+    let vector: DVector<f64> = v_t.row(row).transpose();
+    Some(vector.normalize())
 }
 
 /// Trait representing a renormalization group flow
 pub trait RGFlow<P: ParameterSpace>: Debug {
     /// Get the spatial dimension this RG flow operates in
     fn spatial_dimension(&self) -> usize;
-
+    
     /// Perform a single RG transformation step
     fn step(&self, params: &P) -> Result<P, RGFlowError> {
         if !params.is_compatible_with_dimension(self.spatial_dimension()) {
@@ -117,11 +421,11 @@ pub trait RGFlow<P: ParameterSpace>: Debug {
         
         let mut current = initial.clone();
         
-        for _ in max_iterations {
+        for _ in 0..max_iterations {
             let next = self.do_step(&current)?;
             if next.distance(&current) < tolerance {
                 // We found a fixed point; now analyze it
-                return Ok(self.analyze_fixed_point(&next)?);
+                return self.analyze_fixed_point(&next);
             }
             current = next;
         }
@@ -134,8 +438,652 @@ pub trait RGFlow<P: ParameterSpace>: Debug {
     
     /// Get the beta function at a point in parameter space
     fn beta_function(&self, params: &P) -> Result<DVector<f64>, RGFlowError>;
+    
+    /// Jacobian ∂β_i/∂g_j of the beta function, by central differences
+    fn jacobian(&self, params: &P) -> Result<DMatrix<f64>, RGFlowError> {
+        numerical_jacobian(|p| self.beta_function(p), params, self.spatial_dimension())
+    }
+    
+    /// Find a zero of the beta function with damped Newton (Levenberg-Marquardt) steps.
+    ///
+    /// Unlike [`RGFlow::find_fixed_point`], this does not follow the flow and so
+    /// also finds unstable and saddle fixed points.
+    fn solve_fixed_point(
+        &self,
+        initial: &P,
+        options: &FixedPointSolverOptions,
+    ) -> Result<FixedPointSolution<P>, RGFlowError> {
+        if !initial.is_compatible_with_dimension(self.spatial_dimension()) {
+            return Err(RGFlowError::DimensionMismatch { 
+                expected: self.spatial_dimension(), 
+                actual: initial.spatial_dimension() 
+            });
+        }
+        
+        let mut current = initial.clone();
+        let mut beta = self.beta_function(&current)?;
+        let mut diagnostics = SolverDiagnostics {
+            residual_norm: beta.norm(),
+            damping: options.damping,
+            residual_history: vec![beta.norm()],
+            ..SolverDiagnostics::default()
+        };
+        let fail = |reason, diagnostics| Err(RGFlowError::SolverFailed { reason, diagnostics });
+        
+        loop {
+            if !diagnostics.residual_norm.is_finite() {
+                return fail(SolverFailure::NonFinite, diagnostics);
+            }
+            if diagnostics.residual_norm < options.tolerance {
+                return Ok(FixedPointSolution { parameters: current, diagnostics });
+            }
+            if diagnostics.iterations >= options.max_iterations {
+                return fail(SolverFailure::MaxIterations, diagnostics);
+            }
+            
+            let jacobian = self.jacobian(&current)?;
+            if jacobian.iter().any(|v| !v.is_finite()) {
+                return fail(SolverFailure::NonFinite, diagnostics);
+            }
+            let normal = jacobian.transpose() * &jacobian;
+            let gradient = jacobian.transpose() * &beta;
+            
+            // Raise the damping until a step reduces |β|
+            loop {
+                diagnostics.iterations += 1;
+                let damped = &normal + DMatrix::identity(normal.nrows(), normal.ncols()) * diagnostics.damping;
+                let step = damped.lu().solve(&(-&gradient));
+                
+                if let Some(step) = step.filter(|s| s.iter().all(|v| v.is_finite())) {
+                    let candidate = P::from_vector(current.as_vector() + &step, self.spatial_dimension())?;
+                    // A trial point where β cannot be evaluated is rejected like one that does not improve
+                    let candidate_beta = self.beta_function(&candidate).ok()
+                        .filter(|candidate_beta| candidate_beta.norm() < diagnostics.residual_norm);
+                    
+                    if let Some(candidate_beta) = candidate_beta {
+                        diagnostics.step_norm = step.norm();
+                        diagnostics.residual_norm = candidate_beta.norm();
+                        diagnostics.residual_history.push(diagnostics.residual_norm);
+                        diagnostics.damping /= options.damping_factor;
+                        current = candidate;
+                        beta = candidate_beta;
+                        
+                        if diagnostics.step_norm < options.step_tolerance
+                            && diagnostics.residual_norm >= options.tolerance
+                        {
+                            return fail(SolverFailure::Stalled, diagnostics);
+                        }
+                        break;
+                    }
+                }
+                
+                diagnostics.rejected_steps += 1;
+                diagnostics.damping = (diagnostics.damping * options.damping_factor).max(f64::EPSILON.sqrt());
+                if diagnostics.damping > options.max_damping {
+                    return fail(SolverFailure::DampingExhausted, diagnostics);
+                }
+                if diagnostics.iterations >= options.max_iterations {
+                    return fail(SolverFailure::MaxIterations, diagnostics);
+                }
+            }
+        }
+    }
+    
+    /// Find all fixed points in the box between `lower` and `upper` by running
+    /// [`RGFlow::solve_fixed_point`] from many initial guesses.
+    ///
+    /// Solutions within `options.cluster_tolerance` of each other are merged,
+    /// keeping the first found, and each distinct fixed point is analyzed.
+    fn find_fixed_points(
+        &self,
+        lower: &P,
+        upper: &P,
+        options: &FixedPointSearchOptions,
+    ) -> Result<FixedPointCatalogue<P>, RGFlowError> {
+        let seeds = seed_points(&lower.as_vector(), &upper.as_vector(), options.seeds)?;
+        let mut catalogue = FixedPointCatalogue {
+            fixed_points: Vec::new(),
+            hits: Vec::new(),
+            seeds: seeds.len(),
+            failures: 0,
+            outside: 0,
+        };
+        let mut solutions: Vec<P> = Vec::new();
+        let margin = options.cluster_tolerance;
+        
+        for seed in seeds {
+            let initial = P::from_vector(seed, self.spatial_dimension())?;
+            let solution = match self.solve_fixed_point(&initial, &options.solver) {
+                Ok(solution) => solution.parameters,
+                Err(RGFlowError::DimensionMismatch { expected, actual }) => {
+                    return Err(RGFlowError::DimensionMismatch { expected, actual });
+                }
+                Err(_) => {
+                    catalogue.failures += 1;
+                    continue;
+                }
+            };
+            
+            let x = solution.as_vector();
+            let inside = x.iter().zip(lower.as_vector().iter().zip(upper.as_vector().iter()))
+                .all(|(v, (lo, hi))| *v >= lo.min(*hi) - margin && *v <= lo.max(*hi) + margin);
+            if !inside {
+                catalogue.outside += 1;
+                continue;
+            }
+            
+            match solutions.iter().position(|known| known.distance(&solution) < options.cluster_tolerance) {
+                Some(index) => catalogue.hits[index] += 1,
+                None => {
+                    solutions.push(solution);
+                    catalogue.hits.push(1);
+                }
+            }
+        }
+        
+        for solution in &solutions {
+            catalogue.fixed_points.push(self.analyze_fixed_point(solution)?);
+        }
+        
+        Ok(catalogue)
+    }
+    
+    /// Integrate dg/dℓ = β(g) continuously from ℓ = 0 to `length`.
+    ///
+    /// A negative `length` runs the flow backwards, towards the UV. Integration
+    /// stops early at the first of `options.events` to fire, or if the flow
+    /// blows up; the reason is recorded in the returned trajectory.
+    fn integrate(
+        &self,
+        initial: &P,
+        length: f64,
+        options: &IntegrationOptions,
+    ) -> Result<FlowTrajectory<P>, RGFlowError> {
+        if !initial.is_compatible_with_dimension(self.spatial_dimension()) {
+            return Err(RGFlowError::DimensionMismatch { 
+                expected: self.spatial_dimension(), 
+                actual: initial.spatial_dimension() 
+            });
+        }
+        
+        let dim = self.spatial_dimension();
+        let n = initial.dimension();
+        for event in &options.events {
+            if event.parameter() >= n {
+                return Err(RGFlowError::ParameterOutOfRange(
+                    format!("Event on parameter {} of a {}-dimensional flow", event.parameter(), n)
+                ));
+            }
+        }
+        let beta = |v: &DVector<f64>| -> Result<DVector<f64>, RGFlowError> {
+            let b = self.beta_function(&P::from_vector(v.clone(), dim)?)?;
+            if b.len() != v.len() {
+                return Err(RGFlowError::DimensionMismatch { expected: v.len(), actual: b.len() });
+            }
+            Ok(b)
+        };
+        
+        let direction = if length < 0.0 { -1.0 } else { 1.0 };
+        let mut trajectory = FlowTrajectory {
+            scales: vec![0.0],
+            points: vec![initial.clone()],
+            termination: FlowTermination::MaxSteps,
+            steps: 0,
+            rejected_steps: 0,
+        };
+        let mut scale = 0.0;
+        let mut y = initial.as_vector();
+        let mut dy = beta(&y)?;
+        let mut h = direction * match options.method {
+            IntegrationMethod::RungeKutta4 { step } => step.abs(),
+            IntegrationMethod::DormandPrince { initial_step, .. } => initial_step.abs(),
+        };
+        let mut next_sample = 1;
+        
+        while trajectory.steps < options.max_steps {
+            let remaining = length - scale;
+            if remaining * direction <= 0.0 {
+                trajectory.termination = FlowTermination::Completed;
+                break;
+            }
+            let last_step = h.abs() >= remaining.abs();
+            if last_step {
+                h = remaining;
+            }
+            
+            let (y_new, dy_new, next_h) = match options.method {
+                IntegrationMethod::RungeKutta4 { .. } => {
+                    let y_new = runge_kutta_4(&beta, &y, &dy, h)?;
+                    if y_new.iter().any(|v| !v.is_finite()) {
+                        trajectory.termination = FlowTermination::NonFinite;
+                        break;
+                    }
+                    let dy_new = beta(&y_new)?;
+                    (y_new, dy_new, h)
+                }
+                IntegrationMethod::DormandPrince { min_step, max_step, relative_tolerance, absolute_tolerance, .. } => {
+                    let EmbeddedStep { solution: y_new, derivative: dy_new, error } = dormand_prince(&beta, &y, &dy, h)?;
+                    
+                    // RMS error relative to the mixed tolerance; NaN counts as a failure
+                    let error = (error.iter().zip(y.iter().zip(y_new.iter()))
+                        .map(|(e, (a, b))| (e / (absolute_tolerance + relative_tolerance * a.abs().max(b.abs()))).powi(2))
+                        .sum::<f64>() / n.max(1) as f64)
+                        .sqrt();
+                    let error = if error.is_nan() { f64::INFINITY } else { error };
+                    let factor = (0.9 * error.powf(-0.2)).clamp(0.2, 5.0);
+                    let next_h = direction * (h.abs() * factor).min(max_step);
+                    
+                    if error > 1.0 {
+                        trajectory.rejected_steps += 1;
+                        h = next_h;
+                        if h.abs() < min_step {
+                            trajectory.termination = FlowTermination::StepSizeTooSmall;
+                            break;
+                        }
+                        continue;
+                    }
+                    (y_new, dy_new, next_h)
+                }
+            };
+            trajectory.steps += 1;
+            let new_scale = if last_step { length } else { scale + h };
+            
+            // Locate the earliest event in this step on the cubic Hermite interpolant
+            let interpolate = |theta: f64| hermite(&y, &dy, &y_new, &dy_new, h, theta);
+            let fired = options.events.iter().enumerate()
+                .filter_map(|(index, event)| {
+                    let (before, after) = (event.value(&y), event.value(&y_new));
+                    let crosses = before * after < 0.0 || (after == 0.0 && before != 0.0);
+                    crosses.then(|| (index, bisect(|theta| event.value(&interpolate(theta)), before)))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            
+            let end = fired.map_or(1.0, |(_, theta)| theta);
+            match options.sample_interval {
+                Some(interval) if interval > 0.0 => {
+                    // Uniform samples falling inside this step
+                    loop {
+                        let sample = direction * next_sample as f64 * interval;
+                        let theta = (sample - scale) / h;
+                        if theta > end {
+                            break;
+                        }
+                        trajectory.scales.push(sample);
+                        trajectory.points.push(P::from_vector(interpolate(theta), dim)?);
+                        next_sample += 1;
+                    }
+                }
+                _ => {
+                    if fired.is_none() {
+                        trajectory.scales.push(new_scale);
+                        trajectory.points.push(P::from_vector(y_new.clone(), dim)?);
+                    }
+                }
+            }
+            
+            if let Some((index, theta)) = fired {
+                trajectory.scales.push(scale + theta * h);
+                trajectory.points.push(P::from_vector(interpolate(theta), dim)?);
+                trajectory.termination = FlowTermination::Event { index };
+                break;
+            }
+            
+            scale = new_scale;
+            y = y_new;
+            dy = dy_new;
+            h = next_h;
+        }
+        
+        // Always finish on the point where integration stopped
+        let recorded = trajectory.scales.last()
+            .is_some_and(|last| (last - scale).abs() <= f64::EPSILON * scale.abs().max(1.0));
+        if !recorded && !matches!(trajectory.termination, FlowTermination::Event { .. }) {
+            trajectory.scales.push(scale);
+            trajectory.points.push(P::from_vector(y, dim)?);
+        }
+        
+        Ok(trajectory)
+    }
+}
+
+/// Relative step used for finite-difference Jacobians
+const FINITE_DIFFERENCE_STEP: f64 = 1e-6;
+
+/// Jacobian of `beta` at `params` by central differences, with steps relative
+/// to the magnitude of each parameter
+pub fn numerical_jacobian<P: ParameterSpace>(
+    beta: impl Fn(&P) -> Result<DVector<f64>, RGFlowError>,
+    params: &P,
+    dim: usize,
+) -> Result<DMatrix<f64>, RGFlowError> {
+    let x = params.as_vector();
+    let n = x.len();
+    let mut jacobian = DMatrix::zeros(n, n);
+    
+    for j in 0..n {
+        let h = FINITE_DIFFERENCE_STEP * x[j].abs().max(1.0);
+        let mut forward = x.clone();
+        let mut backward = x.clone();
+        forward[j] += h;
+        backward[j] -= h;
+        
+        let beta_forward = beta(&P::from_vector(forward, dim)?)?;
+        let beta_backward = beta(&P::from_vector(backward, dim)?)?;
+        if beta_forward.len() != n || beta_backward.len() != n {
+            return Err(RGFlowError::DimensionMismatch { expected: n, actual: beta_forward.len() });
+        }
+        jacobian.set_column(j, &((beta_forward - beta_backward) / (2.0 * h)));
+    }
+    
+    Ok(jacobian)
+}
+
+/// Number of Runge-Kutta substeps taken by [`rescale_by`]
+pub const RESCALING_SUBSTEPS: usize = 16;
+
+/// Coarse-grain `params` by a factor `scale` in length by integrating `beta`
+/// over ℓ = ln(scale).
+///
+/// Step functions built on this agree with the continuous flow of the same
+/// beta function, whatever the spatial dimension of `params`.
+pub fn rescale_by<P: ParameterSpace>(
+    beta: impl Fn(&P) -> Result<DVector<f64>, RGFlowError>,
+    params: &P,
+    scale: f64,
+) -> Result<P, RGFlowError> {
+    if scale.is_nan() || scale <= 0.0 {
+        return Err(RGFlowError::ParameterOutOfRange(
+            format!("Rescaling factor must be positive, got {}", scale)
+        ));
+    }
+    
+    let dim = params.spatial_dimension();
+    let f = |v: &DVector<f64>| beta(&P::from_vector(v.clone(), dim)?);
+    let h = scale.ln() / RESCALING_SUBSTEPS as f64;
+    let mut v = params.as_vector();
+    for _ in 0..RESCALING_SUBSTEPS {
+        let dv = f(&v)?;
+        v = runge_kutta_4(&f, &v, &dv, h)?;
+    }
+    
+    P::from_vector(v, dim)
+}
+
+/// Initial guesses in the box between `lower` and `upper` placed by `strategy`.
+///
+/// Fails if a bound is not finite, `upper` lies below `lower` on some axis, or
+/// a grid has more points than can be counted.
+pub fn seed_points(
+    lower: &DVector<f64>,
+    upper: &DVector<f64>,
+    strategy: SeedStrategy,
+) -> Result<Vec<DVector<f64>>, RGFlowError> {
+    if lower.len() != upper.len() {
+        return Err(RGFlowError::DimensionMismatch { expected: lower.len(), actual: upper.len() });
+    }
+    for (i, (lo, hi)) in lower.iter().zip(upper.iter()).enumerate() {
+        if !lo.is_finite() || !hi.is_finite() || hi < lo {
+            return Err(RGFlowError::ParameterOutOfRange(format!(
+                "Seed box along axis {} runs from {} to {}", i, lo, hi
+            )));
+        }
+    }
+    let n = lower.len();
+    let width = upper - lower;
+    
+    let seeds = match strategy {
+        SeedStrategy::Grid { points_per_axis } => {
+            // Axes of zero width get a single point; the rest are enumerated like an odometer
+            let counts: Vec<usize> = width.iter()
+                .map(|w| if *w == 0.0 { 1 } else { points_per_axis.max(1) })
+                .collect();
+            let total = counts.iter().try_fold(1usize, |total, &count| total.checked_mul(count))
+                .ok_or_else(|| RGFlowError::ParameterOutOfRange(format!(
+                    "Grid of {} points per axis in {} dimensions is too large", points_per_axis, n
+                )))?;
+            (0..total).map(|mut index| {
+                DVector::from_fn(n, |i, _| {
+                    let k = index % counts[i];
+                    index /= counts[i];
+                    let fraction = if counts[i] > 1 { k as f64 / (counts[i] - 1) as f64 } else { 0.5 };
+                    lower[i] + fraction * width[i]
+                })
+            }).collect()
+        }
+        SeedStrategy::LatinHypercube { samples, seed } => {
+            // One sample in each of `samples` slices of every axis, paired at random
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut points = vec![DVector::zeros(n); samples];
+            for i in 0..n {
+                let mut slices: Vec<usize> = (0..samples).collect();
+                slices.shuffle(&mut rng);
+                for (point, slice) in points.iter_mut().zip(slices) {
+                    point[i] = lower[i] + (slice as f64 + rng.gen::<f64>()) / samples as f64 * width[i];
+                }
+            }
+            points
+        }
+        SeedStrategy::Random { samples, seed } => {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..samples)
+                .map(|_| DVector::from_fn(n, |i, _| lower[i] + rng.gen::<f64>() * width[i]))
+                .collect()
+        }
+    };
+    
+    Ok(seeds)
+}
+
+/// Runge-Kutta scheme used by [`RGFlow::integrate`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IntegrationMethod {
+    /// Classical fourth-order Runge-Kutta with a fixed step in ℓ
+    RungeKutta4 { step: f64 },
+    
+    /// Dormand-Prince 5(4) embedded pair with step-size control
+    DormandPrince {
+        initial_step: f64,
+        min_step: f64,
+        max_step: f64,
+        relative_tolerance: f64,
+        absolute_tolerance: f64,
+    },
+}
+
+impl IntegrationMethod {
+    /// Dormand-Prince with tolerances suited to smooth beta functions
+    pub fn adaptive() -> Self {
+        IntegrationMethod::DormandPrince {
+            initial_step: 1e-2,
+            min_step: 1e-12,
+            max_step: 1.0,
+            relative_tolerance: 1e-8,
+            absolute_tolerance: 1e-10,
+        }
+    }
+}
+
+/// A condition on one coupling g_i that stops [`RGFlow::integrate`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FlowEvent {
+    /// g_i changes sign
+    CrossesZero { parameter: usize },
+    
+    /// g_i passes through `value`
+    Crosses { parameter: usize, value: f64 },
+    
+    /// |g_i| grows past `threshold`
+    Diverges { parameter: usize, threshold: f64 },
+}
+
+impl FlowEvent {
+    /// Index of the coupling this event watches
+    pub fn parameter(&self) -> usize {
+        match *self {
+            FlowEvent::CrossesZero { parameter }
+            | FlowEvent::Crosses { parameter, .. }
+            | FlowEvent::Diverges { parameter, .. } => parameter,
+        }
+    }
+    
+    /// A function of the couplings that changes sign when the event fires
+    fn value(&self, couplings: &DVector<f64>) -> f64 {
+        match *self {
+            FlowEvent::CrossesZero { parameter } => couplings[parameter],
+            FlowEvent::Crosses { parameter, value } => couplings[parameter] - value,
+            FlowEvent::Diverges { parameter, threshold } => couplings[parameter].abs() - threshold,
+        }
+    }
+}
+
+/// Options for [`RGFlow::integrate`]
+#[derive(Clone, Debug)]
+pub struct IntegrationOptions {
+    /// Integration scheme
+    pub method: IntegrationMethod,
+    
+    /// Record the trajectory every this much ℓ; `None` records every accepted step
+    pub sample_interval: Option<f64>,
+    
+    /// Conditions that end the integration early
+    pub events: Vec<FlowEvent>,
+    
+    /// Give up after this many accepted steps
+    pub max_steps: usize,
+}
+
+impl Default for IntegrationOptions {
+    fn default() -> Self {
+        Self {
+            method: IntegrationMethod::adaptive(),
+            sample_interval: None,
+            events: Vec::new(),
+            max_steps: 100_000,
+        }
+    }
+}
+
+/// Why [`RGFlow::integrate`] stopped
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FlowTermination {
+    /// Reached the requested ℓ
+    Completed,
+    
+    /// The event at this index in [`IntegrationOptions::events`] fired
+    Event { index: usize },
+    
+    /// The adaptive step shrank below its minimum, as at a divergence at finite ℓ
+    StepSizeTooSmall,
+    
+    /// The couplings stopped being finite
+    NonFinite,
+    
+    /// Ran out of steps
+    MaxSteps,
+}
+
+/// A continuous RG trajectory sampled in the log-scale ℓ
+#[derive(Clone, Debug)]
+pub struct FlowTrajectory<P: ParameterSpace> {
+    /// Values of ℓ at which the flow was sampled, starting at 0
+    pub scales: Vec<f64>,
+    
+    /// Couplings at each of `scales`
+    pub points: Vec<P>,
+    
+    /// Why integration stopped
+    pub termination: FlowTermination,
+    
+    /// Number of accepted steps
+    pub steps: usize,
+    
+    /// Number of steps rejected by the error control
+    pub rejected_steps: usize,
+}
+
+/// One classical Runge-Kutta step of size `h`, given `dy = f(y)`
+fn runge_kutta_4(
+    f: &impl Fn(&DVector<f64>) -> Result<DVector<f64>, RGFlowError>,
+    y: &DVector<f64>,
+    dy: &DVector<f64>,
+    h: f64,
+) -> Result<DVector<f64>, RGFlowError> {
+    let k1 = dy;
+    let k2 = f(&(y + k1 * (h / 2.0)))?;
+    let k3 = f(&(y + &k2 * (h / 2.0)))?;
+    let k4 = f(&(y + &k3 * h))?;
+    Ok(y + (k1 + k2 * 2.0 + k3 * 2.0 + k4) * (h / 6.0))
+}
+
+/// Result of a step of an embedded Runge-Kutta pair
+struct EmbeddedStep {
+    /// Higher-order solution at the end of the step
+    solution: DVector<f64>,
+    
+    /// Derivative at `solution`
+    derivative: DVector<f64>,
+    
+    /// Difference between the higher- and lower-order solutions
+    error: DVector<f64>,
+}
+
+/// One Dormand-Prince step of size `h`, given `dy = f(y)`
+fn dormand_prince(
+    f: &impl Fn(&DVector<f64>) -> Result<DVector<f64>, RGFlowError>,
+    y: &DVector<f64>,
+    dy: &DVector<f64>,
+    h: f64,
+) -> Result<EmbeddedStep, RGFlowError> {
+    let k1 = dy;
+    let k2 = f(&(y + k1 * (h / 5.0)))?;
+    let k3 = f(&(y + (k1 * (3.0 / 40.0) + &k2 * (9.0 / 40.0)) * h))?;
+    let k4 = f(&(y + (k1 * (44.0 / 45.0) - &k2 * (56.0 / 15.0) + &k3 * (32.0 / 9.0)) * h))?;
+    let k5 = f(&(y + (k1 * (19372.0 / 6561.0) - &k2 * (25360.0 / 2187.0) + &k3 * (64448.0 / 6561.0)
+        - &k4 * (212.0 / 729.0)) * h))?;
+    let k6 = f(&(y + (k1 * (9017.0 / 3168.0) - &k2 * (355.0 / 33.0) + &k3 * (46732.0 / 5247.0)
+        + &k4 * (49.0 / 176.0) - &k5 * (5103.0 / 18656.0)) * h))?;
+    let y_new = y + (k1 * (35.0 / 384.0) + &k3 * (500.0 / 1113.0) + &k4 * (125.0 / 192.0)
+        - &k5 * (2187.0 / 6784.0) + &k6 * (11.0 / 84.0)) * h;
+    let k7 = f(&y_new)?;
+    let error = (k1 * (71.0 / 57600.0) - &k3 * (71.0 / 16695.0) + &k4 * (71.0 / 1920.0)
+        - &k5 * (17253.0 / 339200.0) + &k6 * (22.0 / 525.0) - &k7 * (1.0 / 40.0)) * h;
+    Ok(EmbeddedStep { solution: y_new, derivative: k7, error })
+}
+
+/// Cubic Hermite interpolation across a step of size `h` at fraction `theta`
+fn hermite(
+    y0: &DVector<f64>,
+    dy0: &DVector<f64>,
+    y1: &DVector<f64>,
+    dy1: &DVector<f64>,
+    h: f64,
+    theta: f64,
+) -> DVector<f64> {
+    let t2 = theta * theta;
+    let t3 = t2 * theta;
+    y0 * (2.0 * t3 - 3.0 * t2 + 1.0)
+        + dy0 * (h * (t3 - 2.0 * t2 + theta))
+        + y1 * (3.0 * t2 - 2.0 * t3)
+        + dy1 * (h * (t3 - t2))
 }
 
+/// Root of `value` on [0, 1] by bisection, given its sign at 0
+fn bisect(value: impl Fn(f64) -> f64, at_start: f64) -> f64 {
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..60 {
+        let mid = 0.5 * (lo + hi);
+        if value(mid) * at_start > 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    hi
+}
+
+/// Analytic Jacobian of a beta function
+pub type JacobianFn<P> = fn(&P) -> Result<DMatrix<f64>, RGFlowError>;
+
 /// A concrete implementation of RG flow
 #[derive(Debug)]
 pub struct ConcreteRGFlow<P: ParameterSpace, C: Category, F: Functor> 
@@ -158,6 +1106,9 @@ where
     /// Implementation of the beta function
     beta_fn: fn(&P) -> Result<DVector<f64>, RGFlowError>,
     
+    /// Analytic Jacobian of the beta function, if known
+    jacobian_fn: Option<JacobianFn<P>>,
+    
     /// Spatial dimension
     dimension: usize,
     
@@ -184,11 +1135,17 @@ where
             functor,
             step_fn,
             beta_fn,
+            jacobian_fn: None,
             dimension,
             _phantom: PhantomData,
         }
     }
     
+    /// Get the name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    
     /// Get the category
     pub fn category(&self) -> &C {
         &self.category
@@ -198,6 +1155,12 @@ where
     pub fn functor(&self) -> &F {
         &self.functor
     }
+    
+    /// Use an analytic Jacobian of the beta function instead of finite differences
+    pub fn with_jacobian(mut self, jacobian_fn: JacobianFn<P>) -> Self {
+        self.jacobian_fn = Some(jacobian_fn);
+        self
+    }
 }
 
 impl<P: ParameterSpace, C: Category, F: Functor> RGFlow<P> for ConcreteRGFlow<P, C, F> 
@@ -214,23 +1177,9 @@ where
     }
     
     fn analyze_fixed_point(&self, fixed_point: &P) -> Result<RGFixedPoint<P>, RGFlowError> {
-        // Calculate beta function and its Jacobian at the fixed point
-        let beta = self.beta_function(fixed_point)?;
-        
-        // In a real implementation we would:
-        // 1. Calculate the Jacobian 
-        // 2. Find eigenvalues to determine critical exponents
-        // 3. Classify the fixed point as stable/unstable/saddle
-        // 4. Identify the universality class
-        
-        // Simplified example:
-        Ok(RGFixedPoint {
-            parameters: fixed_point.clone(),
-            critical_exponents: vec![0.1, 0.2], // Placeholder values
-            classification: "stable".to_string(),
-            universality_class: Some("Ising".to_string()),
-            dimension: self.dimension,
-        })
+        // Linearize the beta function about the fixed point
+        let stability_matrix = self.jacobian(fixed_point)?;
+        linearized_stability(fixed_point, stability_matrix, self.dimension)
     }
     
     fn beta_function(&self, params: &P) -> Result<DVector<f64>, RGFlowError> {
@@ -242,4 +1191,364 @@ where
         }
         (self.beta_fn)(params)
     }
+    
+    fn jacobian(&self, params: &P) -> Result<DMatrix<f64>, RGFlowError> {
+        match self.jacobian_fn {
+            Some(jacobian_fn) => {
+                if !params.is_compatible_with_dimension(self.dimension) {
+                    return Err(RGFlowError::DimensionMismatch { 
+                        expected: self.dimension, 
+                        actual: params.spatial_dimension() 
+                    });
+                }
+                jacobian_fn(params)
+            }
+            None => numerical_jacobian(|p| self.beta_function(p), params, self.dimension),
+        }
+    }
+}
+
+/// Plain coupling vectors and flows given by functions, for tests
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::*;
+    
+    /// Couplings with no further structure, in three spatial dimensions
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Couplings(pub DVector<f64>);
+    
+    impl Couplings {
+        pub fn new(values: &[f64]) -> Self {
+            Self(DVector::from_column_slice(values))
+        }
+    }
+    
+    impl ParameterSpace for Couplings {
+        fn dimension(&self) -> usize {
+            self.0.len()
+        }
+        
+        fn spatial_dimension(&self) -> usize {
+            3
+        }
+        
+        fn as_vector(&self) -> DVector<f64> {
+            self.0.clone()
+        }
+        
+        fn from_vector(vec: DVector<f64>, _dim: usize) -> Result<Self, RGFlowError> {
+            Ok(Self(vec))
+        }
+        
+        fn distance(&self, other: &Self) -> f64 {
+            (&self.0 - &other.0).norm()
+        }
+    }
+    
+    /// The flow dg/dℓ = beta(g)
+    #[derive(Debug)]
+    pub struct Flow {
+        pub beta: fn(&DVector<f64>) -> DVector<f64>,
+    }
+    
+    impl RGFlow<Couplings> for Flow {
+        fn spatial_dimension(&self) -> usize {
+            3
+        }
+        
+        fn do_step(&self, params: &Couplings) -> Result<Couplings, RGFlowError> {
+            Ok(Couplings(&params.0 + self.beta_function(params)?))
+        }
+        
+        fn analyze_fixed_point(&self, fixed_point: &Couplings) -> Result<RGFixedPoint<Couplings>, RGFlowError> {
+            linearized_stability(fixed_point, self.jacobian(fixed_point)?, 3)
+        }
+        
+        fn beta_function(&self, params: &Couplings) -> Result<DVector<f64>, RGFlowError> {
+            Ok((self.beta)(&params.0))
+        }
+    }
+    
+    /// β = (y g₁(1 - g₁), -g₂, 0) with y = 3/2: a saddle at g₁ = 0 with ν = 2/3
+    /// and a stable point at g₁ = 1, for any value of the frozen coupling g₃
+    pub fn saddle_flow() -> Flow {
+        Flow {
+            beta: |g| DVector::from_vec(vec![1.5 * g[0] * (1.0 - g[0]), -g[1], 0.0]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// A single coupling g
+    #[derive(Clone, Debug)]
+    struct Coupling(f64);
+    
+    impl ParameterSpace for Coupling {
+        fn dimension(&self) -> usize {
+            1
+        }
+        
+        fn spatial_dimension(&self) -> usize {
+            3
+        }
+        
+        fn as_vector(&self) -> DVector<f64> {
+            DVector::from_element(1, self.0)
+        }
+        
+        fn from_vector(vec: DVector<f64>, _dim: usize) -> Result<Self, RGFlowError> {
+            Ok(Coupling(vec[0]))
+        }
+        
+        fn distance(&self, other: &Self) -> f64 {
+            (self.0 - other.0).abs()
+        }
+    }
+    
+    /// dg/dℓ = λ g, solved by g(ℓ) = g(0) e^{λℓ}
+    #[derive(Debug)]
+    struct Exponential {
+        rate: f64,
+    }
+    
+    impl RGFlow<Coupling> for Exponential {
+        fn spatial_dimension(&self) -> usize {
+            3
+        }
+        
+        fn do_step(&self, params: &Coupling) -> Result<Coupling, RGFlowError> {
+            Ok(Coupling(params.0 + self.beta_function(params)?[0]))
+        }
+        
+        fn analyze_fixed_point(&self, fixed_point: &Coupling) -> Result<RGFixedPoint<Coupling>, RGFlowError> {
+            linearized_stability(fixed_point, self.jacobian(fixed_point)?, 3)
+        }
+        
+        fn beta_function(&self, params: &Coupling) -> Result<DVector<f64>, RGFlowError> {
+            Ok(DVector::from_element(1, self.rate * params.0))
+        }
+    }
+    
+    #[test]
+    fn dormand_prince_follows_exponential() {
+        let flow = Exponential { rate: 0.7 };
+        let every_step = IntegrationOptions::default();
+        let sampled = IntegrationOptions { sample_interval: Some(0.5), ..Default::default() };
+        
+        // Accepted steps meet the tolerances; samples in between are cubic interpolants
+        for (options, tolerance) in [(every_step, 1e-7), (sampled, 1e-5)] {
+            for length in [5.0, -5.0] {
+                let trajectory = flow.integrate(&Coupling(1.0), length, &options).unwrap();
+                assert_eq!(trajectory.termination, FlowTermination::Completed);
+                assert!((trajectory.scales.last().unwrap() - length).abs() < 1e-12);
+                
+                for (scale, point) in trajectory.scales.iter().zip(&trajectory.points) {
+                    let exact = (flow.rate * scale).exp();
+                    assert!((point.0 - exact).abs() <= tolerance * exact, "g({}) = {}, expected {}", scale, point.0, exact);
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn runge_kutta_is_fourth_order_on_exponential() {
+        let flow = Exponential { rate: 0.7 };
+        let error = |step: f64| {
+            let options = IntegrationOptions { method: IntegrationMethod::RungeKutta4 { step }, ..Default::default() };
+            let trajectory = flow.integrate(&Coupling(1.0), 5.0, &options).unwrap();
+            assert_eq!(trajectory.termination, FlowTermination::Completed);
+            
+            trajectory.scales.iter().zip(&trajectory.points)
+                .map(|(scale, point)| (point.0 / (flow.rate * scale).exp() - 1.0).abs())
+                .fold(0.0, f64::max)
+        };
+        
+        // Halving the step cuts the error sixteenfold
+        let (coarse, fine) = (error(0.1), error(0.05));
+        assert!(coarse < 1e-5, "relative error {}", coarse);
+        assert!((coarse / fine - 16.0).abs() < 1.0, "error ratio {}", coarse / fine);
+    }
+    
+    #[test]
+    fn events_stop_the_flow_where_they_fire() {
+        // g₀ = cos ℓ, g₁ = -sin ℓ
+        let rotation = fixtures::Flow { beta: |g| DVector::from_vec(vec![g[1], -g[0]]) };
+        let start = fixtures::Couplings::new(&[1.0, 0.0]);
+        let methods = [IntegrationMethod::RungeKutta4 { step: 0.1 }, IntegrationMethod::adaptive()];
+        
+        for method in methods {
+            // The zero crossing lies inside a step, so it is found on the interpolant
+            let options = IntegrationOptions {
+                method,
+                events: vec![FlowEvent::CrossesZero { parameter: 0 }],
+                ..Default::default()
+            };
+            let trajectory = rotation.integrate(&start, 10.0, &options).unwrap();
+            assert_eq!(trajectory.termination, FlowTermination::Event { index: 0 });
+            let (scale, point) = (trajectory.scales.last().unwrap(), trajectory.points.last().unwrap());
+            assert!((scale - std::f64::consts::FRAC_PI_2).abs() < 1e-5, "crossed zero at ℓ = {}", scale);
+            assert!(point.0[0].abs() < 1e-5 && (point.0[1] + 1.0).abs() < 1e-5);
+            assert!(trajectory.scales.windows(2).all(|pair| pair[0] < pair[1]));
+            
+            // The earliest of several events wins, whatever its position in the list
+            let options = IntegrationOptions {
+                method,
+                events: vec![FlowEvent::CrossesZero { parameter: 0 }, FlowEvent::Crosses { parameter: 0, value: 0.5 }],
+                sample_interval: Some(0.25),
+                ..Default::default()
+            };
+            let trajectory = rotation.integrate(&start, 10.0, &options).unwrap();
+            assert_eq!(trajectory.termination, FlowTermination::Event { index: 1 });
+            let scale = trajectory.scales.last().unwrap();
+            assert!((scale - std::f64::consts::FRAC_PI_3).abs() < 1e-5, "crossed 1/2 at ℓ = {}", scale);
+            assert_eq!(&trajectory.scales[..trajectory.scales.len() - 1], &[0.0, 0.25, 0.5, 0.75, 1.0]);
+        }
+        
+        // dg/dℓ = g² blows up at ℓ = 1, passing |g| = 10 at ℓ = 0.9; backwards it decays
+        let blow_up = fixtures::Flow { beta: |g| DVector::from_element(1, g[0] * g[0]) };
+        let options = IntegrationOptions {
+            method: IntegrationMethod::RungeKutta4 { step: 1e-3 },
+            events: vec![FlowEvent::Diverges { parameter: 0, threshold: 10.0 }],
+            ..Default::default()
+        };
+        let trajectory = blow_up.integrate(&fixtures::Couplings::new(&[1.0]), 2.0, &options).unwrap();
+        assert_eq!(trajectory.termination, FlowTermination::Event { index: 0 });
+        assert!((trajectory.scales.last().unwrap() - 0.9).abs() < 1e-6);
+        assert!((trajectory.points.last().unwrap().0[0] - 10.0).abs() < 1e-6);
+        
+        let trajectory = blow_up.integrate(&fixtures::Couplings::new(&[1.0]), -2.0, &options).unwrap();
+        assert_eq!(trajectory.termination, FlowTermination::Completed);
+        assert!((trajectory.points.last().unwrap().0[0] - 1.0 / 3.0).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn seed_points_cover_the_box_and_reject_bad_ones() {
+        let lower = DVector::from_vec(vec![0.0, 1.0, 2.0]);
+        let upper = DVector::from_vec(vec![1.0, 1.0, 4.0]);
+        
+        // The flat middle axis gets a single point
+        let grid = seed_points(&lower, &upper, SeedStrategy::Grid { points_per_axis: 3 }).unwrap();
+        assert_eq!(grid.len(), 9);
+        assert!(grid.iter().all(|p| p[1] == 1.0));
+        assert!(grid.iter().any(|p| p[0] == 0.0 && p[2] == 2.0) && grid.iter().any(|p| p[0] == 1.0 && p[2] == 4.0));
+        
+        // Each eighth of the last axis holds one sample of the hypercube
+        let hypercube = seed_points(&lower, &upper, SeedStrategy::LatinHypercube { samples: 8, seed: 1 }).unwrap();
+        let mut slices: Vec<usize> = hypercube.iter().map(|p| ((p[2] - 2.0) / 2.0 * 8.0) as usize).collect();
+        slices.sort();
+        assert_eq!(slices, (0..8).collect::<Vec<_>>());
+        
+        let inverted = seed_points(&upper, &lower, SeedStrategy::Random { samples: 4, seed: 1 });
+        assert!(matches!(inverted, Err(RGFlowError::ParameterOutOfRange(_))));
+        
+        let wide_lower = DVector::zeros(64);
+        let wide_upper = DVector::from_element(64, 1.0);
+        let huge = seed_points(&wide_lower, &wide_upper, SeedStrategy::Grid { points_per_axis: 4 });
+        assert!(matches!(huge, Err(RGFlowError::ParameterOutOfRange(_))));
+    }
+    
+    #[test]
+    fn saddle_is_classified_apart_from_frozen_couplings() {
+        let flow = fixtures::saddle_flow();
+        let saddle = flow.analyze_fixed_point(&fixtures::Couplings::new(&[0.0, 0.0, 0.7])).unwrap();
+        assert_eq!(saddle.classification, "saddle");
+        assert_eq!(saddle.frozen_couplings, vec![2]);
+        assert_eq!(saddle.directions.len(), 2);
+        assert!((saddle.critical_exponents[0] - 1.5).abs() < 1e-6 && (saddle.critical_exponents[1] + 1.0).abs() < 1e-6);
+        assert!((saddle.correlation_length_exponent.unwrap() - 2.0 / 3.0).abs() < 1e-6);
+        
+        let relevant = saddle.directions[0].eigenvector.as_ref().unwrap();
+        assert!((relevant[0].abs() - 1.0).abs() < 1e-9 && relevant[2] == 0.0);
+        
+        let stable = flow.analyze_fixed_point(&fixtures::Couplings::new(&[1.0, 0.0, 0.7])).unwrap();
+        assert_eq!(stable.classification, "stable");
+        assert_eq!(stable.correlation_length_exponent, None);
+    }
+    
+    #[test]
+    fn newton_solver_converges_to_nearest_zero_and_reports_failures() {
+        let flow = fixtures::saddle_flow();
+        let options = FixedPointSolverOptions::default();
+        
+        let solution = flow.solve_fixed_point(&fixtures::Couplings::new(&[0.8, 0.3, 0.7]), &options).unwrap();
+        assert!(solution.parameters.distance(&fixtures::Couplings::new(&[1.0, 0.0, 0.7])) < 1e-9);
+        let history = &solution.diagnostics.residual_history;
+        assert!(history.windows(2).all(|pair| pair[1] < pair[0]));
+        assert!(solution.diagnostics.residual_norm < options.tolerance);
+        
+        // β = 1 + g² has no real zero; the damped steps only creep towards its minimum
+        let no_zero = fixtures::Flow { beta: |g| DVector::from_element(1, 1.0 + g[0] * g[0]) };
+        let failure = no_zero.solve_fixed_point(&fixtures::Couplings::new(&[0.5]), &options);
+        match failure {
+            Err(RGFlowError::SolverFailed { diagnostics, .. }) => {
+                assert!(diagnostics.residual_norm >= 1.0 && diagnostics.residual_norm < 1.25);
+            }
+            other => panic!("expected a solver failure, got {:?}", other),
+        }
+    }
+    
+    /// β(g) = √g - 1, undefined for negative g
+    #[derive(Debug)]
+    struct SquareRoot;
+    
+    impl RGFlow<Coupling> for SquareRoot {
+        fn spatial_dimension(&self) -> usize {
+            3
+        }
+        
+        fn do_step(&self, params: &Coupling) -> Result<Coupling, RGFlowError> {
+            Ok(Coupling(params.0 + self.beta_function(params)?[0]))
+        }
+        
+        fn analyze_fixed_point(&self, fixed_point: &Coupling) -> Result<RGFixedPoint<Coupling>, RGFlowError> {
+            linearized_stability(fixed_point, self.jacobian(fixed_point)?, 3)
+        }
+        
+        fn beta_function(&self, params: &Coupling) -> Result<DVector<f64>, RGFlowError> {
+            if params.0 < 0.0 {
+                return Err(RGFlowError::ParameterOutOfRange(format!("g = {} is negative", params.0)));
+            }
+            Ok(DVector::from_element(1, params.0.sqrt() - 1.0))
+        }
+    }
+    
+    #[test]
+    fn newton_solver_backs_off_from_points_outside_the_domain() {
+        // From g = 9 the Newton step lands on g = -3, where β is undefined
+        let solution = SquareRoot.solve_fixed_point(&Coupling(9.0), &FixedPointSolverOptions::default()).unwrap();
+        assert!((solution.parameters.0 - 1.0).abs() < 1e-9);
+        assert!(solution.diagnostics.rejected_steps >= 1);
+        
+        // Starting outside the domain is still an error
+        let outside = SquareRoot.solve_fixed_point(&Coupling(-1.0), &FixedPointSolverOptions::default());
+        assert!(matches!(outside, Err(RGFlowError::ParameterOutOfRange(_))));
+    }
+    
+    #[test]
+    fn fixed_point_search_merges_seeds_into_distinct_points() {
+        let flow = fixtures::saddle_flow();
+        // Flat along the frozen coupling, which would otherwise give a line of fixed points
+        let lower = fixtures::Couplings::new(&[-0.5, -1.0, 0.7]);
+        let upper = fixtures::Couplings::new(&[1.5, 1.0, 0.7]);
+        let options = FixedPointSearchOptions {
+            seeds: SeedStrategy::Grid { points_per_axis: 5 },
+            ..FixedPointSearchOptions::default()
+        };
+        
+        let catalogue = flow.find_fixed_points(&lower, &upper, &options).unwrap();
+        assert_eq!(catalogue.seeds, 25);
+        assert_eq!(catalogue.hits.iter().sum::<usize>() + catalogue.failures + catalogue.outside, 25);
+        
+        let mut found: Vec<(f64, String)> = catalogue.fixed_points.iter()
+            .map(|fixed| (fixed.parameters.0[0], fixed.classification.clone()))
+            .collect();
+        found.sort_by(|a, b| a.0.total_cmp(&b.0));
+        assert_eq!(found.len(), 2);
+        assert!(found[0].0.abs() < 1e-6 && found[0].1 == "saddle");
+        assert!((found[1].0 - 1.0).abs() < 1e-6 && found[1].1 == "stable");
+        assert!(catalogue.fixed_points.iter().all(|fixed| fixed.parameters.0[1].abs() < 1e-6 && fixed.parameters.0[2] == 0.7));
+    }
 }
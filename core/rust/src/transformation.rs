@@ -0,0 +1,367 @@
+use crate::category::{CategoryError, Morphism, Object};
+use crate::microscopic::QTensor;
+use nalgebra::{DMatrix, Matrix3, Rotation3, Unit, Vector3};
+use std::fmt;
+
+/// A physical transformation carried by a morphism between configurations
+#[derive(Clone, Debug, PartialEq)]
+pub enum Transformation {
+    /// Leave the configuration unchanged
+    Identity,
+    
+    /// Rotate the configuration by `angle` radians about `axis` through its
+    /// centre, moving sites and defects along with the directors. A lattice
+    /// only admits the rotations that map it onto itself.
+    Rotation { axis: Vector3<f64>, angle: f64 },
+    
+    /// Shift the configuration by `offset` in physical units, with periodic wrapping
+    Translation { offset: Vector3<f64> },
+    
+    /// Change the temperature by `delta`
+    TemperatureChange { delta: f64 },
+    
+    /// Apply a uniform external field, replacing any previous one
+    FieldApplication { field: Vector3<f64> },
+    
+    /// Move each defect by the displacement at the same position in the list
+    DefectMotion { displacements: Vec<Vector3<f64>> },
+    
+    /// Average over blocks of `factor` sites along each axis
+    CoarseGraining { factor: usize },
+    
+    /// Add `residual[i]` to the Q-tensor at site i, such as to restore the
+    /// detail lost by coarse-graining. Built between two configurations by
+    /// [`ConfigurationMorphism::reconstruction`].
+    Reconstruction { residual: Vec<DMatrix<f64>> },
+}
+
+impl Transformation {
+    /// Name of this kind of transformation
+    pub fn name(&self) -> &'static str {
+        match self {
+            Transformation::Identity => "Identity",
+            Transformation::Rotation { .. } => "Rotation",
+            Transformation::Translation { .. } => "Translation",
+            Transformation::TemperatureChange { .. } => "TemperatureChange",
+            Transformation::FieldApplication { .. } => "FieldApplication",
+            Transformation::DefectMotion { .. } => "DefectMotion",
+            Transformation::CoarseGraining { .. } => "CoarseGraining",
+            Transformation::Reconstruction { .. } => "Reconstruction",
+        }
+    }
+    
+    /// Numerical parameters of this transformation, flattened.
+    ///
+    /// A reconstruction is fixed by the endpoints of its morphism, so its
+    /// residual is left out.
+    pub fn parameters(&self) -> Vec<f64> {
+        match self {
+            Transformation::Identity | Transformation::Reconstruction { .. } => Vec::new(),
+            Transformation::Rotation { axis, angle } => vec![axis.x, axis.y, axis.z, *angle],
+            Transformation::Translation { offset } => offset.iter().cloned().collect(),
+            Transformation::TemperatureChange { delta } => vec![*delta],
+            Transformation::FieldApplication { field } => field.iter().cloned().collect(),
+            Transformation::DefectMotion { displacements } => {
+                displacements.iter().flat_map(|d| d.iter().cloned()).collect()
+            }
+            Transformation::CoarseGraining { factor } => vec![*factor as f64],
+        }
+    }
+    
+    /// The error for a transformation that has no action on some kind of configuration
+    pub fn unsupported(&self, target: &str) -> CategoryError {
+        CategoryError::InvalidApplication(format!("{} cannot act on {}", self, target))
+    }
+}
+
+impl fmt::Display for Transformation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parameters = self.parameters().iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}({})", self.name(), parameters)
+    }
+}
+
+/// Morphisms made of a transformation between two configurations of Q-tensors
+pub trait ConfigurationMorphism: Morphism + Sized {
+    /// The morphism carrying `transformation` from `domain` to `codomain`
+    fn with_transformation(domain: Self::ObjectType, codomain: Self::ObjectType, transformation: Transformation) -> Self;
+    
+    /// The Q-tensors of a configuration, site by site
+    fn q_tensors(obj: &Self::ObjectType) -> &[QTensor];
+    
+    /// The morphism restoring `codomain` from `domain` by adding their difference
+    /// site by site, or the identity if they coincide.
+    ///
+    /// Fails unless the configurations have the same sites and differ only in
+    /// their Q-tensors.
+    fn reconstruction(domain: Self::ObjectType, codomain: Self::ObjectType) -> Result<Self, CategoryError> {
+        if domain.id() == codomain.id() {
+            return Ok(Self::with_transformation(domain, codomain, Transformation::Identity));
+        }
+        
+        let residual = residual(Self::q_tensors(&domain), Self::q_tensors(&codomain))?;
+        acting_as_declared(Self::with_transformation(domain, codomain, Transformation::Reconstruction { residual }))
+    }
+    
+    /// The image of a morphism carrying `transformation` under a functor that
+    /// takes its endpoints to `domain` and `codomain`.
+    ///
+    /// A residual belongs to the sites it was computed on, so a reconstruction
+    /// has its residual carried over by `map_residual`, the action of the functor
+    /// on Q-tensors. Its image must still take `domain` to `codomain`, which
+    /// holds for linear actions, and is the identity if they coincide.
+    fn map_transformation(
+        transformation: &Transformation,
+        domain: Self::ObjectType,
+        codomain: Self::ObjectType,
+        map_residual: impl FnOnce(&[DMatrix<f64>]) -> Result<Vec<DMatrix<f64>>, CategoryError>,
+    ) -> Result<Self, CategoryError> {
+        match transformation {
+            Transformation::Reconstruction { residual } => {
+                let residual = map_residual(residual)?;
+                acting_as_declared(Self::with_transformation(domain, codomain, Transformation::Reconstruction { residual }))
+            }
+            _ => Ok(Self::with_transformation(domain, codomain, transformation.clone())),
+        }
+    }
+}
+
+/// Check that a reconstruction takes its domain to its codomain, replacing it
+/// by the identity if they coincide
+fn acting_as_declared<M: ConfigurationMorphism>(morphism: M) -> Result<M, CategoryError> {
+    match morphism.apply(morphism.domain()) {
+        Ok(restored) if restored.id() == morphism.codomain().id() => {
+            if morphism.domain().id() == morphism.codomain().id() {
+                let (domain, codomain) = (morphism.domain().clone(), morphism.codomain().clone());
+                Ok(M::with_transformation(domain, codomain, Transformation::Identity))
+            } else {
+                Ok(morphism)
+            }
+        }
+        _ => Err(CategoryError::InvalidApplication(format!(
+            "Reconstruction from {} does not reach {}", morphism.domain().id(), morphism.codomain().id()
+        ))),
+    }
+}
+
+/// Dimensions (nx, ny, nz) of a lattice of Q-tensors
+pub type LatticeDimensions = (usize, usize, usize);
+
+/// Rotation matrix for a rotation of `angle` radians about `axis`
+pub fn rotation_matrix(axis: &Vector3<f64>, angle: f64) -> Result<Matrix3<f64>, CategoryError> {
+    if axis.norm() < 1e-12 {
+        return Err(CategoryError::InvalidApplication(
+            "Rotation axis must be non-zero".to_string()
+        ));
+    }
+    
+    Ok(Rotation3::from_axis_angle(&Unit::new_normalize(*axis), angle).into_inner())
+}
+
+/// Rotate each Q-tensor as Q -> R Q Rᵀ
+pub fn rotate_tensors(values: &[QTensor], rotation: &Matrix3<f64>) -> Vec<QTensor> {
+    let r = DMatrix::from_iterator(3, 3, rotation.iter().cloned());
+    values.iter()
+        .map(|q| QTensor::new(&r * &q.components * r.transpose()))
+        .collect()
+}
+
+/// A rotation of a lattice about its centre as a relabelling of its sites.
+///
+/// Only the rotations of the cube, whose matrices are signed permutations, map
+/// the lattice onto itself. Each new axis is an old one, possibly reversed, so
+/// the extents along the axes may be exchanged.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LatticeRotation {
+    /// The old axis along each new axis, and whether it is reversed
+    pub axes: [(usize, bool); 3],
+}
+
+impl LatticeRotation {
+    /// The lattice rotation with the given matrix, if there is one
+    pub fn from_matrix(rotation: &Matrix3<f64>) -> Result<Self, CategoryError> {
+        let mut axes = [(0, false); 3];
+        for (new, axis) in axes.iter_mut().enumerate() {
+            let row = rotation.row(new);
+            let old = row.iamax_full().1;
+            let off_axis = (0..3).filter(|&b| b != old).all(|b| row[b].abs() < 1e-9);
+            if !off_axis || (row[old].abs() - 1.0).abs() > 1e-9 {
+                return Err(CategoryError::InvalidApplication(format!(
+                    "Rotation {} does not map the lattice onto itself", rotation
+                )));
+            }
+            *axis = (old, row[old] < 0.0);
+        }
+        Ok(Self { axes })
+    }
+    
+    /// Extents of the rotated lattice
+    pub fn dimensions(&self, dimensions: LatticeDimensions) -> LatticeDimensions {
+        let n = [dimensions.0, dimensions.1, dimensions.2];
+        (n[self.axes[0].0], n[self.axes[1].0], n[self.axes[2].0])
+    }
+    
+    /// Move the sites of a lattice and rotate each Q-tensor as Q -> R Q Rᵀ
+    pub fn apply(
+        &self,
+        values: &[QTensor],
+        dimensions: LatticeDimensions,
+        rotation: &Matrix3<f64>,
+    ) -> Result<(Vec<QTensor>, LatticeDimensions), CategoryError> {
+        let (nx, ny, nz) = dimensions;
+        if values.len() != nx * ny * nz {
+            return Err(CategoryError::InvalidApplication(format!(
+                "Lattice has {} sites but {} values", nx * ny * nz, values.len()
+            )));
+        }
+        
+        let n = [nx, ny, nz];
+        let rotated_dimensions = self.dimensions(dimensions);
+        let (_, my, mz) = rotated_dimensions;
+        let tensors = rotate_tensors(values, rotation);
+        
+        let mut rotated = tensors.clone();
+        for i in 0..nx {
+            for j in 0..ny {
+                for k in 0..nz {
+                    let site = [i, j, k];
+                    let to = self.axes.map(|(old, reversed)| {
+                        if reversed { n[old] - 1 - site[old] } else { site[old] }
+                    });
+                    rotated[to[0] * my * mz + to[1] * mz + to[2]] = tensors[i * ny * nz + j * nz + k].clone();
+                }
+            }
+        }
+        
+        Ok((rotated, rotated_dimensions))
+    }
+}
+
+/// Difference taking the Q-tensors `from` to `to`, site by site
+pub fn residual(from: &[QTensor], to: &[QTensor]) -> Result<Vec<DMatrix<f64>>, CategoryError> {
+    if from.len() != to.len() {
+        return Err(CategoryError::InvalidApplication(format!(
+            "Cannot reconstruct {} sites from {}", to.len(), from.len()
+        )));
+    }
+    
+    Ok(from.iter().zip(to).map(|(a, b)| &b.components - &a.components).collect())
+}
+
+/// Add a residual to each Q-tensor, site by site
+pub fn add_residual(values: &[QTensor], residual: &[DMatrix<f64>]) -> Result<Vec<QTensor>, CategoryError> {
+    if values.len() != residual.len() {
+        return Err(CategoryError::InvalidApplication(format!(
+            "Residual for {} sites applied to {}", residual.len(), values.len()
+        )));
+    }
+    
+    Ok(values.iter().zip(residual).map(|(q, r)| QTensor::new(&q.components + r)).collect())
+}
+
+/// The shift in whole sites of a translation by `offset` on a lattice with the
+/// given spacing, failing unless `offset` is a lattice vector
+pub fn lattice_shift(offset: &Vector3<f64>, spacing: (f64, f64, f64)) -> Result<(i64, i64, i64), CategoryError> {
+    let sites = [offset.x / spacing.0, offset.y / spacing.1, offset.z / spacing.2];
+    if sites.iter().any(|s| !s.is_finite() || (s - s.round()).abs() > 1e-9) {
+        return Err(CategoryError::InvalidApplication(format!(
+            "Translation by ({}, {}, {}) is not a whole number of sites", offset.x, offset.y, offset.z
+        )));
+    }
+    Ok((sites[0].round() as i64, sites[1].round() as i64, sites[2].round() as i64))
+}
+
+/// Shift a lattice of Q-tensors by whole sites with periodic boundaries
+pub fn shift_lattice(
+    values: &[QTensor],
+    dimensions: LatticeDimensions,
+    shift: (i64, i64, i64),
+) -> Vec<QTensor> {
+    let (nx, ny, nz) = dimensions;
+    let wrap = |i: usize, s: i64, n: usize| (i as i64 + s).rem_euclid(n as i64) as usize;
+    
+    let mut shifted = values.to_vec();
+    for i in 0..nx {
+        for j in 0..ny {
+            for k in 0..nz {
+                let from = i * ny * nz + j * nz + k;
+                let to = wrap(i, shift.0, nx) * ny * nz + wrap(j, shift.1, ny) * nz + wrap(k, shift.2, nz);
+                if from < values.len() && to < shifted.len() {
+                    shifted[to] = values[from].clone();
+                }
+            }
+        }
+    }
+    
+    shifted
+}
+
+/// Average a lattice of Q-tensors over blocks of `factor` sites along each axis.
+///
+/// Sites beyond the last whole block are dropped.
+pub fn block_average(
+    values: &[QTensor],
+    dimensions: LatticeDimensions,
+    factor: usize,
+) -> Result<(Vec<QTensor>, LatticeDimensions), CategoryError> {
+    let (nx, ny, nz) = dimensions;
+    if values.len() < nx * ny * nz {
+        return Err(CategoryError::InvalidApplication(format!(
+            "Lattice has {} sites but {} values", nx * ny * nz, values.len()
+        )));
+    }
+    if factor == 0 || factor > nx || factor > ny || factor > nz {
+        return Err(CategoryError::InvalidApplication(format!(
+            "Cannot coarse-grain a {}x{}x{} lattice by a factor of {}", nx, ny, nz, factor
+        )));
+    }
+    
+    let coarse = (nx / factor, ny / factor, nz / factor);
+    let mut averaged = Vec::with_capacity(coarse.0 * coarse.1 * coarse.2);
+    
+    for i in 0..coarse.0 {
+        for j in 0..coarse.1 {
+            for k in 0..coarse.2 {
+                let mut sum = DMatrix::zeros(3, 3);
+                for di in 0..factor {
+                    for dj in 0..factor {
+                        for dk in 0..factor {
+                            let idx = (factor * i + di) * ny * nz + (factor * j + dj) * nz + (factor * k + dk);
+                            sum += &values[idx].components;
+                        }
+                    }
+                }
+                averaged.push(QTensor::new(sum / (factor * factor * factor) as f64));
+            }
+        }
+    }
+    
+    Ok((averaged, coarse))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+    
+    #[test]
+    fn quarter_turn_moves_sites() {
+        // Sites of a 2x3x1 lattice labelled by the xx component of their tensor
+        let values: Vec<QTensor> = (0..6)
+            .map(|label| QTensor::new(DMatrix::from_diagonal_element(3, 3, label as f64)))
+            .collect();
+        let rotation = rotation_matrix(&Vector3::z(), PI / 2.0).unwrap();
+        let lattice_rotation = LatticeRotation::from_matrix(&rotation).unwrap();
+        let (rotated, dimensions) = lattice_rotation.apply(&values, (2, 3, 1), &rotation).unwrap();
+        
+        // (x, y) -> (2 - y, x) about the centre of the lattice
+        assert_eq!(dimensions, (3, 2, 1));
+        let labels: Vec<f64> = rotated.iter().map(|q| q.components[(0, 0)]).collect();
+        assert_eq!(labels, vec![2.0, 5.0, 1.0, 4.0, 0.0, 3.0]);
+        
+        assert!(LatticeRotation::from_matrix(&rotation_matrix(&Vector3::z(), PI / 3.0).unwrap()).is_err());
+    }
+}
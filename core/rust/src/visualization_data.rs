@@ -1,14 +1,14 @@
-use crate::microscopic::{MicroscopicConfiguration, QTensor};
+use crate::microscopic::MicroscopicConfiguration;
 use crate::mesoscopic::QTensorField;
-use crate::macroscopic::{MacroscopicConfiguration, Defect};
-use crate::manifold::{CurvedSpace, CurvedSpacePoint};
-use nalgebra::{DMatrix, DVector};
+use crate::macroscopic::MacroscopicConfiguration;
+use crate::manifold::CurvedSpace;
+use crate::phase_diagram::PhaseDiagram;
+use crate::rg_flow::ParameterSpace;
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 use std::error::Error;
 use std::fs::File;
 use std::io::Write;
-use std::path::Path;
 
 /// Data format for visualizing director fields
 #[derive(Serialize, Deserialize)]
@@ -40,6 +40,20 @@ pub struct RGFlowData {
     pub metadata: HashMap<String, String>,
 }
 
+/// Data format for RG phase diagrams on a 2D slice of parameter space
+#[derive(Serialize, Deserialize)]
+pub struct PhaseDiagramData {
+    pub parameter_names: Vec<String>,
+    pub x_values: Vec<f64>,
+    pub y_values: Vec<f64>,
+    pub labels: Vec<Vec<String>>,
+    pub flow_vectors: Vec<Vec<[f64; 2]>>,
+    pub streamlines: Vec<Vec<[f64; 2]>>,
+    pub fixed_points: Vec<[f64; 2]>,
+    pub fixed_point_types: Vec<String>,
+    pub metadata: HashMap<String, String>,
+}
+
 /// Convert a microscopic configuration to director field data
 pub fn microscopic_to_director_field(config: &MicroscopicConfiguration) -> DirectorFieldData {
     let (nx, ny, nz) = config.dimensions;
@@ -98,7 +112,6 @@ pub fn mesoscopic_to_director_field(field: &QTensorField, temperature: f64) -> D
     let mut directions = Vec::with_capacity(field.values.len());
     let mut order_parameters = Vec::with_capacity(field.values.len());
     
-    let mut index = 0;
     for i in 0..nx {
         for j in 0..ny {
             for k in 0..nz {
@@ -115,8 +128,6 @@ pub fn mesoscopic_to_director_field(field: &QTensorField, temperature: f64) -> D
                     let (s, n) = q.to_director();
                     directions.push([n[0], n[1], n[2]]);
                     order_parameters.push(s);
-                    
-                    index += 1;
                 }
             }
         }
@@ -339,16 +350,14 @@ pub fn save_to_json<T: Serialize>(
 }
 
 /// Generate RG flow data for visualization
-pub fn generate_rg_flow_data<P: serde::Serialize>(
+pub fn generate_rg_flow_data<P: ParameterSpace>(
     parameter_names: Vec<String>,
     trajectories: Vec<Vec<P>>,
     fixed_points: Vec<(Vec<f64>, String)>,
 ) -> RGFlowData {
     let mut trajectory_data = Vec::new();
     for traj in trajectories {
-        let serialized = serde_json::to_value(traj).unwrap();
-        let points: Vec<Vec<f64>> = serde_json::from_value(serialized).unwrap();
-        trajectory_data.extend(points);
+        trajectory_data.extend(traj.iter().map(|point| point.as_vector().iter().cloned().collect::<Vec<f64>>()));
     }
     
     let mut fixed_point_data = Vec::new();
@@ -371,3 +380,31 @@ pub fn generate_rg_flow_data<P: serde::Serialize>(
         metadata,
     }
 }
+
+/// Generate phase diagram data for visualization
+pub fn generate_phase_diagram_data(
+    diagram: &PhaseDiagram,
+    x_name: String,
+    y_name: String,
+) -> PhaseDiagramData {
+    let labels = diagram.basins.iter()
+        .map(|row| row.iter().map(|basin| basin.label()).collect())
+        .collect();
+    
+    let mut metadata = HashMap::new();
+    metadata.insert("x_parameter".to_string(), diagram.x.parameter.to_string());
+    metadata.insert("y_parameter".to_string(), diagram.y.parameter.to_string());
+    metadata.insert("visualization_type".to_string(), "phase_diagram".to_string());
+    
+    PhaseDiagramData {
+        parameter_names: vec![x_name, y_name],
+        x_values: diagram.x.values(),
+        y_values: diagram.y.values(),
+        labels,
+        flow_vectors: diagram.flow.clone(),
+        streamlines: diagram.streamlines.clone(),
+        fixed_points: diagram.fixed_points.clone(),
+        fixed_point_types: diagram.fixed_point_types.clone(),
+        metadata,
+    }
+}